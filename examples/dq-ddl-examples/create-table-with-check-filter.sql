@@ -5,5 +5,5 @@ Create table if not exists Test {
         -CONTAINS "test" 0.9 | Price > 10,
         -not_empty,
         -unique},
-    Price FLOAT(3,8) PRIMARY KEY
+    Price FLOAT(8,3) PRIMARY KEY
 };
\ No newline at end of file
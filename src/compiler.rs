@@ -1,7 +1,10 @@
 use clap::ValueEnum;
 use serde::Serialize;
+use std::fmt::Display;
 
 pub(crate) mod dqdl;
+pub mod explain;
+pub mod open_lineage;
 pub mod pydeequ;
 pub mod pyspark_class;
 mod test_strings;
@@ -11,6 +14,27 @@ pub enum CompilationTarget {
     PyDeequ,
     Dqdl,
     PySparkClass,
+    OpenLineage,
+    Explain,
     #[default]
     None,
 }
+
+/// Error of a rule that cannot be expressed in the language of a compilation target.
+#[derive(Debug)]
+pub enum CompileError {
+    UnsupportedByTarget {
+        target: CompilationTarget,
+        message: String,
+    },
+}
+
+impl Display for CompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompileError::UnsupportedByTarget { target, message } => {
+                write!(f, "Unsupported by target {:?}: {}", target, message)
+            }
+        }
+    }
+}
@@ -1,136 +1,817 @@
+use crate::compiler::{CompilationTarget, CompileError};
+use crate::model::anomaly::AnomalyMetric;
+use crate::model::catalog::Catalog;
 use crate::model::column_rule::ColumnRule;
+use crate::model::custom_sql::CustomSql;
+use crate::model::distribution::{Category, Distribution};
+use crate::model::pragma::SAMPLE_FRACTION;
+use crate::model::rule_ext_config::Severity;
+use crate::model::rule_filter::filter::ColumnRuleFilter;
+use crate::model::statistic::Statistic;
 use crate::model::table_expr::TableDef;
+use crate::model::table_rule::TableRule;
+use crate::model::threshold::Threshold;
+use crate::model::volume::{RowCount, PREVIOUS_RUN};
+use std::collections::BTreeMap;
+use std::fmt::Display;
 
-pub fn compile_column_rule(
-    column_rule: ColumnRule,
-    table_name: String,
-    column_name: String,
-) -> String {
-    match column_rule {
-        ColumnRule::RegexPattern(rule) => {
-            format!(
-                "CustomSql \"select count() from {} where {} like '{}' \"",
-                table_name, column_name, rule.pattern
-            )
-        }
-        ColumnRule::LikePattern(rule) => {
-            format!(
-                "CustomSql \"select count() from {} where {} like '{}' \"",
-                table_name, column_name, rule.pattern
-            )
-        }
-        ColumnRule::ContainsValue(rule) => {
-            format!(
-                "CustomSql \"select count() from {} where {} like '%{}%' \"",
-                table_name, column_name, rule.value
-            )
-        }
-        ColumnRule::Uniqueness(_) => {
-            format!("IsPrimaryKey \"{}\"", column_name)
-        }
-        ColumnRule::NotEmpty(_) => {
-            format!("ColumnLength \"{}\" > 0", column_name)
-        }
-        ColumnRule::NonNull(_) => {
-            format!("IsComplete \"{}\"", column_name)
-        }
-        ColumnRule::IsType(rule) => {
-            format!(
-                "ColumnDataType \"{}\" = \"{}\"",
-                column_name, rule.data_type.class
-            )
-        } // _ => unimplemented!("DQDL has no implementation of rule: {:?}", column_rule)
-    }
-}
-
-pub fn compile(table_def: TableDef) -> String {
-    let mut compiled = String::new();
-    for column_def in table_def.columns {
-        for filter in column_def.rules {
-            if filter.filter_string.is_some() {
-                log::warn!("custom filters are not supported for DQDL at the moment!")
+/// Escapes a string so it can be used as DQDL string literal.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\\\""))
+}
+
+/// Converts a SQL LIKE pattern to an anchored regex, since DQDL only supports regex matching.
+/// `%` matches any sequence, `_` matches a single character and `\` escapes the next character.
+pub fn like_to_regex(pattern: &str) -> String {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    push_escaped(&mut regex, escaped);
+                }
             }
+            _ => push_escaped(&mut regex, c),
+        }
+    }
+
+    regex.push('$');
+    regex
+}
+
+/// Converts a value to a regex matching any string that contains it.
+fn contains_to_regex(value: &str) -> String {
+    let mut regex = String::from(".*");
+    value.chars().for_each(|c| push_escaped(&mut regex, c));
+    regex.push_str(".*");
+    regex
+}
+
+fn push_escaped(regex: &mut String, c: char) {
+    if "\\.+*?()|[]{}^$".contains(c) {
+        regex.push('\\');
+    }
+    regex.push(c);
+}
+
+/// Name of the DQDL rule type whose metric is checked by `DetectAnomalies`.
+fn anomaly_rule_type(metric: AnomalyMetric) -> &'static str {
+    match metric {
+        AnomalyMetric::RowCount => "RowCount",
+        AnomalyMetric::Completeness => "Completeness",
+        AnomalyMetric::Uniqueness => "Uniqueness",
+        AnomalyMetric::Mean => "Mean",
+        AnomalyMetric::Sum => "Sum",
+        AnomalyMetric::StandardDeviation => "StandardDeviation",
+        AnomalyMetric::ApproxDistinct => "DistinctValuesCount",
+        AnomalyMetric::Entropy => "Entropy",
+    }
+}
+
+/// A single DQDL rule with its optional `where` and `with threshold` clauses.
+struct DqdlRule {
+    expression: String,
+    filter: Option<String>,
+    threshold: Option<Threshold>,
+}
 
-            for rule in filter.rules {
-                let compiled_rule = compile_column_rule(
-                    rule,
-                    table_def.table_ref.to_string().clone(),
-                    column_def.name.clone(),
-                );
-                compiled.push_str(&compiled_rule);
-                compiled.push_str(",\n");
+impl DqdlRule {
+    fn new(expression: String) -> Self {
+        Self {
+            expression,
+            filter: None,
+            threshold: None,
+        }
+    }
+
+    /// Only rules that do not have to hold for every row get a threshold clause.
+    fn with_threshold(mut self, threshold: Threshold) -> Self {
+        if !threshold.is_every_row() {
+            self.threshold = Some(threshold);
+        }
+        self
+    }
+}
+
+impl Display for DqdlRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expression)?;
+        if let Some(filter) = &self.filter {
+            write!(f, " where {}", quote(filter))?;
+        }
+        if let Some(threshold) = self.threshold {
+            write!(f, " with threshold {}", threshold)?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the rows that violate a type conformance condition, null values conform to every type.
+fn conformance_rule(condition: &str) -> DqdlRule {
+    DqdlRule::new(format!(
+        "CustomSql {} = 0",
+        quote(&format!(
+            "select count(*) from primary where not ({})",
+            condition
+        ))
+    ))
+}
+
+/// Custom SQL is an aggregate over the rows of the table, which DQDL calls `primary`.
+fn custom_sql_rule(rule: &CustomSql) -> DqdlRule {
+    let (aggregate, threshold) = rule.assertion();
+    DqdlRule::new(format!(
+        "CustomSql {} {}",
+        quote(&format!("select {} from primary", aggregate)),
+        threshold
+    ))
+}
+
+/// Each category is checked with the ratio as threshold of `ColumnValues .. in [..]`, the largest
+/// category ratio is computed with custom SQL.
+fn distribution_rule(distribution: &Distribution, column_name: &str) -> DqdlRule {
+    let categories = match distribution {
+        Distribution::MaxCategoryRatio(ratio) => {
+            return DqdlRule::new(format!(
+                "CustomSql {} <= {}",
+                quote(&format!(
+                    "select max(category_count) / sum(category_count) from (select count(*) as category_count from primary group by {})",
+                    column_name
+                )),
+                ratio
+            ))
+        }
+        Distribution::Categories(categories) => categories,
+    };
+    let rules = categories
+        .iter()
+        .map(|category_ratio| {
+            let value = match &category_ratio.category {
+                Category::Integer(value) => value.to_string(),
+                category => quote(&category.value()),
+            };
+            DqdlRule::new(format!(
+                "ColumnValues {} in [{}]",
+                quote(column_name),
+                value
+            ))
+            .with_threshold(category_ratio.ratio)
+            .to_string()
+        })
+        .collect::<Vec<String>>();
+    if rules.len() == 1 {
+        return DqdlRule::new(rules[0].clone());
+    }
+    DqdlRule::new(
+        rules
+            .iter()
+            .map(|rule| format!("({})", rule))
+            .collect::<Vec<String>>()
+            .join(" and "),
+    )
+}
+
+fn compile_rule(column_rule: ColumnRule, column_name: &str) -> Result<DqdlRule, CompileError> {
+    let column = quote(column_name);
+    let dqdl_rule = match column_rule {
+        ColumnRule::RegexPattern(rule) => DqdlRule::new(format!(
+            "ColumnValues {} matches {}",
+            column,
+            quote(&rule.pattern)
+        ))
+        .with_threshold(rule.threshold),
+        ColumnRule::LikePattern(rule) => DqdlRule::new(format!(
+            "ColumnValues {} matches {}",
+            column,
+            quote(&like_to_regex(&rule.pattern))
+        ))
+        .with_threshold(rule.threshold),
+        ColumnRule::ContainsValue(rule) => DqdlRule::new(format!(
+            "ColumnValues {} matches {}",
+            column,
+            quote(&contains_to_regex(&rule.value))
+        ))
+        .with_threshold(rule.threshold),
+        // DQDL rules run on all rows, a sample is only available in custom SQL
+        ColumnRule::Uniqueness(rule) if rule.sampled => DqdlRule::new(format!(
+            "CustomSql {} = 0",
+            quote(&format!(
+                "select count({}) - count(distinct {}) from primary tablesample ({} percent)",
+                column_name,
+                column_name,
+                SAMPLE_FRACTION * 100.0
+            ))
+        )),
+        ColumnRule::Uniqueness(_) => DqdlRule::new(format!("IsUnique {}", column)),
+        ColumnRule::NotEmpty(rule) => {
+            DqdlRule::new(format!("ColumnLength {} > 0", column)).with_threshold(rule.threshold)
+        }
+        ColumnRule::NonNull(rule) => {
+            if rule.threshold.is_every_row() {
+                DqdlRule::new(format!("IsComplete {}", column))
+            } else {
+                DqdlRule::new(format!("Completeness {} {}", column, rule.threshold))
+            }
+        }
+        ColumnRule::IsType(rule) => DqdlRule::new(format!(
+            "ColumnDataType {} = {}",
+            column,
+            quote(&rule.data_type.class.to_string())
+        )),
+        ColumnRule::Positive(rule) => {
+            DqdlRule::new(format!("ColumnValues {} > 0", column)).with_threshold(rule.threshold)
+        }
+        ColumnRule::GeneratedValue(rule) => DqdlRule::new(format!(
+            "CustomSql {} = 0",
+            quote(&format!(
+                "select count(*) from primary where not ({} <=> ({}))",
+                column_name, rule.expression
+            ))
+        )),
+        ColumnRule::SemanticValidator(rule) => {
+            match (rule.format.pattern(), rule.format.values()) {
+                (Some(pattern), _) => DqdlRule::new(format!(
+                    "ColumnValues {} matches {}",
+                    column,
+                    quote(pattern)
+                )),
+                (None, values) => DqdlRule::new(format!(
+                    "ColumnValues {} in [{}]",
+                    column,
+                    values
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|value| quote(value))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )),
+            }
+            .with_threshold(rule.threshold)
+        }
+        ColumnRule::ColumnStatistic(rule) => match rule.statistic {
+            Statistic::NullRatio => DqdlRule::new(format!(
+                "Completeness {} {}",
+                column,
+                rule.threshold.complement()
+            )),
+            // DQDL has no distinctness rule, it is the number of distinct values per row
+            Statistic::Distinctness => DqdlRule::new(format!(
+                "CustomSql {} {}",
+                quote(&format!(
+                    "select count(distinct {}) / count(*) from primary",
+                    column_name
+                )),
+                rule.threshold
+            )),
+            Statistic::UniqueValueRatio => {
+                DqdlRule::new(format!("UniqueValueRatio {} {}", column, rule.threshold))
             }
+            Statistic::Entropy => DqdlRule::new(format!("Entropy {} {}", column, rule.threshold)),
+            Statistic::ApproxDistinct => {
+                DqdlRule::new(format!("DistinctValuesCount {} {}", column, rule.threshold))
+            }
+        },
+        ColumnRule::MinLength(rule) => {
+            DqdlRule::new(format!("ColumnLength {} >= {}", column, rule.length))
+        }
+        ColumnRule::MaxLength(rule) => {
+            DqdlRule::new(format!("ColumnLength {} <= {}", column, rule.length))
         }
+        ColumnRule::IntegerRange(rule) => conformance_rule(&rule.condition(column_name)),
+        ColumnRule::NumericPrecision(rule) => conformance_rule(&rule.condition(column_name)),
+        ColumnRule::CustomSql(rule) => custom_sql_rule(&rule),
+        ColumnRule::ColumnSequence(rule) => DqdlRule::new(format!(
+            "CustomSql {} = 0",
+            quote(&format!(
+                "select count(*) from (select {}, {} as {} from primary) ordered where {}",
+                column_name,
+                rule.previous_value(column_name),
+                rule.previous_column(column_name),
+                rule.violation(column_name)
+            ))
+        )),
+        // DQDL learns the expected range from the history, the strategy only applies to PyDeequ
+        ColumnRule::Anomaly(rule) => DqdlRule::new(format!(
+            "DetectAnomalies {} {}",
+            quote(anomaly_rule_type(rule.metric)),
+            column
+        )),
+        ColumnRule::ValueDistribution(rule) => distribution_rule(&rule.distribution, column_name),
+        // Python functions cannot be called by DQDL rules
+        ColumnRule::UserFunction(rule) => {
+            return Err(CompileError::UnsupportedByTarget {
+                target: CompilationTarget::Dqdl,
+                message: format!(
+                    "user-defined function {} of column {} can only be compiled to PyDeequ",
+                    rule.function, column_name
+                ),
+            })
+        }
+    };
+    Ok(dqdl_rule)
+}
+
+/// Adds the severity of a rule as DQDL label.
+fn with_severity_label(rule: String, severity: Severity) -> String {
+    format!("{} labels={{\"severity\": \"{}\"}}", rule, severity)
+}
+
+/// Compiles the rules of a filter group. Rules of a filtered group with the same severity are
+/// combined into one composite rule, so the group passes or fails as a whole like in the other
+/// targets.
+pub fn compile_rule_filter(
+    rule_filter: ColumnRuleFilter,
+    column_name: &str,
+    default_severity: Severity,
+) -> Result<Vec<String>, CompileError> {
+    let filter = rule_filter.to_sql();
+
+    let mut rules_by_severity: BTreeMap<Severity, Vec<String>> = BTreeMap::new();
+    for rule in rule_filter.rules {
+        let severity = rule.severity(default_severity);
+        let mut compiled_rule = compile_rule(rule, column_name)?;
+        compiled_rule.filter.clone_from(&filter);
+        rules_by_severity
+            .entry(severity)
+            .or_default()
+            .push(compiled_rule.to_string());
+    }
+
+    let mut compiled_rules: Vec<String> = vec![];
+    for (severity, rules) in rules_by_severity {
+        if filter.is_none() || rules.len() < 2 {
+            compiled_rules.extend(
+                rules
+                    .into_iter()
+                    .map(|rule| with_severity_label(rule, severity)),
+            );
+            continue;
+        }
+
+        let composite_rule = rules
+            .iter()
+            .map(|rule| format!("({})", rule))
+            .collect::<Vec<String>>()
+            .join(" and ");
+        compiled_rules.push(with_severity_label(composite_rule, severity));
+    }
+    Ok(compiled_rules)
+}
+
+fn quote_columns(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| quote(column))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub fn compile_table_rule(table_rule: TableRule) -> String {
+    match table_rule {
+        TableRule::PrimaryKey(rule) => format!("IsPrimaryKey {}", quote_columns(&rule.columns)),
+        TableRule::UniqueKey(rule) => {
+            format!("Uniqueness {} = 1.0", quote_columns(&rule.columns))
+        }
+        TableRule::CustomSql(rule) => custom_sql_rule(&rule).to_string(),
+        // the previous load has to be passed as reference dataset with the alias previous_run
+        TableRule::Volume(rule) => match rule.row_count {
+            RowCount::PreviousRun(operator, factor) => {
+                format!(
+                    "RowCountMatch {} {} {}",
+                    quote(PREVIOUS_RUN),
+                    operator,
+                    factor
+                )
+            }
+            row_count => format!("RowCount {}", row_count),
+        },
+        TableRule::Correlation(rule) => format!(
+            "ColumnCorrelation {} {} {}",
+            quote(&rule.first_column),
+            quote(&rule.second_column),
+            rule.threshold
+        ),
+        TableRule::Anomaly(rule) => {
+            format!("DetectAnomalies {}", quote(anomaly_rule_type(rule.metric)))
+        }
+    }
+}
+
+/// Compiles a table to a DQDL ruleset (`Rules = [ ... ]`).
+pub fn compile(table_def: TableDef) -> Result<String, CompileError> {
+    let mut rules: Vec<String> = vec![];
+    for column_def in table_def.columns {
+        for filter in column_def.rules {
+            rules.extend(compile_rule_filter(
+                filter,
+                &column_def.name,
+                table_def.severity,
+            )?);
+        }
+    }
+    for table_rule in table_def.table_level_rules {
+        let severity = table_rule.severity(table_def.severity);
+        rules.push(with_severity_label(
+            compile_table_rule(table_rule),
+            severity,
+        ));
+    }
+
+    if rules.is_empty() {
+        return Ok(String::from("Rules = [\n]\n"));
     }
-    compiled
+
+    Ok(format!("Rules = [\n    {}\n]\n", rules.join(",\n    ")))
+}
+
+/// Compiles every table of the catalog to its own ruleset.
+pub fn compile_catalog(catalog: Catalog) -> Result<String, CompileError> {
+    let rulesets = catalog
+        .tables
+        .into_iter()
+        .map(|table_def| {
+            let table_name = table_def.table_ref.to_string();
+            Ok(format!(
+                "# Ruleset for table {}\n{}",
+                table_name,
+                compile(table_def)?
+            ))
+        })
+        .collect::<Result<Vec<String>, CompileError>>()?;
+    Ok(rulesets.join("\n"))
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::model::anomaly::{Anomaly, AnomalyMetric, AnomalyStrategy};
+    use crate::model::catalog::Catalog;
     use crate::model::column_rule::{
-        ColumnRule, ContainsValue, IsType, LikePattern, NonNull, NotEmpty, RegexPattern, Uniqueness,
+        ColumnRule, ColumnSequence, ColumnStatistic, ContainsValue, GeneratedValue, IntegerRange,
+        IsType, LikePattern, MaxLength, MinLength, NonNull, NotEmpty, NumericPrecision, Positive,
+        RegexPattern, SemanticValidator, Uniqueness,
     };
+    use crate::model::custom_sql::CustomSql;
+    use crate::model::distribution::{Category, CategoryRatio, Distribution, ValueDistribution};
+    use crate::model::rule_ext_config::Severity;
     use crate::model::rule_filter::filter::ColumnRuleFilter;
+    use crate::model::semantic_format::SemanticFormat;
+    use crate::model::sequence::Sequence;
+    use crate::model::statistic::Statistic;
     use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+    use crate::model::table_rule::{Correlation, PrimaryKey, TableRule, UniqueKey};
+    use crate::model::threshold::{Threshold, ThresholdOperator};
+    use crate::model::volume::{RowCount, Volume};
     use rstest::rstest;
 
-    use super::compile;
+    use super::{compile, compile_catalog, compile_rule_filter, compile_table_rule, like_to_regex};
 
     #[rstest]
     #[case(
         ColumnRule::Uniqueness(Uniqueness::new(None, None)),
-        "Test",
         "Id",
-        "IsPrimaryKey \"Id\""
+        "IsUnique \"Id\""
+    )]
+    #[case(
+        ColumnRule::Anomaly(Anomaly::new(
+            None,
+            AnomalyMetric::Completeness,
+            AnomalyStrategy::RateOfChange(0.1),
+            None
+        )),
+        "Id",
+        "DetectAnomalies \"Completeness\" \"Id\""
+    )]
+    #[case(
+        ColumnRule::ValueDistribution(ValueDistribution::new(None, Distribution::Categories(vec![
+            CategoryRatio {category: Category::String("web".to_owned()), ratio: Threshold::Between(0.4, 0.6)},
+            CategoryRatio {category: Category::String("app".to_owned()), ratio: Threshold::Between(0.3, 0.5)},
+        ]), None)),
+        "Channel",
+        "(ColumnValues \"Channel\" in [\"web\"] with threshold between 0.4 and 0.6) and (ColumnValues \"Channel\" in [\"app\"] with threshold between 0.3 and 0.5)"
+    )]
+    #[case(
+        ColumnRule::ValueDistribution(ValueDistribution::new(None, Distribution::MaxCategoryRatio(0.8), None)),
+        "Channel",
+        "CustomSql \"select max(category_count) / sum(category_count) from (select count(*) as category_count from primary group by Channel)\" <= 0.8"
     )]
     #[case(
         ColumnRule::IsType(IsType::new(None, DataType::new("Int", Some(3), None), None)),
-        "Test",
         "Id",
         "ColumnDataType \"Id\" = \"Int\""
     )]
     #[case(
         ColumnRule::NonNull(NonNull::new(None, None, None)),
-        "Test",
         "Id",
         "IsComplete \"Id\""
     )]
+    #[case(
+        ColumnRule::NonNull(NonNull::new(None, None, Some(0.9.into()))),
+        "Id",
+        "Completeness \"Id\" >= 0.9"
+    )]
     #[case(
         ColumnRule::NotEmpty(NotEmpty::new(None, None, None)),
-        "Test",
         "Id",
         "ColumnLength \"Id\" > 0"
     )]
-    #[case(ColumnRule::ContainsValue(ContainsValue::new(None, "test".to_owned(), None, None)), "Test", "Id", "CustomSql \"select count() from Test where Id like '%test%' \"")]
-    #[case(ColumnRule::LikePattern(LikePattern::new(None, "test".to_owned(), None, None)), "Test", "Id", "CustomSql \"select count() from Test where Id like 'test' \"")]
-    #[case(ColumnRule::RegexPattern(RegexPattern::new(None, "test".to_owned(), None, None)), "Test", "Id", "CustomSql \"select count() from Test where Id like 'test' \"")]
-    pub fn compile_column_rule_test(
+    #[case(
+        ColumnRule::NotEmpty(NotEmpty::new(None, None, Some(0.5.into()))),
+        "Id",
+        "ColumnLength \"Id\" > 0 with threshold >= 0.5"
+    )]
+    #[case(ColumnRule::ContainsValue(ContainsValue::new(None, "test".to_owned(), None, None)), "Id", "ColumnValues \"Id\" matches \".*test.*\"")]
+    #[case(ColumnRule::ContainsValue(ContainsValue::new(None, "1.5 (kg)".to_owned(), None, None)), "Id", "ColumnValues \"Id\" matches \".*1\\.5 \\(kg\\).*\"")]
+    #[case(ColumnRule::LikePattern(LikePattern::new(None, "%te_st%".to_owned(), None, None)), "Id", "ColumnValues \"Id\" matches \"^.*te.st.*$\"")]
+    #[case(ColumnRule::RegexPattern(RegexPattern::new(None, "[0-9]*test".to_owned(), None, Some(0.75.into()))), "Id", "ColumnValues \"Id\" matches \"[0-9]*test\" with threshold >= 0.75")]
+    #[case(
+        ColumnRule::Positive(Positive::new(None, None, None)),
+        "Id",
+        "ColumnValues \"Id\" > 0"
+    )]
+    #[case(
+        ColumnRule::Positive(Positive::new(None, None, Some(Threshold::Between(0.9, 0.99)))),
+        "Id",
+        "ColumnValues \"Id\" > 0 with threshold between 0.9 and 0.99"
+    )]
+    #[case(
+        ColumnRule::NonNull(NonNull::new(
+            None,
+            None,
+            Some(Threshold::Compare(ThresholdOperator::Less, 0.01))
+        )),
+        "Id",
+        "Completeness \"Id\" < 0.01"
+    )]
+    #[case(ColumnRule::GeneratedValue(GeneratedValue::new(None, "Price * Quantity".to_owned(), None)), "Total",
+        "CustomSql \"select count(*) from primary where not (Total <=> (Price * Quantity))\" = 0")]
+    #[case(ColumnRule::SemanticValidator(SemanticValidator::new(None, SemanticFormat::Uuid, None, Some(0.99.into()))), "Id",
+        "ColumnValues \"Id\" matches \"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$\" with threshold >= 0.99")]
+    #[case(
+        ColumnRule::ColumnStatistic(ColumnStatistic::new(
+            None,
+            Statistic::NullRatio,
+            None,
+            Threshold::Compare(ThresholdOperator::LessEqual, 0.05)
+        )),
+        "Id",
+        "Completeness \"Id\" >= 0.95"
+    )]
+    #[case(ColumnRule::ColumnStatistic(ColumnStatistic::new(None, Statistic::Distinctness, None, 0.8.into())), "Id",
+        "CustomSql \"select count(distinct Id) / count(*) from primary\" >= 0.8")]
+    #[case(ColumnRule::ColumnStatistic(ColumnStatistic::new(None, Statistic::UniqueValueRatio, None, 0.8.into())), "Id",
+        "UniqueValueRatio \"Id\" >= 0.8")]
+    #[case(
+        ColumnRule::ColumnStatistic(ColumnStatistic::new(
+            None,
+            Statistic::Entropy,
+            None,
+            Threshold::Compare(ThresholdOperator::Greater, 2.5)
+        )),
+        "Id",
+        "Entropy \"Id\" > 2.5"
+    )]
+    #[case(
+        ColumnRule::ColumnStatistic(ColumnStatistic::new(
+            None,
+            Statistic::ApproxDistinct,
+            None,
+            Threshold::Between(10.0, 20.0)
+        )),
+        "Id",
+        "DistinctValuesCount \"Id\" between 10 and 20"
+    )]
+    #[case(
+        ColumnRule::MinLength(MinLength::new(None, 3, None)),
+        "Code",
+        "ColumnLength \"Code\" >= 3"
+    )]
+    #[case(
+        ColumnRule::MaxLength(MaxLength::new(None, 20, None)),
+        "Code",
+        "ColumnLength \"Code\" <= 20"
+    )]
+    #[case(ColumnRule::CustomSql(CustomSql::try_new(None, "count(*) filter (where Price > Total) = 0".to_owned(), None).unwrap()), "Price",
+        "CustomSql \"select count(*) FILTER (WHERE (Price > Total)) from primary\" = 0")]
+    #[case(ColumnRule::CustomSql(CustomSql::try_new(None, "count(*) = 16777217".to_owned(), None).unwrap()), "Price",
+        "CustomSql \"select count(*) from primary\" = 16777217")]
+    #[case(ColumnRule::ColumnSequence(ColumnSequence::new(None, Sequence::StrictlyIncreasing, Some("CreatedAt".to_owned()), None)), "Id",
+        "CustomSql \"select count(*) from (select Id, lag(Id) over (order by CreatedAt) as Id_previous_strictly_increasing from primary) ordered where Id <= Id_previous_strictly_increasing\" = 0")]
+    #[case(ColumnRule::Uniqueness(Uniqueness::new(None, None).sampled()), "Id",
+        "CustomSql \"select count(Id) - count(distinct Id) from primary tablesample (10 percent)\" = 0")]
+    #[case(ColumnRule::IntegerRange(IntegerRange::new(None, -128, 127, None)), "Quantity",
+        "CustomSql \"select count(*) from primary where not (Quantity BETWEEN -128 AND 127)\" = 0")]
+    #[case(ColumnRule::NumericPrecision(NumericPrecision::new(None, 10, 2, None)), "Price",
+        "CustomSql \"select count(*) from primary where not (abs(Price) < 1E8 AND Price = round(Price, 2))\" = 0")]
+    pub fn compile_rule_test(
         #[case] column_rule: ColumnRule,
-        #[case] table_name: &str,
         #[case] column_name: &str,
         #[case] expected: &str,
     ) {
-        let actual =
-            super::compile_column_rule(column_rule, table_name.to_owned(), column_name.to_owned());
+        let actual = super::compile_rule(column_rule, column_name)
+            .unwrap()
+            .to_string();
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    pub fn compile_value_set_rule_test() {
+        let rule = ColumnRule::SemanticValidator(SemanticValidator::new(
+            None,
+            SemanticFormat::IsoCountry,
+            None,
+            None,
+        ));
+        let compiled = super::compile_rule(rule, "Country").unwrap().to_string();
+        assert!(compiled.starts_with("ColumnValues \"Country\" in [\"AD\", \"AE\","));
+        assert!(compiled.ends_with("\"ZW\"]"));
+    }
+
+    #[test]
+    pub fn compile_table_rule_severity_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE TABLE t @severity(error) {id INT(3), PRIMARY KEY (id) @severity(info), -ROWS > 0};",
+        )
+        .unwrap();
+        let compiled = compile_catalog(catalog).unwrap();
+        assert!(compiled.contains("IsPrimaryKey \"id\" labels={\"severity\": \"info\"}"));
+        assert!(compiled.contains("RowCount > 0 labels={\"severity\": \"error\"}"));
+    }
+
+    #[test]
+    pub fn compile_keyword_column_names_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE TABLE t {Type VARCHAR(10), Add INT(3) NULL, Unique INT(3) UNIQUE, Constraint INT(3)};",
+        )
+        .unwrap();
+        let compiled = compile_catalog(catalog).unwrap();
+        for rule in [
+            "ColumnDataType \"Type\" = \"VarChar\"",
+            "ColumnDataType \"Add\" = \"Int\"",
+            "IsUnique \"Unique\"",
+            "ColumnDataType \"Constraint\" = \"Int\"",
+        ] {
+            assert!(
+                compiled.contains(rule),
+                "{} is missing in {}",
+                rule,
+                compiled
+            );
+        }
+    }
+
+    #[test]
+    pub fn compile_user_function_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE FUNCTION checks.luhn(VARCHAR) RETURNS BOOL;
+            CREATE TABLE Test {Iban VARCHAR(34) {-UDF checks.luhn()}};",
+        )
+        .unwrap();
+        let err = compile_catalog(catalog).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unsupported by target Dqdl: user-defined function checks.luhn of column Iban can only be compiled to PyDeequ"
+        );
+    }
+
     #[rstest]
-    #[case(TableDef {table_ref: TableRef::new("Test", None, None), columns: vec![]}, "")]
+    #[case(
+        TableRule::Volume(Volume::new(None, RowCount::Between(1000, 5_000_000), None)),
+        "RowCount between 1000 and 5000000"
+    )]
+    #[case(
+        TableRule::Volume(Volume::new(
+            None,
+            RowCount::Compare(ThresholdOperator::Greater, 0),
+            None
+        )),
+        "RowCount > 0"
+    )]
+    #[case(
+        TableRule::Volume(Volume::new(
+            None,
+            RowCount::PreviousRun(ThresholdOperator::GreaterEqual, 0.9),
+            None
+        )),
+        "RowCountMatch \"previous_run\" >= 0.9"
+    )]
+    #[case(
+        TableRule::Anomaly(Anomaly::new(
+            None,
+            AnomalyMetric::RowCount,
+            AnomalyStrategy::AbsoluteChange(1000.0),
+            None
+        )),
+        "DetectAnomalies \"RowCount\""
+    )]
+    #[case(
+        TableRule::Correlation(Correlation::new(
+            None,
+            "Price".to_owned(),
+            "Quantity".to_owned(),
+            None,
+            Threshold::Compare(ThresholdOperator::LessEqual, -0.5)
+        )),
+        "ColumnCorrelation \"Price\" \"Quantity\" <= -0.5"
+    )]
+    pub fn compile_table_rule_test(#[case] table_rule: TableRule, #[case] expected: &str) {
+        assert_eq!(compile_table_rule(table_rule), expected);
+    }
+
+    #[rstest]
+    #[case("%test%", "^.*test.*$")]
+    #[case("t_st", "^t.st$")]
+    #[case("1.5\\%", "^1\\.5%$")]
+    #[case("(a)", "^\\(a\\)$")]
+    pub fn like_to_regex_test(#[case] pattern: &str, #[case] expected: &str) {
+        assert_eq!(like_to_regex(pattern), expected);
+    }
+
+    #[rstest]
+    #[case(ColumnRuleFilter::new(Some(" Price > 1".to_owned()), vec![
+        ColumnRule::NonNull(NonNull::new(None, None, None)),
+    ]), vec!["IsComplete \"Id\" where \"Price > 1\" labels={\"severity\": \"warning\"}"])]
+    #[case(ColumnRuleFilter::new(Some(" Price > 1".to_owned()), vec![
+        ColumnRule::NonNull(NonNull::new(None, None, None)),
+        ColumnRule::NotEmpty(NotEmpty::new(None, None, Some(0.9.into()))),
+    ]).parse().unwrap(), vec!["(IsComplete \"Id\" where \"Price > 1\") and (ColumnLength \"Id\" > 0 where \"Price > 1\" with threshold >= 0.9) labels={\"severity\": \"warning\"}"])]
+    #[case(ColumnRuleFilter::new(None, vec![
+        ColumnRule::NonNull(NonNull::new(None, None, None)),
+        ColumnRule::NotEmpty(NotEmpty::new(None, None, Some(0.9.into()))),
+    ]), vec!["IsComplete \"Id\" labels={\"severity\": \"warning\"}", "ColumnLength \"Id\" > 0 with threshold >= 0.9 labels={\"severity\": \"warning\"}"])]
+    #[case(ColumnRuleFilter::new(Some(" Price > 1".to_owned()), vec![
+        ColumnRule::NonNull(NonNull::new(None, None, None)),
+        ColumnRule::NotEmpty(NotEmpty::new(None, None, None)).with_severity(Severity::Error),
+        ColumnRule::Positive(Positive::new(None, None, None)).with_severity(Severity::Info),
+    ]), vec![
+        "ColumnLength \"Id\" > 0 where \"Price > 1\" labels={\"severity\": \"error\"}",
+        "IsComplete \"Id\" where \"Price > 1\" labels={\"severity\": \"warning\"}",
+        "ColumnValues \"Id\" > 0 where \"Price > 1\" labels={\"severity\": \"info\"}",
+    ])]
+    pub fn compile_rule_filter_test(
+        #[case] rule_filter: ColumnRuleFilter,
+        #[case] expected: Vec<&str>,
+    ) {
+        assert_eq!(
+            compile_rule_filter(rule_filter, "Id", Severity::Warning).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(TableDef {table_ref: TableRef::new("Test", None, None), columns: vec![], table_level_rules: vec![], ..Default::default()}, "Rules = [\n]\n")]
     #[case(TableDef {table_ref: TableRef::new("Test", None, None), columns: vec![
         ColumnDef::new("Id".to_owned(), DataType::new("INT", Some(3), None), false, false)
-    ]}, "ColumnDataType \"Id\" = \"Int\",\n")]
+    ], table_level_rules: vec![], ..Default::default()}, "Rules = [\n    ColumnDataType \"Id\" = \"Int\" labels={\"severity\": \"warning\"},\n    \
+        CustomSql \"select count(*) from primary where not (Id BETWEEN -2147483648 AND 2147483647)\" = 0 labels={\"severity\": \"warning\"}\n]\n")]
     #[case(TableDef {table_ref: TableRef::new("Test", None, None), columns: vec![
-        ColumnDef {name: "Id".to_owned(), data_type: DataType::new("INT", Some(3), None), not_null: false, primary_key: false, rules: 
+        ColumnDef {name: "Id".to_owned(), data_type: DataType::new("INT", Some(3), None), not_null: false, primary_key: false, rules:
         vec![ColumnRuleFilter::new(None, vec![
             ColumnRule::Uniqueness(Uniqueness::new(None, None)),
             ColumnRule::NonNull(NonNull::new(None, None, None)),
             ColumnRule::NotEmpty(NotEmpty::new(None, None, None)),
             ColumnRule::ContainsValue(ContainsValue::new(None, "test".to_owned(), None, None)),
-        ])]}
-    ]}, "IsPrimaryKey \"Id\",\nIsComplete \"Id\",\nColumnLength \"Id\" > 0,\nCustomSql \"select count() from Test where Id like '%test%' \",\n")]
+        ])], ..Default::default()}
+    ], table_level_rules: vec![], ..Default::default()}, "Rules = [\n    IsUnique \"Id\" labels={\"severity\": \"warning\"},\n    IsComplete \"Id\" labels={\"severity\": \"warning\"},\n    ColumnLength \"Id\" > 0 labels={\"severity\": \"warning\"},\n    ColumnValues \"Id\" matches \".*test.*\" labels={\"severity\": \"warning\"}\n]\n")]
+    #[case(TableDef {table_ref: TableRef::new("Test", None, None), columns: vec![], table_level_rules: vec![
+        TableRule::PrimaryKey(PrimaryKey::new(None, vec!["OrderId".to_owned(), "LineNo".to_owned()], None)),
+        TableRule::UniqueKey(UniqueKey::new(None, vec!["OrderId".to_owned(), "Sku".to_owned()], None)),
+    ], ..Default::default()}, "Rules = [\n    IsPrimaryKey \"OrderId\" \"LineNo\" labels={\"severity\": \"warning\"},\n    Uniqueness \"OrderId\" \"Sku\" = 1.0 labels={\"severity\": \"warning\"}\n]\n")]
     pub fn compile_test(#[case] table_def: TableDef, #[case] expected: &str) {
-        let compiled = compile(table_def);
+        let compiled = compile(table_def).unwrap();
         assert_eq!(compiled, expected);
     }
+
+    #[test]
+    pub fn compile_catalog_test() {
+        let catalog = Catalog::new(vec![
+            TableDef {
+                table_ref: TableRef::new("Test", None, None),
+                columns: vec![ColumnDef::new(
+                    "Id".to_owned(),
+                    DataType::new("INT", Some(3), None),
+                    true,
+                    false,
+                )],
+                table_level_rules: vec![],
+                ..Default::default()
+            },
+            TableDef {
+                table_ref: TableRef::new("Other", Some("Schema"), None),
+                columns: vec![],
+                table_level_rules: vec![],
+                ..Default::default()
+            },
+        ]);
+
+        assert_eq!(
+            compile_catalog(catalog).unwrap(),
+            "# Ruleset for table Test\nRules = [\n    IsComplete \"Id\" labels={\"severity\": \"warning\"},\n    ColumnDataType \"Id\" = \"Int\" labels={\"severity\": \"warning\"},\n    \
+            CustomSql \"select count(*) from primary where not (Id BETWEEN -2147483648 AND 2147483647)\" = 0 labels={\"severity\": \"warning\"}\n]\n\n\
+            # Ruleset for table Schema.Other\nRules = [\n]\n"
+        );
+    }
 }
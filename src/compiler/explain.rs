@@ -0,0 +1,179 @@
+use crate::model::catalog::Catalog;
+use crate::model::column_rule::ColumnRule;
+use crate::model::rule_filter::filter::ColumnRuleFilter;
+use crate::model::table_expr::TableDef;
+use crate::model::table_rule::TableRule;
+use crate::model::threshold::Threshold;
+
+/// Appends the threshold of rules that do not have to hold for every row.
+fn with_threshold(description: String, threshold: Threshold) -> String {
+    if threshold.is_every_row() {
+        return description;
+    }
+    format!("{} {}", description, threshold)
+}
+
+/// Describes a column rule in the notation of DDLx, rules without keyword get a descriptive name.
+fn describe_rule(rule: &ColumnRule) -> String {
+    match rule {
+        ColumnRule::LikePattern(rule) => {
+            with_threshold(format!("-LIKE \"{}\"", rule.pattern), rule.threshold)
+        }
+        ColumnRule::RegexPattern(rule) => {
+            with_threshold(format!("-REGEX \"{}\"", rule.pattern), rule.threshold)
+        }
+        ColumnRule::ContainsValue(rule) => {
+            with_threshold(format!("-CONTAINS \"{}\"", rule.value), rule.threshold)
+        }
+        ColumnRule::NonNull(rule) => with_threshold("NOT NULL".to_owned(), rule.threshold),
+        ColumnRule::NotEmpty(rule) => with_threshold("-NOT_EMPTY".to_owned(), rule.threshold),
+        ColumnRule::Uniqueness(rule) if rule.sampled => "-UNIQUE (sampled)".to_owned(),
+        ColumnRule::Uniqueness(_) => "-UNIQUE".to_owned(),
+        ColumnRule::IsType(rule) => format!("TYPE {}", rule.data_type),
+        ColumnRule::Positive(rule) => with_threshold("POSITIVE".to_owned(), rule.threshold),
+        ColumnRule::GeneratedValue(rule) => format!("GENERATED AS ({})", rule.expression),
+        ColumnRule::SemanticValidator(rule) => with_threshold(
+            format!("-{}", rule.format.to_string().to_uppercase()),
+            rule.threshold,
+        ),
+        ColumnRule::ColumnStatistic(rule) => format!(
+            "-{} {}",
+            rule.statistic.to_string().to_uppercase(),
+            rule.threshold
+        ),
+        ColumnRule::MinLength(rule) => format!("-MIN_LENGTH {}", rule.length),
+        ColumnRule::MaxLength(rule) => format!("-MAX_LENGTH {}", rule.length),
+        ColumnRule::IntegerRange(rule) => format!("RANGE {} AND {}", rule.min, rule.max),
+        ColumnRule::NumericPrecision(rule) => {
+            format!("PRECISION ({}, {})", rule.precision, rule.scale)
+        }
+        ColumnRule::CustomSql(rule) => format!("-SQL \"{}\"", rule.sql),
+        ColumnRule::UserFunction(rule) => with_threshold(
+            format!(
+                "-UDF {}({})",
+                rule.function,
+                rule.arguments
+                    .iter()
+                    .map(|argument| argument.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            rule.threshold,
+        ),
+        ColumnRule::ColumnSequence(rule) => match &rule.order_by {
+            Some(order_by) => format!(
+                "-{} ORDER BY {}",
+                rule.sequence.to_string().to_uppercase(),
+                order_by
+            ),
+            None => format!("-{}", rule.sequence.to_string().to_uppercase()),
+        },
+        ColumnRule::Anomaly(rule) => rule.to_string(),
+        ColumnRule::ValueDistribution(rule) => rule.to_string(),
+    }
+}
+
+fn describe_table_rule(rule: &TableRule) -> String {
+    match rule {
+        TableRule::PrimaryKey(rule) => format!("PRIMARY KEY ({})", rule.columns.join(", ")),
+        TableRule::UniqueKey(rule) => format!("UNIQUE ({})", rule.columns.join(", ")),
+        TableRule::CustomSql(rule) => format!("-SQL \"{}\"", rule.sql),
+        TableRule::Volume(rule) => format!("-ROWS {}", rule.row_count),
+        TableRule::Anomaly(rule) => rule.to_string(),
+        TableRule::Correlation(rule) => format!(
+            "-CORRELATION({}, {}) {}",
+            rule.first_column, rule.second_column, rule.threshold
+        ),
+    }
+}
+
+fn explain_rule_filter(rule_filter: &ColumnRuleFilter, table: &TableDef) -> Vec<String> {
+    let filter = rule_filter.to_sql();
+    rule_filter
+        .rules
+        .iter()
+        .map(|rule| {
+            let origin = if rule.is_implicit() {
+                "implicit"
+            } else {
+                "explicit"
+            };
+            let mut line = format!(
+                "{} {} @severity({})",
+                origin,
+                describe_rule(rule),
+                rule.severity(table.severity)
+            );
+            if let Some(filter) = &filter {
+                line.push_str(&format!(" | {}", filter));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Lists the pragmas and rules of a table, each column rule is marked as implicit or explicit.
+pub fn explain(table: &TableDef) -> String {
+    let mut lines = vec![format!("Table {}", table.table_ref)];
+    for pragma in &table.pragmas {
+        lines.push(format!("  {}", pragma));
+    }
+    for column in &table.columns {
+        lines.push(format!("  Column {} {}", column.name, column.data_type));
+        for rule_filter in &column.rules {
+            lines.extend(
+                explain_rule_filter(rule_filter, table)
+                    .into_iter()
+                    .map(|line| format!("    {}", line)),
+            );
+        }
+    }
+    for rule in &table.table_level_rules {
+        lines.push(format!("  table {}", describe_table_rule(rule)));
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Explains every table of the catalog.
+pub fn compile_catalog(catalog: Catalog) -> String {
+    catalog
+        .tables
+        .iter()
+        .map(explain)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_catalog;
+
+    use super::compile_catalog;
+
+    #[test]
+    fn test_explain_implicit_and_explicit_rules() {
+        let catalog = parse_catalog(
+            "#pragma implicit_type_checks = off
+            CREATE TABLE Orders {
+                #pragma implicit_uniqueness = sampled,
+                Id INT(10) NOT NULL UNIQUE,
+                Code VARCHAR(10) NOT NULL { -LIKE \"A%\" @severity(error) | Id > 10, }
+            };",
+        )
+        .unwrap();
+
+        assert_eq!(
+            compile_catalog(catalog),
+            "Table Orders
+  #pragma implicit_type_checks = off
+  #pragma implicit_uniqueness = sampled
+  Column Id Int(10)
+    implicit NOT NULL @severity(warning)
+    implicit -UNIQUE (sampled) @severity(warning)
+  Column Code VarChar(10)
+    implicit NOT NULL @severity(warning)
+    explicit -LIKE \"A%\" @severity(error) | Id > 10
+"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::model::catalog::Catalog;
+use crate::model::lineage::{ColumnLineage, LineageGraph};
+use serde_json::{json, Map, Value};
+
+/// Namespace of all datasets defined in DDLx.
+const NAMESPACE: &str = "ddlx";
+const PRODUCER: &str = env!("CARGO_PKG_NAME");
+
+fn input_fields(sources: Vec<&ColumnLineage>) -> Value {
+    Value::Array(
+        sources
+            .into_iter()
+            .map(|source| {
+                json!({
+                    "namespace": NAMESPACE,
+                    "name": source.table,
+                    "field": source.column,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Builds a dataset with its schema facet and the column lineage facet if any column of the
+/// dataset has a source.
+fn dataset(name: String, columns: Vec<(String, String)>, graph: &LineageGraph) -> Value {
+    let mut lineage_fields = Map::new();
+    for (column, _) in &columns {
+        let sources = graph.sources(&name, column);
+        if !sources.is_empty() {
+            lineage_fields.insert(
+                column.clone(),
+                json!({ "inputFields": input_fields(sources) }),
+            );
+        }
+    }
+
+    let mut facets = Map::new();
+    facets.insert(
+        "schema".to_owned(),
+        json!({
+            "_producer": PRODUCER,
+            "fields": columns
+                .iter()
+                .map(|(column, data_type)| json!({"name": column, "type": data_type}))
+                .collect::<Vec<Value>>(),
+        }),
+    );
+    if !lineage_fields.is_empty() {
+        facets.insert(
+            "columnLineage".to_owned(),
+            json!({"_producer": PRODUCER, "fields": lineage_fields}),
+        );
+    }
+
+    json!({"namespace": NAMESPACE, "name": name, "facets": facets})
+}
+
+/// Compiles the tables and views of the catalog to OpenLineage datasets with schema and column
+/// lineage facets.
+pub fn compile_catalog(catalog: Catalog) -> String {
+    let graph = LineageGraph::from_catalog(&catalog);
+
+    let mut datasets: Vec<Value> = vec![];
+    for table in &catalog.tables {
+        let columns = table
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.data_type.to_string()))
+            .collect();
+        datasets.push(dataset(table.table_ref.to_string(), columns, &graph));
+    }
+    for view in &catalog.views {
+        let columns = view
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.data_type.to_string()))
+            .collect();
+        datasets.push(dataset(view.view_ref.to_string(), columns, &graph));
+    }
+
+    serde_json::to_string_pretty(&json!({ "datasets": datasets })).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compiler::open_lineage::compile_catalog;
+    use crate::parser::parse_catalog;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_compile_catalog() {
+        let catalog = parse_catalog(
+            "CREATE TABLE crm.Customers {Id INT(10) NOT NULL};
+            CREATE TABLE Orders {customer_id BIGINT(20) LINEAGE FROM crm.Customers.Id};",
+        )
+        .unwrap();
+        let compiled: Value = serde_json::from_str(&compile_catalog(catalog)).unwrap();
+
+        assert_eq!(
+            compiled["datasets"][0]["facets"]["schema"]["fields"],
+            json!([{"name": "Id", "type": "Int(10)"}])
+        );
+        assert!(compiled["datasets"][0]["facets"]["columnLineage"].is_null());
+        assert_eq!(
+            compiled["datasets"][1]["facets"]["columnLineage"]["fields"]["customer_id"],
+            json!({"inputFields": [{"namespace": "ddlx", "name": "crm.Customers", "field": "Id"}]})
+        );
+    }
+}
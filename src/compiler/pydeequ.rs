@@ -1,19 +1,96 @@
 use serde::Serialize;
+use std::collections::BTreeMap;
 use tera::{Context, Tera};
 
-use crate::compiler::pydeequ::pydeequ_rule::compile_column_rule;
+use crate::compiler::pydeequ::pydeequ_rule::{
+    anomaly_analyzer, anomaly_strategy, assertion, compile_column_rule, compile_table_rule,
+};
+use crate::compiler::{CompilationTarget, CompileError};
+use crate::model::anomaly::Anomaly;
+use crate::model::column_rule::ColumnRule;
+use crate::model::custom_sql::CustomSql;
+use crate::model::distribution::{Distribution, ValueDistribution};
+use crate::model::pragma::SAMPLE_FRACTION;
+use crate::model::rule_ext_config::Severity;
 use crate::model::rule_filter::filter::ColumnRuleFilter;
 use crate::model::table_expr::{ColumnDef, TableDef};
+use crate::model::table_rule::TableRule;
+use crate::model::udf::UserFunction;
 
 pub mod pydeequ_rule {
+    use crate::model::anomaly::{AnomalyMetric, AnomalyStrategy};
     use crate::model::column_rule::{
-        ColumnRule, ContainsValue, IsType, LikePattern, NotEmpty, RegexPattern,
+        ColumnRule, ColumnSequence, ColumnStatistic, ContainsValue, GeneratedValue, IntegerRange,
+        IsType, LikePattern, MaxLength, MinLength, NonNull, NotEmpty, NumericPrecision, Positive,
+        RegexPattern, SemanticValidator,
     };
+    use crate::model::distribution::{Distribution, ValueDistribution};
+    use crate::model::semantic_format::SemanticFormat;
+    use crate::model::statistic::Statistic;
+    use crate::model::table_rule::{Correlation, PrimaryKey, TableRule, UniqueKey};
+    use crate::model::threshold::{round_value, Threshold, ThresholdOperator};
+    use crate::model::udf::UserFunction;
+    use crate::model::volume::Volume;
 
     pub trait Compiling {
         fn compile(&self) -> String;
     }
 
+    /// Python lambda that checks the metric of a constraint against the threshold.
+    pub fn assertion(threshold: &Threshold) -> String {
+        match threshold {
+            Threshold::Compare(ThresholdOperator::Equal, value) => {
+                format!("lambda x: x == {}", value)
+            }
+            Threshold::Compare(operator, value) => format!("lambda x: x {} {}", operator, value),
+            Threshold::Between(min, max) => format!("lambda x: {} <= x <= {}", min, max),
+        }
+    }
+
+    /// Strategy of an anomaly check, a rate of change is the allowed ratio to the previous value.
+    pub fn anomaly_strategy(strategy: &AnomalyStrategy) -> String {
+        match *strategy {
+            AnomalyStrategy::RateOfChange(change) => format!(
+                "RelativeRateOfChangeStrategy(maxRateDecrease={}, maxRateIncrease={})",
+                round_value(1.0 - change),
+                round_value(1.0 + change)
+            ),
+            AnomalyStrategy::AbsoluteChange(change) => format!(
+                "AbsoluteChangeStrategy(maxRateDecrease={}, maxRateIncrease={})",
+                -change, change
+            ),
+        }
+    }
+
+    /// Analyzer that computes the metric of an anomaly check, the row count has no column.
+    pub fn anomaly_analyzer(
+        metric: AnomalyMetric,
+        column_name: Option<&str>,
+        filter: Option<&str>,
+    ) -> String {
+        let column = column_name.map(|column| format!("\"{}\"", column));
+        let mut arguments = match metric {
+            AnomalyMetric::Uniqueness => column.map(|column| format!("[{}]", column)),
+            _ => column,
+        }
+        .into_iter()
+        .collect::<Vec<String>>();
+        if let Some(filter) = filter {
+            arguments.push(format!("where=\"{}\"", filter));
+        }
+        let analyzer = match metric {
+            AnomalyMetric::RowCount => "Size",
+            AnomalyMetric::Completeness => "Completeness",
+            AnomalyMetric::Uniqueness => "Uniqueness",
+            AnomalyMetric::Mean => "Mean",
+            AnomalyMetric::Sum => "Sum",
+            AnomalyMetric::StandardDeviation => "StandardDeviation",
+            AnomalyMetric::ApproxDistinct => "ApproxCountDistinct",
+            AnomalyMetric::Entropy => "Entropy",
+        };
+        format!("{}({})", analyzer, arguments.join(", "))
+    }
+
     pub struct HasDataType {
         rule: IsType,
         column_name: String,
@@ -62,13 +139,17 @@ pub mod pydeequ_rule {
                 &self.table_name, &self.column_name
             );
             format!(
-                ".hasPattern(\"{}\", r\"{}\", lambda x: x >= {}, \"{}\")",
-                &self.column_name, &self.rule.pattern, self.rule.threshold, constraint_name
+                ".hasPattern(\"{}\", r\"{}\", {}, \"{}\")",
+                &self.column_name,
+                &self.rule.pattern,
+                assertion(&self.rule.threshold),
+                constraint_name
             )
         }
     }
 
     pub struct Completeness {
+        rule: NonNull, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.hasCompleteness
         column_name: String,
         table_name: String,
     }
@@ -79,6 +160,14 @@ pub mod pydeequ_rule {
                 "check_completeness_{}_{}",
                 &self.table_name, &self.column_name
             );
+            if !self.rule.threshold.is_every_row() {
+                return format!(
+                    ".hasCompleteness(\"{}\", {}, \"{}\")",
+                    &self.column_name,
+                    assertion(&self.rule.threshold),
+                    constraint_name
+                );
+            }
             format!(
                 ".isComplete(\"{}\", \"{}\")",
                 &self.column_name, constraint_name
@@ -117,8 +206,11 @@ pub mod pydeequ_rule {
                 &self.table_name, &self.column_name
             );
             format!(
-                ".satisfies(\"{} LIKE '{}'\", \"{}\", lambda x: x >= {})",
-                &self.column_name, &self.rule.pattern, constraint_name, self.rule.threshold
+                ".satisfies(\"{} LIKE '{}'\", \"{}\", {})",
+                &self.column_name,
+                &self.rule.pattern,
+                constraint_name,
+                assertion(&self.rule.threshold)
             )
         }
     }
@@ -136,8 +228,11 @@ pub mod pydeequ_rule {
                 &self.table_name, &self.column_name
             );
             format!(
-                ".hasPattern(\"{}\", r\"{}\", lambda x: x >= {}, \"{}\")",
-                &self.column_name, &self.rule.value, self.rule.threshold, constraint_name
+                ".hasPattern(\"{}\", r\"{}\", {}, \"{}\")",
+                &self.column_name,
+                &self.rule.value,
+                assertion(&self.rule.threshold),
+                constraint_name
             )
         }
     }
@@ -153,12 +248,406 @@ pub mod pydeequ_rule {
             let constraint_name =
                 format!("check_not_empty_{}_{}", &self.table_name, &self.column_name);
             format!(
-                ".satisfies(\"length({}) > 0\", \"{}\", lambda x: x >= {})",
-                &self.column_name, constraint_name, self.rule.threshold
+                ".satisfies(\"length({}) > 0\", \"{}\", {})",
+                &self.column_name,
+                constraint_name,
+                assertion(&self.rule.threshold)
+            )
+        }
+    }
+
+    pub struct IsPositive {
+        rule: Positive, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.isPositive
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for IsPositive {
+        fn compile(&self) -> String {
+            let constraint_name =
+                format!("check_positive_{}_{}", &self.table_name, &self.column_name);
+            format!(
+                ".isPositive(\"{}\", {}, \"{}\")",
+                &self.column_name,
+                assertion(&self.rule.threshold),
+                constraint_name
+            )
+        }
+    }
+
+    pub struct SatisfiesGenerated {
+        rule: GeneratedValue, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.satisfies
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for SatisfiesGenerated {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_generated_value_{}_{}",
+                &self.table_name, &self.column_name
+            );
+            format!(
+                ".satisfies(\"{} <=> ({})\", \"{}\", lambda x: x >= 1)",
+                &self.column_name,
+                self.rule.expression.replace('"', "\\\""),
+                constraint_name
+            )
+        }
+    }
+
+    pub struct HasSemanticFormat {
+        rule: SemanticValidator, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.containsEmail
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for HasSemanticFormat {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_{}_{}_{}",
+                self.rule.format, &self.table_name, &self.column_name
+            );
+            let assertion = assertion(&self.rule.threshold);
+            match (self.rule.format, self.rule.format.values()) {
+                (SemanticFormat::Email, _) => format!(
+                    ".containsEmail(\"{}\", {}, \"{}\")",
+                    &self.column_name, assertion, constraint_name
+                ),
+                (SemanticFormat::Url, _) => format!(
+                    ".containsURL(\"{}\", {}, \"{}\")",
+                    &self.column_name, assertion, constraint_name
+                ),
+                (_, Some(values)) => format!(
+                    ".isContainedIn(\"{}\", [{}], {}, \"{}\")",
+                    &self.column_name,
+                    quote_columns(values),
+                    assertion,
+                    constraint_name
+                ),
+                (format, None) => format!(
+                    ".hasPattern(\"{}\", r\"{}\", {}, \"{}\")",
+                    &self.column_name,
+                    format.pattern().unwrap_or_default(),
+                    assertion,
+                    constraint_name
+                ),
+            }
+        }
+    }
+
+    pub struct HasStatistic {
+        rule: ColumnStatistic, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.hasEntropy
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for HasStatistic {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_{}_{}_{}",
+                self.rule.statistic, &self.table_name, &self.column_name
+            );
+            let assertion = match self.rule.statistic {
+                // PyDeequ has no null ratio, so the completeness is checked against the complement
+                Statistic::NullRatio => assertion(&self.rule.threshold.complement()),
+                _ => assertion(&self.rule.threshold),
+            };
+            match self.rule.statistic {
+                Statistic::NullRatio => format!(
+                    ".hasCompleteness(\"{}\", {}, \"{}\")",
+                    &self.column_name, assertion, constraint_name
+                ),
+                Statistic::Distinctness => format!(
+                    ".hasDistinctness([\"{}\"], {}, \"{}\")",
+                    &self.column_name, assertion, constraint_name
+                ),
+                Statistic::UniqueValueRatio => format!(
+                    ".hasUniqueValueRatio([\"{}\"], {}, \"{}\")",
+                    &self.column_name, assertion, constraint_name
+                ),
+                Statistic::Entropy => format!(
+                    ".hasEntropy(\"{}\", {}, \"{}\")",
+                    &self.column_name, assertion, constraint_name
+                ),
+                Statistic::ApproxDistinct => format!(
+                    ".hasApproxCountDistinct(\"{}\", {}, \"{}\")",
+                    &self.column_name, assertion, constraint_name
+                ),
+            }
+        }
+    }
+
+    pub struct HasMinLength {
+        rule: MinLength, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.hasMinLength
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for HasMinLength {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_min_length_{}_{}",
+                &self.table_name, &self.column_name
+            );
+            format!(
+                ".hasMinLength(\"{}\", lambda x: x >= {}, \"{}\")",
+                &self.column_name, self.rule.length, constraint_name
             )
         }
     }
 
+    pub struct HasMaxLength {
+        rule: MaxLength, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.hasMaxLength
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for HasMaxLength {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_max_length_{}_{}",
+                &self.table_name, &self.column_name
+            );
+            format!(
+                ".hasMaxLength(\"{}\", lambda x: x <= {}, \"{}\")",
+                &self.column_name, self.rule.length, constraint_name
+            )
+        }
+    }
+
+    /// Null values conform to every type, like in `isPositive`.
+    fn satisfies_conformance(
+        column_name: &str,
+        condition: String,
+        constraint_name: String,
+    ) -> String {
+        format!(
+            ".satisfies(\"{} IS NULL OR ({})\", \"{}\", lambda x: x >= 1)",
+            column_name, condition, constraint_name
+        )
+    }
+
+    pub struct SatisfiesIntegerRange {
+        rule: IntegerRange, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.satisfies
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for SatisfiesIntegerRange {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_integer_range_{}_{}",
+                &self.table_name, &self.column_name
+            );
+            satisfies_conformance(
+                &self.column_name,
+                self.rule.condition(&self.column_name),
+                constraint_name,
+            )
+        }
+    }
+
+    pub struct SatisfiesPrecision {
+        rule: NumericPrecision, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.satisfies
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for SatisfiesPrecision {
+        fn compile(&self) -> String {
+            let constraint_name =
+                format!("check_precision_{}_{}", &self.table_name, &self.column_name);
+            satisfies_conformance(
+                &self.column_name,
+                self.rule.condition(&self.column_name),
+                constraint_name,
+            )
+        }
+    }
+
+    /// The previous value is a window column of the data frame, see `WindowColumn`. The first
+    /// row has no previous value and null values are not compared.
+    pub struct SatisfiesSequence {
+        rule: ColumnSequence, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.satisfies
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for SatisfiesSequence {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_{}_{}_{}",
+                self.rule.sequence, &self.table_name, &self.column_name
+            );
+            format!(
+                ".satisfies(\"NOT coalesce({}, false)\", \"{}\", lambda x: x >= 1)",
+                self.rule.violation(&self.column_name),
+                constraint_name
+            )
+        }
+    }
+
+    /// Every category is a constraint on the ratio of its rows, the largest ratio is checked on the
+    /// ratio column of each row, see `ValueDistribution::ratio_value`.
+    pub struct SatisfiesDistribution {
+        rule: ValueDistribution, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.satisfies
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for SatisfiesDistribution {
+        fn compile(&self) -> String {
+            match &self.rule.distribution {
+                Distribution::MaxCategoryRatio(ratio) => format!(
+                    ".satisfies(\"{} <= {}\", \"check_max_category_ratio_{}_{}\", lambda x: x >= 1)",
+                    self.rule.ratio_column(&self.column_name),
+                    ratio,
+                    &self.table_name,
+                    &self.column_name
+                ),
+                Distribution::Categories(categories) => categories
+                    .iter()
+                    .map(|category_ratio| {
+                        format!(
+                            ".satisfies(\"{} = {}\", \"check_distribution_{}_{}_{}\", {})",
+                            &self.column_name,
+                            category_ratio.category,
+                            &self.table_name,
+                            &self.column_name,
+                            category_ratio.category.value(),
+                            assertion(&category_ratio.ratio)
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n            "),
+            }
+        }
+    }
+
+    /// The function is imported and registered as Spark UDF, see `UdfImport`.
+    pub struct SatisfiesUserFunction {
+        rule: UserFunction, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.satisfies
+        column_name: String,
+        table_name: String,
+    }
+
+    impl Compiling for SatisfiesUserFunction {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_udf_{}_{}_{}",
+                self.rule.function.replace('.', "_"),
+                &self.table_name,
+                &self.column_name
+            );
+            format!(
+                ".satisfies(\"{}\", \"{}\", {})",
+                self.rule.call(&self.column_name),
+                constraint_name,
+                assertion(&self.rule.threshold)
+            )
+        }
+    }
+
+    pub struct IsPrimaryKey {
+        rule: PrimaryKey, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.isPrimaryKey
+        table_name: String,
+    }
+
+    impl Compiling for IsPrimaryKey {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_primary_key_{}_{}",
+                &self.table_name,
+                self.rule.columns.join("_")
+            );
+            format!(
+                ".isPrimaryKey({}, hint=\"{}\")",
+                quote_columns(&self.rule.columns),
+                constraint_name
+            )
+        }
+    }
+
+    pub struct HasUniqueness {
+        rule: UniqueKey, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.hasUniqueness
+        table_name: String,
+    }
+
+    impl Compiling for HasUniqueness {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_uniqueness_{}_{}",
+                &self.table_name,
+                self.rule.columns.join("_")
+            );
+            format!(
+                ".hasUniqueness([{}], lambda x: x == 1, \"{}\")",
+                quote_columns(&self.rule.columns),
+                constraint_name
+            )
+        }
+    }
+
+    /// Run-over-run rules compare with `previous_size`, which is loaded from the metrics repository.
+    pub struct HasSize {
+        rule: Volume, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.hasSize
+        table_name: String,
+    }
+
+    impl Compiling for HasSize {
+        fn compile(&self) -> String {
+            let constraint_name = format!("check_rows_{}", &self.table_name);
+            format!(
+                ".hasSize({}, \"{}\")",
+                self.rule.row_count.assertion(),
+                constraint_name
+            )
+        }
+    }
+
+    pub struct HasCorrelation {
+        rule: Correlation, // https://pydeequ.readthedocs.io/en/latest/pydeequ.html#pydeequ.checks.Check.hasCorrelation
+        table_name: String,
+    }
+
+    impl Compiling for HasCorrelation {
+        fn compile(&self) -> String {
+            let constraint_name = format!(
+                "check_correlation_{}_{}_{}",
+                &self.table_name, &self.rule.first_column, &self.rule.second_column
+            );
+            format!(
+                ".hasCorrelation(\"{}\", \"{}\", {}, \"{}\")",
+                &self.rule.first_column,
+                &self.rule.second_column,
+                assertion(&self.rule.threshold),
+                constraint_name
+            )
+        }
+    }
+
+    fn quote_columns<T: AsRef<str>>(columns: &[T]) -> String {
+        columns
+            .iter()
+            .map(|column| format!("\"{}\"", column.as_ref()))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn compile_table_rule(table_rule: TableRule, table_name: String) -> String {
+        match table_rule {
+            TableRule::PrimaryKey(rule) => IsPrimaryKey { rule, table_name }.compile(),
+            TableRule::UniqueKey(rule) => HasUniqueness { rule, table_name }.compile(),
+            // custom SQL is evaluated with Spark SQL instead of a PyDeequ check
+            TableRule::CustomSql(_) => unreachable!("Custom SQL is compiled to a CustomSqlCheck"),
+            TableRule::Volume(rule) => HasSize { rule, table_name }.compile(),
+            TableRule::Correlation(rule) => HasCorrelation { rule, table_name }.compile(),
+            // anomaly rules compare with the metrics repository instead of a PyDeequ check
+            TableRule::Anomaly(_) => unreachable!("Anomaly rules are compiled to an AnomalyCheck"),
+        }
+    }
+
     pub fn compile_column_rule(
         column_rule: ColumnRule,
         table_name: String,
@@ -189,7 +678,8 @@ pub mod pydeequ_rule {
                 table_name: table_name.clone(),
             }
             .compile(),
-            ColumnRule::NonNull(_) => Completeness {
+            ColumnRule::NonNull(rule) => Completeness {
+                rule,
                 column_name: column_name.clone(),
                 table_name: table_name.clone(),
             }
@@ -199,31 +689,218 @@ pub mod pydeequ_rule {
                 table_name: table_name.clone(),
                 rule,
             }
-            .compile(), // _ => unimplemented!("Pydeequ has no implementation of rule: {:?}", column_rule),
+            .compile(),
+            ColumnRule::Positive(rule) => IsPositive {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::GeneratedValue(rule) => SatisfiesGenerated {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::SemanticValidator(rule) => HasSemanticFormat {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::ColumnStatistic(rule) => HasStatistic {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::MinLength(rule) => HasMinLength {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::MaxLength(rule) => HasMaxLength {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::IntegerRange(rule) => SatisfiesIntegerRange {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::NumericPrecision(rule) => SatisfiesPrecision {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::CustomSql(_) => unreachable!("Custom SQL is compiled to a CustomSqlCheck"),
+            ColumnRule::ColumnSequence(rule) => SatisfiesSequence {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::UserFunction(rule) => SatisfiesUserFunction {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::ValueDistribution(rule) => SatisfiesDistribution {
+                rule,
+                column_name,
+                table_name,
+            }
+            .compile(),
+            ColumnRule::Anomaly(_) => unreachable!("Anomaly rules are compiled to an AnomalyCheck"),
         }
     }
 
     #[cfg(test)]
     pub mod test {
-        use crate::compiler::pydeequ::pydeequ_rule::compile_column_rule;
+        use crate::compiler::pydeequ::pydeequ_rule::{compile_column_rule, compile_table_rule};
         use crate::model::column_rule::{
-            ColumnRule, ContainsValue, IsType, LikePattern, NonNull, NotEmpty, RegexPattern,
-            Uniqueness,
+            ColumnRule, ColumnSequence, ColumnStatistic, ContainsValue, GeneratedValue,
+            IntegerRange, IsType, LikePattern, MaxLength, MinLength, NonNull, NotEmpty,
+            NumericPrecision, Positive, RegexPattern, SemanticValidator, Uniqueness,
+        };
+        use crate::model::distribution::{
+            Category, CategoryRatio, Distribution, ValueDistribution,
         };
+        use crate::model::semantic_format::SemanticFormat;
+        use crate::model::sequence::Sequence;
+        use crate::model::statistic::Statistic;
         use crate::model::table_expr::DataType;
+        use crate::model::table_rule::{Correlation, PrimaryKey, TableRule, UniqueKey};
+        use crate::model::threshold::{Threshold, ThresholdOperator};
+        use crate::model::volume::{RowCount, Volume};
         use rstest::rstest;
 
         #[rstest]
-        #[case(ColumnRule::RegexPattern(RegexPattern {name: "".to_owned(), pattern: "^(?:\\D*\\d){10}$".to_owned(), threshold: 0.5, ..Default::default()}), "Test", "Id", ".hasPattern(\"Id\", r\"^(?:\\D*\\d){10}$\", lambda x: x >= 0.5, \"check_has_pattern_Test_Id\")")]
+        #[case(ColumnRule::RegexPattern(RegexPattern {name: "".to_owned(), pattern: "^(?:\\D*\\d){10}$".to_owned(), threshold: 0.5.into(), ..Default::default()}), "Test", "Id", ".hasPattern(\"Id\", r\"^(?:\\D*\\d){10}$\", lambda x: x >= 0.5, \"check_has_pattern_Test_Id\")")]
         #[case(ColumnRule::LikePattern(LikePattern {name: "".to_owned(), pattern: "%test%".to_owned(), ..Default::default()}), "Test", "Price", ".satisfies(\"Price LIKE '%test%'\", \"check_like_pattern_Test_Price\", lambda x: x >= 1)")]
         #[case(ColumnRule::ContainsValue(ContainsValue {name: "".to_owned(), value: "test".to_owned(), ..Default::default()}), "Test", "Id", ".hasPattern(\"Id\", r\"test\", lambda x: x >= 1, \"check_contains_value_Test_Id\")")]
         #[case(ColumnRule::Uniqueness(Uniqueness {name: "".to_owned(), ..Default::default()}), "Test", "Id", ".isUnique(\"Id\", \"check_uniqueness_Test_Id\")")]
         #[case(ColumnRule::NonNull(NonNull {name: "".to_owned(), ..Default::default()}), "Table", "Column", ".isComplete(\"Column\", \"check_completeness_Table_Column\")")]
+        #[case(
+            ColumnRule::NonNull(NonNull::new(
+                None,
+                None,
+                Some(Threshold::Compare(ThresholdOperator::Greater, 0.95))
+            )),
+            "Table",
+            "Column",
+            ".hasCompleteness(\"Column\", lambda x: x > 0.95, \"check_completeness_Table_Column\")"
+        )]
+        #[case(
+            ColumnRule::NotEmpty(NotEmpty::new(
+                None,
+                None,
+                Some(Threshold::Compare(ThresholdOperator::Equal, 0.5))
+            )),
+            "Test",
+            "Value",
+            ".satisfies(\"length(Value) > 0\", \"check_not_empty_Test_Value\", lambda x: x == 0.5)"
+        )]
+        #[case(
+            ColumnRule::Positive(Positive::new(None, None, Some(Threshold::Between(0.9, 0.99)))),
+            "Test",
+            "Id",
+            ".isPositive(\"Id\", lambda x: 0.9 <= x <= 0.99, \"check_positive_Test_Id\")"
+        )]
         #[case(ColumnRule::IsType(IsType {name: "".to_owned(), data_type: DataType::new("INT", Some(4), None), ..Default::default()}), "Test", "Quantity", ".hasDataType(\"Quantity\", ConstrainableDataTypes.Numeric, lambda x: x >= 1)")]
         #[case(ColumnRule::IsType(IsType {name: "".to_owned(), data_type: DataType::new("VarChar", Some(4), None), ..Default::default()}), "Test", "Description", ".hasDataType(\"Description\", ConstrainableDataTypes.String, lambda x: x >= 1)")]
         #[case(ColumnRule::IsType(IsType {name: "".to_owned(), data_type: DataType::new("Float", Some(4), None), ..Default::default()}), "Test", "Price", ".hasDataType(\"Price\", ConstrainableDataTypes.Fractional, lambda x: x >= 1)")]
         #[case(ColumnRule::IsType(IsType {name: "".to_owned(), data_type: DataType::new("Bool", None, None), ..Default::default()}), "Test", "Available", ".hasDataType(\"Available\", ConstrainableDataTypes.Boolean, lambda x: x >= 1)")]
         #[case(ColumnRule::NotEmpty(NotEmpty {name: "".to_owned(), ..Default::default()}), "Test", "Value", ".satisfies(\"length(Value) > 0\", \"check_not_empty_Test_Value\", lambda x: x >= 1)")]
+        #[case(
+            ColumnRule::Positive(Positive::new(None, None, None)),
+            "Test",
+            "Id",
+            ".isPositive(\"Id\", lambda x: x >= 1, \"check_positive_Test_Id\")"
+        )]
+        #[case(ColumnRule::GeneratedValue(GeneratedValue::new(None, "Price * Quantity".to_owned(), None)), "Test", "Total", ".satisfies(\"Total <=> (Price * Quantity)\", \"check_generated_value_Test_Total\", lambda x: x >= 1)")]
+        #[case(
+            ColumnRule::SemanticValidator(SemanticValidator::new(
+                None,
+                SemanticFormat::Email,
+                None,
+                Some(0.9.into())
+            )),
+            "Test",
+            "Mail",
+            ".containsEmail(\"Mail\", lambda x: x >= 0.9, \"check_email_Test_Mail\")"
+        )]
+        #[case(
+            ColumnRule::SemanticValidator(SemanticValidator::new(
+                None,
+                SemanticFormat::Url,
+                None,
+                None
+            )),
+            "Test",
+            "Link",
+            ".containsURL(\"Link\", lambda x: x >= 1, \"check_url_Test_Link\")"
+        )]
+        #[case(ColumnRule::SemanticValidator(SemanticValidator::new(None, SemanticFormat::PhoneE164, None, None)), "Test", "Phone", ".hasPattern(\"Phone\", r\"^\\+[1-9][0-9]{1,14}$\", lambda x: x >= 1, \"check_phone_e164_Test_Phone\")")]
+        #[case(
+            ColumnRule::ColumnStatistic(ColumnStatistic::new(
+                None,
+                Statistic::NullRatio,
+                None,
+                Threshold::Compare(ThresholdOperator::LessEqual, 0.05)
+            )),
+            "Test",
+            "Id",
+            ".hasCompleteness(\"Id\", lambda x: x >= 0.95, \"check_null_ratio_Test_Id\")"
+        )]
+        #[case(ColumnRule::ColumnStatistic(ColumnStatistic::new(None, Statistic::Distinctness, None, 0.8.into())), "Test", "Id",
+            ".hasDistinctness([\"Id\"], lambda x: x >= 0.8, \"check_distinctness_Test_Id\")")]
+        #[case(ColumnRule::ColumnStatistic(ColumnStatistic::new(None, Statistic::UniqueValueRatio, None, Threshold::Compare(ThresholdOperator::Greater, 0.5))), "Test", "Id",
+            ".hasUniqueValueRatio([\"Id\"], lambda x: x > 0.5, \"check_unique_value_ratio_Test_Id\")")]
+        #[case(
+            ColumnRule::ColumnStatistic(ColumnStatistic::new(
+                None,
+                Statistic::Entropy,
+                None,
+                Threshold::Compare(ThresholdOperator::Greater, 2.5)
+            )),
+            "Test",
+            "Id",
+            ".hasEntropy(\"Id\", lambda x: x > 2.5, \"check_entropy_Test_Id\")"
+        )]
+        #[case(ColumnRule::ColumnStatistic(ColumnStatistic::new(None, Statistic::ApproxDistinct, None, Threshold::Between(10.0, 20.0))), "Test", "Id",
+            ".hasApproxCountDistinct(\"Id\", lambda x: 10 <= x <= 20, \"check_approx_distinct_Test_Id\")")]
+        #[case(
+            ColumnRule::MinLength(MinLength::new(None, 3, None)),
+            "Test",
+            "Code",
+            ".hasMinLength(\"Code\", lambda x: x >= 3, \"check_min_length_Test_Code\")"
+        )]
+        #[case(
+            ColumnRule::MaxLength(MaxLength::new(None, 20, None)),
+            "Test",
+            "Code",
+            ".hasMaxLength(\"Code\", lambda x: x <= 20, \"check_max_length_Test_Code\")"
+        )]
+        #[case(ColumnRule::ColumnSequence(ColumnSequence::new(None, Sequence::NoGaps, None, None)), "Test", "Id",
+            ".satisfies(\"NOT coalesce(Id > Id_previous_no_gaps + 1, false)\", \"check_no_gaps_Test_Id\", lambda x: x >= 1)")]
+        #[case(ColumnRule::IntegerRange(IntegerRange::new(None, -128, 127, None)), "Test", "Quantity",
+            ".satisfies(\"Quantity IS NULL OR (Quantity BETWEEN -128 AND 127)\", \"check_integer_range_Test_Quantity\", lambda x: x >= 1)")]
+        #[case(ColumnRule::NumericPrecision(NumericPrecision::new(None, 10, 2, None)), "Test", "Price",
+            ".satisfies(\"Price IS NULL OR (abs(Price) < 1E8 AND Price = round(Price, 2))\", \"check_precision_Test_Price\", lambda x: x >= 1)")]
+        #[case(ColumnRule::ValueDistribution(ValueDistribution::new(None, Distribution::Categories(vec![
+            CategoryRatio { category: Category::String("web".to_owned()), ratio: Threshold::Between(0.4, 0.6) },
+            CategoryRatio { category: Category::Integer(3), ratio: Threshold::Between(0.0, 0.1) },
+        ]), None)), "Test", "Channel",
+            ".satisfies(\"Channel = 'web'\", \"check_distribution_Test_Channel_web\", lambda x: 0.4 <= x <= 0.6)\n            .satisfies(\"Channel = 3\", \"check_distribution_Test_Channel_3\", lambda x: 0 <= x <= 0.1)")]
+        #[case(ColumnRule::ValueDistribution(ValueDistribution::new(None, Distribution::MaxCategoryRatio(0.8), None)), "Test", "Channel",
+            ".satisfies(\"Channel_category_ratio <= 0.8\", \"check_max_category_ratio_Test_Channel\", lambda x: x >= 1)")]
         pub fn test_compile_column_rule(
             #[case] column_rule: ColumnRule,
             #[case] table_name: String,
@@ -233,34 +910,196 @@ pub mod pydeequ_rule {
             let result = compile_column_rule(column_rule, table_name, column_name);
             assert_eq!(result, expected);
         }
+
+        #[test]
+        pub fn test_compile_value_set_rule() {
+            let compiled = compile_column_rule(
+                ColumnRule::SemanticValidator(SemanticValidator::new(
+                    None,
+                    SemanticFormat::IsoCurrency,
+                    None,
+                    None,
+                )),
+                "Test".to_owned(),
+                "Currency".to_owned(),
+            );
+            assert!(compiled.starts_with(".isContainedIn(\"Currency\", [\"AED\", \"AFN\","));
+            assert!(compiled
+                .ends_with("\"ZWL\"], lambda x: x >= 1, \"check_iso_currency_Test_Currency\")"));
+        }
+
+        #[rstest]
+        #[case(TableRule::PrimaryKey(PrimaryKey::new(None, vec!["OrderId".to_owned(), "LineNo".to_owned()], None)), "Test",
+            ".isPrimaryKey(\"OrderId\", \"LineNo\", hint=\"check_primary_key_Test_OrderId_LineNo\")")]
+        #[case(TableRule::UniqueKey(UniqueKey::new(None, vec!["OrderId".to_owned(), "LineNo".to_owned()], None)), "Test",
+            ".hasUniqueness([\"OrderId\", \"LineNo\"], lambda x: x == 1, \"check_uniqueness_Test_OrderId_LineNo\")")]
+        #[case(
+            TableRule::Volume(Volume::new(None, RowCount::Between(1000, 5_000_000), None)),
+            "Test",
+            ".hasSize(lambda x: 1000 <= x <= 5000000, \"check_rows_Test\")"
+        )]
+        #[case(
+            TableRule::Volume(Volume::new(
+                None,
+                RowCount::Compare(ThresholdOperator::Equal, 0),
+                None
+            )),
+            "Test",
+            ".hasSize(lambda x: x == 0, \"check_rows_Test\")"
+        )]
+        #[case(
+            TableRule::Correlation(Correlation::new(
+                None,
+                "Price".to_owned(),
+                "Quantity".to_owned(),
+                None,
+                0.7.into()
+            )),
+            "Test",
+            ".hasCorrelation(\"Price\", \"Quantity\", lambda x: x >= 0.7, \"check_correlation_Test_Price_Quantity\")"
+        )]
+        pub fn test_compile_table_rule(
+            #[case] table_rule: TableRule,
+            #[case] table_name: String,
+            #[case] expected: String,
+        ) {
+            let result = compile_table_rule(table_rule, table_name);
+            assert_eq!(result, expected);
+        }
+    }
+}
+
+/// PyDeequ check level of a severity, PyDeequ has no info level so info rules are emitted as
+/// warnings and only differ in the `severity` column of the result.
+fn check_level(severity: Severity) -> String {
+    match severity {
+        Severity::Error => String::from("Error"),
+        Severity::Warning | Severity::Info => String::from("Warning"),
+    }
+}
+
+/// Checks of a filter group with the same severity, emitted as one PyDeequ `Check`. Sampled
+/// checks are emitted as separate `Check` that runs on a sample of the rows.
+#[derive(Serialize)]
+pub struct SeverityChecks {
+    pub severity: String,
+    pub level: String,
+    pub sample_fraction: Option<f64>,
+    pub checks: Vec<String>,
+}
+
+/// Column that is added to the data frame before the checks run, e.g. the previous value of a
+/// sequence that is compared by a `satisfies` check.
+#[derive(Serialize)]
+pub struct WindowColumn {
+    pub name: String,
+    pub expression: String,
+}
+
+/// Python function of a `-UDF` rule that is imported and registered as Spark UDF under its
+/// SQL name.
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UdfImport {
+    pub module: String,
+    pub function: String,
+    pub sql_name: String,
+}
+
+impl UdfImport {
+    /// Functions used by the rules of the table, each function is imported once.
+    pub fn from_table(table: &TableDef) -> Vec<Self> {
+        let mut imports = table
+            .columns
+            .iter()
+            .flat_map(|column| &column.rules)
+            .flat_map(|rule_filter| &rule_filter.rules)
+            .filter_map(|rule| match rule {
+                ColumnRule::UserFunction(UserFunction {
+                    signature: Some(signature),
+                    ..
+                }) => Some(UdfImport {
+                    module: signature.module.clone(),
+                    function: signature.function.clone(),
+                    sql_name: signature.sql_name(),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<UdfImport>>();
+        imports.sort();
+        imports.dedup();
+        imports
     }
 }
 
+/// Rules that are compiled to a constraint of a PyDeequ check, custom SQL and anomaly rules are
+/// evaluated by their own functions.
+fn is_check_constraint(rule: &ColumnRule) -> bool {
+    !rule.is_custom_sql() && !rule.is_anomaly()
+}
+
 #[derive(Serialize)]
 pub struct ColumnLevelFilter {
     pub has_filter: bool,
-    pub checks: Vec<String>,
+    pub severity_checks: Vec<SeverityChecks>,
+    pub window_columns: Vec<WindowColumn>,
     pub filter: String,
     pub description: String,
 }
 
 impl ColumnLevelFilter {
     pub fn new(filter_rules: ColumnRuleFilter, column: &ColumnDef, table: &TableDef) -> Self {
-        let mut checks = vec![String::new(); filter_rules.rules.len()];
-
-        for (i, rule) in filter_rules.rules.iter().enumerate() {
-            let rule_copy = rule.clone();
+        let mut checks_by_severity: BTreeMap<(Severity, bool), Vec<String>> = BTreeMap::new();
 
+        for rule in filter_rules
+            .rules
+            .iter()
+            .filter(|rule| is_check_constraint(rule))
+        {
             let table_name = table.table_ref.to_string();
             let column_name = column.name.clone();
-            checks[i] = compile_column_rule(rule_copy, table_name, column_name);
+            checks_by_severity
+                .entry((rule.severity(table.severity), rule.is_sampled()))
+                .or_default()
+                .push(compile_column_rule(rule.clone(), table_name, column_name));
         }
 
+        let severity_checks = checks_by_severity
+            .into_iter()
+            .map(|((severity, sampled), checks)| SeverityChecks {
+                severity: severity.to_string(),
+                level: check_level(severity),
+                sample_fraction: sampled.then_some(SAMPLE_FRACTION),
+                checks,
+            })
+            .collect::<Vec<SeverityChecks>>();
+
+        let window_columns = filter_rules
+            .rules
+            .iter()
+            .filter_map(|rule| match rule {
+                ColumnRule::ColumnSequence(rule) => Some(WindowColumn {
+                    name: rule.previous_column(&column.name),
+                    expression: rule.previous_value(&column.name),
+                }),
+                ColumnRule::ValueDistribution(
+                    rule @ ValueDistribution {
+                        distribution: Distribution::MaxCategoryRatio(_),
+                        ..
+                    },
+                ) => Some(WindowColumn {
+                    name: rule.ratio_column(&column.name),
+                    expression: rule.ratio_value(&column.name),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<WindowColumn>>();
+
         let filter = filter_rules.filter_string.unwrap_or_default();
 
         Self {
             has_filter: !filter.is_empty(),
-            checks,
+            severity_checks,
+            window_columns,
             filter: filter.clone(),
             description: format!(
                 "Autogenerated check for column level rules for table {} and column {} with filter {}",
@@ -301,7 +1140,238 @@ impl ColumnLevelCheck {
     }
 }
 
-pub fn compile_column_level_checks(columns: Vec<ColumnDef>, table: &TableDef) -> String {
+/// Table level rules with the same severity, emitted as one PyDeequ `Check`.
+#[derive(Serialize)]
+pub struct TableSeverityChecks {
+    pub description: String,
+    pub severity: String,
+    pub level: String,
+    pub checks: Vec<String>,
+}
+
+/// Table level rules, the checks of all severities and the anomaly checks are run in one
+/// verification suite, so their metrics are saved once per run.
+#[derive(Serialize)]
+pub struct TableLevelCheck {
+    pub ext_table_name: String,
+    pub severity_checks: Vec<TableSeverityChecks>,
+    pub anomaly_checks: Vec<AnomalyCheck>,
+    /// the row count of the previous run is loaded from the metrics repository
+    pub run_over_run: bool,
+}
+
+impl TableLevelCheck {
+    pub fn new(table: &TableDef) -> Self {
+        let mut checks_by_severity: BTreeMap<Severity, Vec<String>> = BTreeMap::new();
+        for rule in table
+            .table_level_rules
+            .iter()
+            .filter(|rule| !matches!(rule, TableRule::CustomSql(_) | TableRule::Anomaly(_)))
+        {
+            checks_by_severity
+                .entry(rule.severity(table.severity))
+                .or_default()
+                .push(compile_table_rule(
+                    rule.clone(),
+                    table.table_ref.to_string(),
+                ));
+        }
+
+        let severity_checks = checks_by_severity
+            .into_iter()
+            .map(|(severity, checks)| TableSeverityChecks {
+                description: format!(
+                    "Autogenerated check for {} table level rules for table {}",
+                    severity, table.table_ref
+                ),
+                severity: severity.to_string(),
+                level: check_level(severity),
+                checks,
+            })
+            .collect::<Vec<TableSeverityChecks>>();
+
+        Self {
+            ext_table_name: table.table_ref.to_string(),
+            severity_checks,
+            anomaly_checks: AnomalyCheck::from_table(table),
+            run_over_run: table
+                .table_level_rules
+                .iter()
+                .any(|rule| matches!(rule, TableRule::Volume(rule) if rule.is_run_over_run())),
+        }
+    }
+}
+
+/// Custom SQL rule that is evaluated with Spark SQL, its result has the columns of the PyDeequ
+/// check results.
+#[derive(Serialize)]
+pub struct CustomSqlCheck {
+    pub description: String,
+    pub constraint_name: String,
+    pub aggregate: String,
+    pub assertion: String,
+    pub filter: String,
+    pub check_category: String,
+    pub columns: String,
+    pub severity: String,
+    pub level: String,
+}
+
+impl CustomSqlCheck {
+    fn new(
+        rule: &CustomSql,
+        check_category: &str,
+        filter: String,
+        columns: String,
+        severity: Severity,
+    ) -> Self {
+        let (aggregate, threshold) = rule.assertion();
+        Self {
+            description: format!("Autogenerated custom SQL check for {}", columns),
+            constraint_name: format!("check_custom_sql_{}", columns.replace('.', "_")),
+            aggregate: aggregate.to_string(),
+            assertion: assertion(&threshold),
+            check_category: check_category.to_owned(),
+            filter,
+            columns,
+            severity: severity.to_string(),
+            level: check_level(severity),
+        }
+    }
+
+    /// Custom SQL rules of the columns followed by the ones of the table.
+    pub fn from_table(table: &TableDef) -> Vec<Self> {
+        let mut checks: Vec<Self> = vec![];
+        for column in &table.columns {
+            for rule_filter in &column.rules {
+                for rule in &rule_filter.rules {
+                    if let ColumnRule::CustomSql(custom_sql) = rule {
+                        checks.push(Self::new(
+                            custom_sql,
+                            "column level",
+                            rule_filter
+                                .filter_string
+                                .as_deref()
+                                .unwrap_or_default()
+                                .trim()
+                                .to_owned(),
+                            format!("{}.{}", table.table_ref, column.name),
+                            rule.severity(table.severity),
+                        ));
+                    }
+                }
+            }
+        }
+        for rule in &table.table_level_rules {
+            if let TableRule::CustomSql(custom_sql) = rule {
+                checks.push(Self::new(
+                    custom_sql,
+                    "table level",
+                    String::new(),
+                    table.table_ref.to_string(),
+                    rule.severity(table.severity),
+                ));
+            }
+        }
+        checks
+    }
+}
+
+/// Anomaly rule that is checked against the history of its metric in the metrics repository.
+#[derive(Serialize)]
+pub struct AnomalyCheck {
+    pub description: String,
+    pub strategy: String,
+    pub analyzer: String,
+    pub filter: String,
+    pub check_category: String,
+    pub columns: String,
+    pub severity: String,
+    pub level: String,
+}
+
+impl AnomalyCheck {
+    fn new(
+        rule: &Anomaly,
+        column_name: Option<&str>,
+        filter: String,
+        columns: String,
+        severity: Severity,
+    ) -> Self {
+        Self {
+            // the description identifies the results of the check in the verification suite
+            description: match filter.as_str() {
+                "" => format!(
+                    "Autogenerated anomaly check of {} {} for {}",
+                    rule.metric, rule.strategy, columns
+                ),
+                filter => format!(
+                    "Autogenerated anomaly check of {} {} for {} where {}",
+                    rule.metric, rule.strategy, columns, filter
+                ),
+            },
+            strategy: anomaly_strategy(&rule.strategy),
+            analyzer: anomaly_analyzer(
+                rule.metric,
+                column_name,
+                Some(filter.as_str()).filter(|filter| !filter.is_empty()),
+            ),
+            check_category: if column_name.is_some() {
+                "column level"
+            } else {
+                "table level"
+            }
+            .to_owned(),
+            filter,
+            columns,
+            severity: severity.to_string(),
+            level: check_level(severity),
+        }
+    }
+
+    /// Anomaly rules of the columns followed by the ones of the table.
+    pub fn from_table(table: &TableDef) -> Vec<Self> {
+        let mut checks: Vec<Self> = vec![];
+        for column in &table.columns {
+            for rule_filter in &column.rules {
+                for rule in &rule_filter.rules {
+                    if let ColumnRule::Anomaly(anomaly) = rule {
+                        checks.push(Self::new(
+                            anomaly,
+                            Some(&column.name),
+                            rule_filter
+                                .filter_string
+                                .as_deref()
+                                .unwrap_or_default()
+                                .trim()
+                                .to_owned(),
+                            format!("{}.{}", table.table_ref, column.name),
+                            rule.severity(table.severity),
+                        ));
+                    }
+                }
+            }
+        }
+        for rule in &table.table_level_rules {
+            if let TableRule::Anomaly(anomaly) = rule {
+                checks.push(Self::new(
+                    anomaly,
+                    None,
+                    String::new(),
+                    table.table_ref.to_string(),
+                    rule.severity(table.severity),
+                ));
+            }
+        }
+        checks
+    }
+}
+
+pub fn compile_column_level_checks(
+    columns: Vec<ColumnDef>,
+    table: &TableDef,
+    metrics_repository: Option<&str>,
+) -> String {
     let tera = match Tera::new("templates/gen_pyspark/**/*.py") {
         Ok(t) => t,
         Err(e) => {
@@ -314,31 +1384,86 @@ pub fn compile_column_level_checks(columns: Vec<ColumnDef>, table: &TableDef) ->
 
     let column_level_checks: Vec<ColumnLevelCheck> = columns
         .iter()
-        .filter(|column| !column.rules.is_empty())
+        .filter(|column| {
+            column
+                .rules
+                .iter()
+                .any(|rule_filter| rule_filter.rules.iter().any(is_check_constraint))
+        })
         .map(|column| ColumnLevelCheck::new(column.clone(), table))
         .collect();
 
     context.insert("column_level_checks", &column_level_checks);
+    context.insert("udfs", &UdfImport::from_table(table));
+    if let Some(metrics_repository) = metrics_repository {
+        context.insert("metrics_repository", metrics_repository);
+    }
+
+    let table_level_check = TableLevelCheck::new(table);
+    if !table_level_check.severity_checks.is_empty() || !table_level_check.anomaly_checks.is_empty()
+    {
+        context.insert("table_level_check", &table_level_check);
+    }
+
+    let custom_sql_checks = CustomSqlCheck::from_table(table);
+    if !custom_sql_checks.is_empty() {
+        context.insert("custom_sql_checks", &custom_sql_checks);
+    }
+
+    context.insert(
+        "anomaly_checks",
+        &!table_level_check.anomaly_checks.is_empty(),
+    );
 
     tera.render("column_level_check.py", &context)
         .unwrap()
         .replace('\r', "")
 }
 
-pub fn compile(table: TableDef) -> String {
-    compile_column_level_checks(table.columns.clone(), &table)
+/// Compiles the checks of a table. The metrics of the table level checks are saved to the metrics
+/// repository if one is given, run-over-run and anomaly rules need it to load the history.
+pub fn compile(table: TableDef, metrics_repository: Option<&str>) -> Result<String, CompileError> {
+    let table_level_check = TableLevelCheck::new(&table);
+    if table_level_check.run_over_run && metrics_repository.is_none() {
+        return Err(CompileError::UnsupportedByTarget {
+            target: CompilationTarget::PyDeequ,
+            message: format!(
+                "row count of table {} is compared with the previous run, which needs a metrics repository",
+                table.table_ref
+            ),
+        });
+    }
+    if !table_level_check.anomaly_checks.is_empty() && metrics_repository.is_none() {
+        return Err(CompileError::UnsupportedByTarget {
+            target: CompilationTarget::PyDeequ,
+            message: format!(
+                "anomaly rules of table {} are checked against the history of their metrics, which needs a metrics repository",
+                table.table_ref
+            ),
+        });
+    }
+    Ok(compile_column_level_checks(
+        table.columns.clone(),
+        &table,
+        metrics_repository,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
 
+    use crate::compiler::pydeequ::ColumnLevelFilter;
     use crate::compiler::test_strings::pydeequ::PYTHON_PYDEEQU_RESULT_1;
     use crate::model::column_rule::{
-        ColumnRule, ContainsValue, IsType, LikePattern, NonNull, NotEmpty, RegexPattern, Uniqueness,
+        ColumnRule, ColumnSequence, ContainsValue, IsType, LikePattern, NonNull, NotEmpty,
+        RegexPattern, Uniqueness,
     };
+    use crate::model::rule_ext_config::Severity;
     use crate::model::rule_filter::filter::{ColumnRuleFilter, FilterCondition};
     use crate::model::rule_filter::operator::ComparisonOperator;
+    use crate::model::sequence::Sequence;
     use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+    use crate::model::table_rule::{PrimaryKey, TableRule};
 
     #[test]
     pub fn compile_test() {
@@ -356,12 +1481,12 @@ mod tests {
                             vec![
                                 ColumnRule::LikePattern(LikePattern {
                                     pattern: "%test%".to_string(),
-                                    threshold: 0.5,
+                                    threshold: 0.5.into(),
                                     ..Default::default()
                                 }),
                                 ColumnRule::ContainsValue(ContainsValue {
                                     value: "test".to_string(),
-                                    threshold: 0.9,
+                                    threshold: 0.9.into(),
                                     ..Default::default()
                                 }),
                             ],
@@ -383,7 +1508,7 @@ mod tests {
                                     ..Default::default()
                                 }),
                                 ColumnRule::NotEmpty(NotEmpty {
-                                    threshold: 0.9,
+                                    threshold: 0.9.into(),
                                     ..Default::default()
                                 }),
                                 ColumnRule::Uniqueness(Uniqueness {
@@ -392,6 +1517,7 @@ mod tests {
                             ],
                         ),
                     ],
+                    ..Default::default()
                 },
                 ColumnDef {
                     name: "Price".to_string(),
@@ -417,21 +1543,22 @@ mod tests {
                             vec![
                                 ColumnRule::LikePattern(LikePattern {
                                     pattern: "%test%".to_string(),
-                                    threshold: 0.5,
+                                    threshold: 0.5.into(),
                                     ..LikePattern::default()
                                 }),
                                 ColumnRule::RegexPattern(RegexPattern {
                                     pattern: "[0-9]*test[0-9]*".to_string(),
-                                    threshold: 0.75,
+                                    threshold: 0.75.into(),
                                     ..Default::default()
                                 }),
                                 ColumnRule::NotEmpty(NotEmpty {
-                                    threshold: 0.75,
+                                    threshold: 0.75.into(),
                                     ..Default::default()
                                 }),
                             ],
                         ),
                     ],
+                    ..Default::default()
                 },
                 ColumnDef {
                     name: "Test".to_string(),
@@ -439,11 +1566,259 @@ mod tests {
                     not_null: false,
                     primary_key: false,
                     rules: vec![],
+                    ..Default::default()
                 },
             ],
+            table_level_rules: vec![],
+            ..Default::default()
         };
 
-        let compiled = crate::compiler::pydeequ::compile(table);
+        let compiled = crate::compiler::pydeequ::compile(table, None).unwrap();
         assert_eq!(PYTHON_PYDEEQU_RESULT_1, compiled);
     }
+
+    #[test]
+    pub fn compile_table_level_test() {
+        let table = TableDef {
+            table_ref: TableRef::new("OrderLines", None, None),
+            columns: vec![],
+            table_level_rules: vec![TableRule::PrimaryKey(PrimaryKey::new(
+                None,
+                vec!["OrderId".to_owned(), "LineNo".to_owned()],
+                None,
+            ))],
+            ..Default::default()
+        };
+
+        let compiled = crate::compiler::pydeequ::compile(table, None).unwrap();
+        assert!(compiled.contains("def check_table_level("));
+        assert!(compiled.contains(
+            ".isPrimaryKey(\"OrderId\", \"LineNo\", hint=\"check_primary_key_OrderLines_OrderId_LineNo\")"
+        ));
+        assert!(compiled.contains("    return combined_result_df, failed_checks\n\n\nif __name__"));
+    }
+
+    #[test]
+    pub fn compile_severity_test() {
+        let column = ColumnDef::new(
+            "Id".to_owned(),
+            DataType::new("VarChar", Some(3), None),
+            false,
+            false,
+        );
+        let table = TableDef {
+            table_ref: TableRef::new("Test", None, None),
+            table_level_rules: vec![TableRule::PrimaryKey(PrimaryKey::new(
+                None,
+                vec!["Id".to_owned()],
+                None,
+            ))],
+            severity: Severity::Error,
+            ..Default::default()
+        };
+        let rule_filter = ColumnRuleFilter::new(
+            None,
+            vec![
+                ColumnRule::NotEmpty(NotEmpty::new(None, None, None)),
+                ColumnRule::Uniqueness(Uniqueness::new(None, None)).with_severity(Severity::Info),
+            ],
+        );
+
+        let filter = ColumnLevelFilter::new(rule_filter, &column, &table);
+        let levels = filter
+            .severity_checks
+            .iter()
+            .map(|checks| {
+                (
+                    checks.severity.as_str(),
+                    checks.level.as_str(),
+                    checks.checks.len(),
+                )
+            })
+            .collect::<Vec<(&str, &str, usize)>>();
+        assert_eq!(levels, vec![("error", "Error", 1), ("info", "Warning", 1)]);
+
+        let compiled = crate::compiler::pydeequ::compile(table, None).unwrap();
+        assert!(compiled.contains("Check(spark_session, CheckLevel.Error,"));
+        assert!(compiled.contains(".withColumn(\"severity\", lit(\"error\")))"));
+    }
+
+    #[test]
+    pub fn compile_table_rule_severity_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE TABLE Orders {Id INT(10), PRIMARY KEY (Id) @severity(error), -ROWS > 0};",
+        )
+        .unwrap();
+        let compiled = crate::compiler::pydeequ::compile(catalog.tables[0].clone(), None).unwrap();
+
+        assert_eq!(
+            compiled.matches(".addCheck(\n            Check(").count(),
+            2
+        );
+        assert!(compiled.contains(
+            "Check(spark_session, CheckLevel.Error,\n                  \"Autogenerated check for error table level rules for table Orders\")\n            .isPrimaryKey("
+        ));
+        assert!(compiled.contains(
+            "result_df.filter(result_df.check == \"Autogenerated check for warning table level rules for table Orders\")"
+        ));
+        assert!(compiled.contains(".withColumn(\"severity\", lit(\"warning\")))"));
+    }
+
+    #[test]
+    pub fn compile_sequence_test() {
+        let mut column = ColumnDef::new(
+            "Id".to_owned(),
+            DataType::new("BigInt", Some(20), None),
+            false,
+            false,
+        );
+        column.rules = vec![ColumnRuleFilter::new(
+            None,
+            vec![ColumnRule::ColumnSequence(ColumnSequence::new(
+                None,
+                Sequence::Increasing,
+                None,
+                None,
+            ))],
+        )];
+        let table = TableDef {
+            table_ref: TableRef::new("Test", None, None),
+            columns: vec![column],
+            ..Default::default()
+        };
+
+        let compiled = crate::compiler::pydeequ::compile(table, None).unwrap();
+        assert!(compiled.contains(
+            ".onData(data_frame.withColumn(\"Id_previous_increasing\", expr(\"lag(Id) over (order by monotonically_increasing_id())\")))"
+        ));
+    }
+
+    #[test]
+    pub fn compile_custom_sql_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE TABLE Test {
+                Price INT(10) {-SQL \"count(*) filter (where Price > Total) = 0\" | Price > 1,},
+                Total INT(10),
+                -SQL \"sum(Total) >= 100\"
+            };",
+        )
+        .unwrap();
+        let compiled = crate::compiler::pydeequ::compile(catalog.tables[0].clone(), None).unwrap();
+
+        assert!(compiled.contains(
+            "value = data_frame.filter(\"Price > 1\").selectExpr(\"count(*) FILTER (WHERE (Price > Total)) AS value\").first()[\"value\"]"
+        ));
+        assert!(compiled
+            .contains("value = data_frame.selectExpr(\"sum(Total) AS value\").first()[\"value\"]"));
+        assert!(compiled.contains("\"check_custom_sql_Test_Price\", value, lambda x: x == 0,"));
+        assert!(compiled
+            .contains("is_success, result_df = check_custom_sql(data_frame, spark_session)"));
+        assert!(!compiled.contains("def check_table_level("));
+    }
+
+    #[test]
+    pub fn compile_user_function_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE FUNCTION checks.luhn(VARCHAR, VARCHAR) RETURNS BOOL;
+            CREATE TABLE Test {
+                Iban VARCHAR(34) {-UDF checks.luhn(\"DE\") 0.9, -UDF checks.luhn(\"AT\")}
+            };",
+        )
+        .unwrap();
+        let compiled = crate::compiler::pydeequ::compile(catalog.tables[0].clone(), None).unwrap();
+
+        assert_eq!(
+            compiled
+                .matches("from checks import luhn as checks_luhn\n")
+                .count(),
+            1
+        );
+        assert!(compiled
+            .contains("spark_session.udf.register(\"checks_luhn\", checks_luhn, BooleanType())"));
+        assert!(compiled.contains(
+            ".satisfies(\"checks_luhn(Iban, 'DE')\", \"check_udf_checks_luhn_Test_Iban\", lambda x: x >= 0.9)"
+        ));
+    }
+
+    #[test]
+    pub fn compile_run_over_run_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE TABLE Orders {Id INT(10), -ROWS >= 0.9 * previous_run};",
+        )
+        .unwrap();
+        let table = catalog.tables[0].clone();
+        assert!(crate::compiler::pydeequ::compile(table.clone(), None).is_err());
+
+        let compiled =
+            crate::compiler::pydeequ::compile(table, Some("s3://dq/metrics.json")).unwrap();
+        assert!(compiled.contains("METRICS_REPOSITORY = \"s3://dq/metrics.json\""));
+        assert!(compiled.contains("previous_size = load_previous_size(spark_session, \"Orders\")"));
+        assert!(compiled.contains(
+            ".onData(data_frame).useRepository(repository).saveOrAppendResult(result_key).addCheck("
+        ));
+        assert!(compiled.contains(
+            ".hasSize(lambda x: previous_size is None or x >= 0.9 * previous_size, \"check_rows_Orders\")"
+        ));
+    }
+
+    #[test]
+    pub fn compile_anomaly_test() {
+        let catalog = crate::parser::parse_catalog(
+            "CREATE TABLE Orders {
+                Id INT(10),
+                Amount DECIMAL(10, 2) {-ANOMALY completeness RATE_OF_CHANGE 0.1 | Id > 0, -ANOMALY mean RATE_OF_CHANGE 0.3},
+                -ANOMALY row_count ABSOLUTE_CHANGE 1000
+            };",
+        )
+        .unwrap();
+        let table = catalog.tables[0].clone();
+        assert!(crate::compiler::pydeequ::compile(table.clone(), None).is_err());
+
+        let compiled =
+            crate::compiler::pydeequ::compile(table, Some("s3://dq/metrics.json")).unwrap();
+        assert!(compiled.contains("from pydeequ.verification import AnomalyCheckConfig"));
+        assert!(compiled.contains(".addAnomalyCheck(\n            RelativeRateOfChangeStrategy(maxRateDecrease=0.9, maxRateIncrease=1.1), Completeness(\"Amount\", where=\"Id > 0\"),"));
+        assert!(compiled.contains(".addAnomalyCheck(\n            RelativeRateOfChangeStrategy(maxRateDecrease=0.7, maxRateIncrease=1.3), Mean(\"Amount\"),"));
+        assert!(compiled.contains(".addAnomalyCheck(\n            AbsoluteChangeStrategy(maxRateDecrease=-1000, maxRateIncrease=1000), Size(),"));
+        assert!(compiled.contains(
+            "result_df.filter(result_df.check == \"Autogenerated anomaly check of completeness RATE_OF_CHANGE 0.1 for Orders.Amount where Id > 0\")"
+        ));
+        // all metrics of a run are saved with the same result key
+        assert_eq!(compiled.matches("ResultKey(spark_session").count(), 1);
+        assert_eq!(compiled.matches(".useRepository(repository)").count(), 1);
+        assert!(!compiled.contains("check_anomalies"));
+    }
+
+    #[test]
+    pub fn compile_sampled_uniqueness_test() {
+        let mut column = ColumnDef::new(
+            "Id".to_owned(),
+            DataType::new("VarChar", Some(3), None),
+            true,
+            false,
+        );
+        column.rules = vec![ColumnRuleFilter::new(
+            None,
+            vec![
+                ColumnRule::NonNull(NonNull::new(None, None, None)),
+                ColumnRule::Uniqueness(Uniqueness::new(None, None).sampled()),
+            ],
+        )];
+        let table = TableDef {
+            table_ref: TableRef::new("Test", None, None),
+            columns: vec![column.clone()],
+            ..Default::default()
+        };
+
+        let filter = ColumnLevelFilter::new(column.rules[0].clone(), &column, &table);
+        let fractions = filter
+            .severity_checks
+            .iter()
+            .map(|checks| checks.sample_fraction)
+            .collect::<Vec<Option<f64>>>();
+        assert_eq!(fractions, vec![None, Some(0.1)]);
+
+        let compiled = crate::compiler::pydeequ::compile(table, None).unwrap();
+        assert!(compiled.contains(".onData(data_frame.sample(fraction=0.1, seed=42))"));
+    }
 }
@@ -9,6 +9,7 @@ pub struct PySparkDataClassColumn {
     ref_name: String,
     not_null: bool,
     data_class: DataClass,
+    default: Option<String>,
 }
 
 pub fn to_snake_case(name: &str) -> String {
@@ -53,9 +54,17 @@ pub fn compile(table_def: TableDef) -> String {
             ref_name: to_snake_case(x.name.as_str()),
             not_null: x.not_null,
             data_class: convert_string_like_type(x.data_type.class.clone()),
+            default: x
+                .default
+                .as_ref()
+                .map(|default| default.replace('"', "\\\"")),
         })
         .collect::<Vec<PySparkDataClassColumn>>();
 
+    context.insert(
+        "has_defaults",
+        &columns.iter().any(|column| column.default.is_some()),
+    );
     context.insert("columns", &columns);
     context.insert(
         "table_name",
@@ -94,12 +103,12 @@ pub mod test {
                             vec![
                                 ColumnRule::LikePattern(LikePattern {
                                     pattern: "%test%".to_string(),
-                                    threshold: 0.5,
+                                    threshold: 0.5.into(),
                                     ..Default::default()
                                 }),
                                 ColumnRule::ContainsValue(ContainsValue {
                                     value: "test".to_string(),
-                                    threshold: 0.9,
+                                    threshold: 0.9.into(),
                                     ..Default::default()
                                 }),
                             ],
@@ -121,7 +130,7 @@ pub mod test {
                                     ..Default::default()
                                 }),
                                 ColumnRule::NotEmpty(NotEmpty {
-                                    threshold: 0.9,
+                                    threshold: 0.9.into(),
                                     ..Default::default()
                                 }),
                                 ColumnRule::Uniqueness(Uniqueness {
@@ -130,6 +139,7 @@ pub mod test {
                             ],
                         ),
                     ],
+                    ..Default::default()
                 },
                 ColumnDef {
                     name: "Price".to_string(),
@@ -155,21 +165,22 @@ pub mod test {
                             vec![
                                 ColumnRule::LikePattern(LikePattern {
                                     pattern: "%test%".to_string(),
-                                    threshold: 0.5,
+                                    threshold: 0.5.into(),
                                     ..LikePattern::default()
                                 }),
                                 ColumnRule::RegexPattern(RegexPattern {
                                     pattern: "[0-9]*test[0-9]*".to_string(),
-                                    threshold: 0.75,
+                                    threshold: 0.75.into(),
                                     ..Default::default()
                                 }),
                                 ColumnRule::NotEmpty(NotEmpty {
-                                    threshold: 0.75,
+                                    threshold: 0.75.into(),
                                     ..Default::default()
                                 }),
                             ],
                         ),
                     ],
+                    ..Default::default()
                 },
                 ColumnDef {
                     name: "Test".to_string(),
@@ -177,11 +188,43 @@ pub mod test {
                     not_null: false,
                     primary_key: false,
                     rules: vec![],
+                    ..Default::default()
                 },
             ],
+            table_level_rules: vec![],
+            ..Default::default()
         };
 
         let compiled = compile(table);
         assert_eq!(PYSPARK_CLASS_EXPECTED_TEST_1, compiled);
     }
+
+    #[test]
+    fn test_compile_defaults() {
+        let table = TableDef {
+            table_ref: TableRef::new("Orders", None, None),
+            columns: vec![
+                ColumnDef {
+                    name: "Status".to_string(),
+                    data_type: DataType::new("VarChar", Some(10), None),
+                    default: Some("'open'".to_string()),
+                    ..Default::default()
+                },
+                ColumnDef {
+                    name: "Price".to_string(),
+                    data_type: DataType::new("Double", Some(10), Some(2)),
+                    ..Default::default()
+                },
+            ],
+            table_level_rules: vec![],
+            ..Default::default()
+        };
+
+        let compiled = compile(table);
+        assert!(compiled.contains("from pyspark.sql.functions import coalesce, col, expr"));
+        assert!(compiled.contains(
+            "        self._defaults = {\n            \"Status\": \"'open'\",\n        }\n"
+        ));
+        assert!(compiled.contains("    def fill_defaults(self) -> \"Orders\":"));
+    }
 }
@@ -1,7 +1,7 @@
 #[cfg(test)]
 pub const PYTHON_PYDEEQU_RESULT_1: &str = "from pyspark.sql import SparkSession, DataFrame
 from pydeequ import deequ_maven_coord, f2j_maven_coord
-from pyspark.sql.functions import lit
+from pyspark.sql.functions import expr, lit
 from pydeequ.checks import Check, CheckLevel, ConstrainableDataTypes
 from pydeequ.verification import VerificationSuite, VerificationResult
 
@@ -22,7 +22,8 @@ def column_level_checks_id(data_frame: DataFrame, spark_session: SparkSession) -
         result_df = VerificationResult.checkResultsAsDataFrame(spark_session, check_result)
         result_df = (result_df.withColumn(\"check_category\", lit(\"column level\"))
                      .withColumn(\"columns\", lit(\"test.id\"))
-                     .withColumn(\"filter\", lit(\"Price > 1\")))
+                     .withColumn(\"filter\", lit(\"Price > 1\"))
+                     .withColumn(\"severity\", lit(\"warning\")))
         data_frames.append(result_df)
         
         check = Check(spark_session, CheckLevel.Warning,
@@ -39,7 +40,8 @@ def column_level_checks_id(data_frame: DataFrame, spark_session: SparkSession) -
         result_df = VerificationResult.checkResultsAsDataFrame(spark_session, check_result)
         result_df = (result_df.withColumn(\"check_category\", lit(\"column level\"))
                      .withColumn(\"columns\", lit(\"test.id\"))
-                     .withColumn(\"filter\", lit(\"\")))
+                     .withColumn(\"filter\", lit(\"\"))
+                     .withColumn(\"severity\", lit(\"warning\")))
         data_frames.append(result_df)
         
         final_df = None
@@ -69,7 +71,8 @@ def column_level_checks_price(data_frame: DataFrame, spark_session: SparkSession
         result_df = VerificationResult.checkResultsAsDataFrame(spark_session, check_result)
         result_df = (result_df.withColumn(\"check_category\", lit(\"column level\"))
                      .withColumn(\"columns\", lit(\"test.price\"))
-                     .withColumn(\"filter\", lit(\"Price > 1\")))
+                     .withColumn(\"filter\", lit(\"Price > 1\"))
+                     .withColumn(\"severity\", lit(\"warning\")))
         data_frames.append(result_df)
         
         check = Check(spark_session, CheckLevel.Warning,
@@ -84,7 +87,8 @@ def column_level_checks_price(data_frame: DataFrame, spark_session: SparkSession
         result_df = VerificationResult.checkResultsAsDataFrame(spark_session, check_result)
         result_df = (result_df.withColumn(\"check_category\", lit(\"column level\"))
                      .withColumn(\"columns\", lit(\"test.price\"))
-                     .withColumn(\"filter\", lit(\"\")))
+                     .withColumn(\"filter\", lit(\"\"))
+                     .withColumn(\"severity\", lit(\"warning\")))
         data_frames.append(result_df)
         
         final_df = None
@@ -1,10 +1,20 @@
 use crate::compiler::dqdl;
+use crate::compiler::explain;
+use crate::compiler::open_lineage;
 use crate::compiler::pydeequ;
 use crate::compiler::pyspark_class;
-use crate::compiler::CompilationTarget;
+use crate::compiler::{CompilationTarget, CompileError};
+use crate::model::catalog::Catalog;
+use crate::model::pattern::pattern_warnings;
+use crate::model::pragma::metrics_repository;
+use crate::model::table_expr::TableDef;
+use crate::parser::error_utils::DDLxParseError;
+use crate::parser::project::Project;
 use clap::Parser;
-use std::fs::{read_to_string, File};
+use std::fs::{read_dir, read_to_string, File};
 use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::exit;
 
 mod compiler;
 mod model;
@@ -14,62 +24,199 @@ mod parser;
 #[command(version, about, long_about = None)]
 struct Args {
     /// Compilation Target
-    #[arg(short, long, value_enum)]
+    #[arg(short, long, value_enum, default_value_t)]
     target: CompilationTarget,
 
     /// Input string for compilation, will prefer input file over input string
     #[arg(short = 's', long, default_value_t = String::new())]
     input_string: String,
 
-    /// Input file path for compilation
-    #[arg(short = 'f', long, default_value_t = String::new())]
-    input_file: String,
+    /// Input file paths for compilation, files are read in order, e.g. a create script
+    /// followed by its migration scripts. Imported files are read before the importing file
+    #[arg(short = 'f', long)]
+    input_file: Vec<String>,
+
+    /// Project directory, all `.ddlx` and `.sql` files of the directory are compiled together
+    #[arg(short = 'p', long)]
+    project: Option<String>,
 
     /// Output file path for compilation
     #[arg(short = 'o', long, default_value_t = String::new())]
     output_file: String,
+
+    /// Path of the PyDeequ metrics repository, the metrics of each run are saved to it so that
+    /// run-over-run rules like `-ROWS >= 0.9 * previous_run` and `-ANOMALY` rules can compare with
+    /// the previous runs
+    #[arg(long)]
+    metrics_repository: Option<String>,
+
+    /// SQL file or folder of SQL files with `INSERT .. SELECT` transformations that are checked
+    /// against the tables of the input
+    #[arg(long)]
+    check_transform: Option<String>,
+
+    /// Table that is compiled by the targets that compile a single table, i.e. py-deequ and
+    /// py-spark-class. Required if the input defines more than one table, e.g. `crm.Customers`
+    #[arg(long)]
+    table: Option<String>,
 }
 
 impl Args {
-    fn get_input_string(&self) -> String {
+    /// Parses the project directory, the input files or the input string into one catalog.
+    fn parse_catalog(&self) -> Result<Catalog, DDLxParseError> {
+        if let Some(project) = &self.project {
+            return parser::parse_project(&Project::from_dir(Path::new(project))?);
+        }
+
         if !self.input_file.is_empty() {
-            let contents = read_to_string(self.input_file.as_str()).unwrap();
-            return contents;
+            let paths = self
+                .input_file
+                .iter()
+                .map(PathBuf::from)
+                .collect::<Vec<PathBuf>>();
+            return parser::parse_project(&Project::from_files(&paths)?);
         }
 
         if !self.input_string.is_empty() {
-            return self.input_string.clone();
+            return parser::parse_catalog(&self.input_string);
         }
 
         panic!("input is not defined")
     }
 }
 
+/// Reads the transformation script, all `.sql` files are read in order if the path is a folder.
+fn read_transform(path: &str) -> Result<String, String> {
+    let read_error = |file: &Path, err: std::io::Error| {
+        format!("Failed to read transformation {}: {}", file.display(), err)
+    };
+    if !Path::new(path).is_dir() {
+        return read_to_string(path).map_err(|err| read_error(Path::new(path), err));
+    }
+
+    let mut files = read_dir(path)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<PathBuf>, std::io::Error>>()
+        })
+        .map_err(|err| read_error(Path::new(path), err))?
+        .into_iter()
+        .filter(|file| file.extension().is_some_and(|extension| extension == "sql"))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+    files
+        .iter()
+        .map(|file| read_to_string(file).map_err(|err| read_error(file, err)))
+        .collect::<Result<Vec<String>, String>>()
+        .map(|scripts| scripts.join("\n"))
+}
+
+/// Checks all transformations against the catalog, the issues are printed and counted.
+fn check_transform(catalog: &Catalog, path: &str) -> Result<usize, String> {
+    let inserts = parser::parse_transform(read_transform(path)?.as_str())
+        .map_err(|err| format!("Failed to parse transformation {}", err))?;
+
+    let mut issue_count = 0;
+    for insert in inserts {
+        let issues = insert
+            .check(catalog)
+            .map_err(|err| format!("Failed to check transformation {}", err))?;
+        for issue in &issues {
+            println!("{}", issue);
+        }
+        issue_count += issues.len();
+    }
+    Ok(issue_count)
+}
+
+fn parse_catalog(args: &Args) -> Result<Catalog, String> {
+    let catalog = args
+        .parse_catalog()
+        .map_err(|err| format!("Failed to parse input {}", err))?;
+
+    for warning in &catalog.lineage_warnings {
+        log::warn!("{}", warning);
+    }
+    for warning in pattern_warnings(&catalog) {
+        log::warn!("{}", warning);
+    }
+    Ok(catalog)
+}
+
+/// The table selected with `--table`, or the only table of the input.
+fn parse_table(args: &Args) -> Result<TableDef, String> {
+    let catalog = parse_catalog(args)?;
+    match &args.table {
+        Some(table_name) => catalog
+            .find_table(table_name)
+            .cloned()
+            .ok_or_else(|| format!("Table {} is not defined in the input", table_name)),
+        None if catalog.tables.len() > 1 => Err(format!(
+            "The input defines {} tables, select the table to compile with --table",
+            catalog.tables.len()
+        )),
+        None => parser::single_table(catalog)
+            .map(|table| *table)
+            .map_err(|err| format!("Failed to parse input {}", err)),
+    }
+}
+
+/// Compiles the input to the target of the arguments.
+fn compile(args: &Args) -> Result<String, String> {
+    let compile_error = |err: CompileError| format!("Failed to compile {}", err);
+    match args.target {
+        CompilationTarget::PyDeequ => {
+            let table = parse_table(args)?;
+            // the command line overrides the metrics repository of the pragmas
+            let metrics_repository = args
+                .metrics_repository
+                .clone()
+                .or_else(|| metrics_repository(&table.pragmas).map(str::to_owned));
+            pydeequ::compile(table, metrics_repository.as_deref()).map_err(compile_error)
+        }
+        CompilationTarget::Dqdl => {
+            dqdl::compile_catalog(parse_catalog(args)?).map_err(compile_error)
+        }
+        CompilationTarget::OpenLineage => Ok(open_lineage::compile_catalog(parse_catalog(args)?)),
+        CompilationTarget::PySparkClass => Ok(pyspark_class::compile(parse_table(args)?)),
+        CompilationTarget::Explain => Ok(explain::compile_catalog(parse_catalog(args)?)),
+        CompilationTarget::None => Err(format!("Cannot compile to target: {:?}", args.target)),
+    }
+}
+
 pub fn main() {
     // println!("{:?}", "Create table if not exists Test {".to_owned().len());
     // println!("{:?}", "    Id Varchar(10) {".to_owned().len());
     // println!("{:?}", "        -LIKE \"%test%\",".to_owned().len());
     // println!("{:?}", "        -REGEX \"[0-9]*test[0-9]*\",.".to_owned().len());
     // return;
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
     let args = Args::parse();
 
     println!("{:?}", args);
 
-    let input_string = args.get_input_string();
-
-    let table_def = parser::parse(input_string.as_str());
-
-    if table_def.is_err() {
-        panic!("Failed to parse input {:?}", table_def.err());
+    if let Some(path) = &args.check_transform {
+        match parse_catalog(&args).and_then(|catalog| check_transform(&catalog, path)) {
+            Ok(0) => {}
+            Ok(issue_count) => {
+                println!("Found {} transformation issues", issue_count);
+                exit(1);
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                exit(1);
+            }
+        }
+        if let CompilationTarget::None = args.target {
+            return;
+        }
     }
-    let table_def = *table_def.unwrap();
 
-    let compiled: String = match args.target {
-        CompilationTarget::PyDeequ => pydeequ::compile(table_def),
-        CompilationTarget::Dqdl => dqdl::compile(table_def),
-        CompilationTarget::PySparkClass => pyspark_class::compile(table_def),
-        _ => unimplemented!("Cannot compile to target: {:?}", args.target),
-    };
+    let compiled = compile(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        exit(1);
+    });
 
     println!("{}", compiled);
 
@@ -94,3 +241,56 @@ pub fn main() {
     //     Ok(())
     // }
 }
+
+#[cfg(test)]
+pub mod test {
+    use crate::{compile, Args};
+    use clap::Parser;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("py-deequ", "Orders", "def check_table(")]
+    #[case("py-deequ", "crm.Customers", "check_primary_key_crm.Customers_Id")]
+    #[case("py-spark-class", "Orders", "customer_id")]
+    #[case("py-spark-class", "crm.Customers", "Id")]
+    fn test_compile_project_table(
+        #[case] target: &str,
+        #[case] table: &str,
+        #[case] expected: &str,
+    ) {
+        let args = Args::parse_from([
+            "ddlx",
+            "-t",
+            target,
+            "-p",
+            "test_data/project",
+            "--table",
+            table,
+        ]);
+        let compiled = compile(&args).unwrap();
+        assert!(compiled.contains(expected), "{}", compiled);
+    }
+
+    #[rstest]
+    #[case(
+        &["-t", "py-deequ", "-p", "test_data/project"],
+        "The input defines 2 tables, select the table to compile with --table"
+    )]
+    #[case(
+        &["-t", "py-spark-class", "-p", "test_data/project", "--table", "Customers"],
+        "Table Customers is not defined in the input"
+    )]
+    fn test_compile_project_table_error(#[case] args: &[&str], #[case] expected: &str) {
+        let args = Args::parse_from(["ddlx"].iter().chain(args));
+        assert_eq!(compile(&args), Err(expected.to_owned()));
+    }
+
+    #[rstest]
+    #[case("dqdl")]
+    #[case("open-lineage")]
+    #[case("explain")]
+    fn test_compile_project(#[case] target: &str) {
+        let args = Args::parse_from(["ddlx", "-t", target, "-p", "test_data/project"]);
+        assert!(compile(&args).is_ok());
+    }
+}
@@ -1,6 +1,25 @@
+pub mod anomaly;
+pub mod catalog;
 pub mod column_rule;
+pub mod custom_sql;
 pub mod data_class;
+pub mod distribution;
+pub mod domain;
+pub mod lineage;
+pub mod pattern;
+pub mod pragma;
 pub mod rule_ext_config;
 pub mod rule_filter;
 pub mod rule_traits;
+pub mod select_expr;
+pub mod semantic_format;
+pub mod sequence;
+pub mod statement;
+pub mod statistic;
 pub mod table_expr;
+pub mod table_rule;
+pub mod threshold;
+pub mod transform;
+pub mod udf;
+pub mod view_expr;
+pub mod volume;
@@ -0,0 +1,268 @@
+use crate::model::rule_ext_config::RuleExtConfig;
+use crate::model::rule_traits::{ColumnValidationError, ValidColumnRule, ValidTableRule};
+use crate::model::table_expr::{ColumnDef, TableDef};
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+use std::fmt::Display;
+use valid_column_rule_derive::ValidColumnRule;
+
+/// Metric of a column or table whose history is checked for anomalies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum AnomalyMetric {
+    RowCount,
+    Completeness,
+    Uniqueness,
+    Mean,
+    Sum,
+    StandardDeviation,
+    ApproxDistinct,
+    Entropy,
+}
+
+impl AnomalyMetric {
+    /// The row count is a metric of the table, all other metrics are metrics of a column.
+    pub fn is_table_metric(&self) -> bool {
+        *self == AnomalyMetric::RowCount
+    }
+
+    /// Metrics that are only defined for numeric columns.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            AnomalyMetric::Mean | AnomalyMetric::Sum | AnomalyMetric::StandardDeviation
+        )
+    }
+
+    /// Metric of its name in any case, e.g. `row_count`.
+    pub fn try_from_name(name: &str) -> Result<Self, DDLxParseError> {
+        match name.to_uppercase().as_str() {
+            "ROW_COUNT" => Ok(AnomalyMetric::RowCount),
+            "COMPLETENESS" => Ok(AnomalyMetric::Completeness),
+            "UNIQUENESS" => Ok(AnomalyMetric::Uniqueness),
+            "MEAN" => Ok(AnomalyMetric::Mean),
+            "SUM" => Ok(AnomalyMetric::Sum),
+            "STANDARD_DEVIATION" => Ok(AnomalyMetric::StandardDeviation),
+            "APPROX_DISTINCT" => Ok(AnomalyMetric::ApproxDistinct),
+            "ENTROPY" => Ok(AnomalyMetric::Entropy),
+            _ => Err(DDLxParseError::SyntaxError(format!(
+                "Unknown anomaly metric {}",
+                name
+            ))),
+        }
+    }
+}
+
+impl Display for AnomalyMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            AnomalyMetric::RowCount => "row_count",
+            AnomalyMetric::Completeness => "completeness",
+            AnomalyMetric::Uniqueness => "uniqueness",
+            AnomalyMetric::Mean => "mean",
+            AnomalyMetric::Sum => "sum",
+            AnomalyMetric::StandardDeviation => "standard_deviation",
+            AnomalyMetric::ApproxDistinct => "approx_distinct",
+            AnomalyMetric::Entropy => "entropy",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+/// Change of a metric compared with the previous run that is not an anomaly.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum AnomalyStrategy {
+    /// `RATE_OF_CHANGE 0.1` allows the metric to change by 10% of the previous value
+    RateOfChange(f64),
+    /// `ABSOLUTE_CHANGE 5` allows the metric to change by 5
+    AbsoluteChange(f64),
+}
+
+impl Display for AnomalyStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnomalyStrategy::RateOfChange(change) => write!(f, "RATE_OF_CHANGE {}", change),
+            AnomalyStrategy::AbsoluteChange(change) => write!(f, "ABSOLUTE_CHANGE {}", change),
+        }
+    }
+}
+
+/// `-ANOMALY <metric> <strategy>` rule that compares a metric with its history in the metrics
+/// repository, e.g. `-ANOMALY completeness RATE_OF_CHANGE 0.1`. It can be used as column or table
+/// level rule, table level rules check the row count.
+#[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
+#[validate_with(validate_metric)]
+pub struct Anomaly {
+    pub name: String,
+    pub metric: AnomalyMetric,
+    pub strategy: AnomalyStrategy,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl Anomaly {
+    pub fn new(
+        name: Option<String>,
+        metric: AnomalyMetric,
+        strategy: AnomalyStrategy,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            metric,
+            strategy,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+
+    fn validate_strategy(&self) -> Result<(), String> {
+        match self.strategy {
+            AnomalyStrategy::RateOfChange(change) | AnomalyStrategy::AbsoluteChange(change)
+                if change <= 0.0 =>
+            {
+                Err(format!("the allowed change of {} has to be positive", self))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_metric(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        self.validate_strategy().map_err(|message| {
+            ColumnValidationError::InvalidThreshold(format!("Column {}: {}", column.name, message))
+        })?;
+        if self.metric.is_table_metric() {
+            return Err(ColumnValidationError::InvalidType(format!(
+                "Column {}: {} is a metric of the table",
+                column.name, self.metric
+            )));
+        }
+        if self.metric.is_numeric() && !column.data_type.class.is_numeric_like() {
+            return Err(ColumnValidationError::InvalidType(format!(
+                "Type of column {} is not numeric-like for {} anomaly rule",
+                column.name, self.metric
+            )));
+        }
+        Ok(String::from("valid"))
+    }
+}
+
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-ANOMALY {} {}", self.metric, self.strategy)
+    }
+}
+
+impl ValidTableRule for Anomaly {
+    /// Table level rules have no column, so only the row count can be checked.
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError> {
+        self.validate_strategy().map_err(|message| {
+            ColumnValidationError::InvalidThreshold(format!(
+                "Table {}: {}",
+                table.table_ref, message
+            ))
+        })?;
+        if !self.metric.is_table_metric() {
+            return Err(ColumnValidationError::InvalidType(format!(
+                "Table {}: {} is a metric of a column",
+                table.table_ref, self.metric
+            )));
+        }
+        Ok(String::from("valid"))
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::anomaly::{Anomaly, AnomalyMetric, AnomalyStrategy};
+    use crate::model::column_rule::ColumnRule;
+    use crate::model::rule_traits::{ValidColumnRule, ValidTableRule};
+    use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+    use crate::parser::table::ColumnRuleExprParser;
+    use lalrpop_util::ParseError;
+    use rstest::rstest;
+
+    fn parse(rule: &str) -> Result<Anomaly, String> {
+        let rule_filter = ColumnRuleExprParser::new()
+            .parse(rule)
+            .map_err(|err| match err {
+                ParseError::User { error } => error.to_string(),
+                err => err.to_string(),
+            })?;
+        match &rule_filter.rules[..] {
+            [ColumnRule::Anomaly(rule)] => Ok(rule.clone()),
+            rules => Err(format!("Expected an anomaly rule, found {:?}", rules)),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "-ANOMALY completeness RATE_OF_CHANGE 0.1",
+        AnomalyMetric::Completeness,
+        AnomalyStrategy::RateOfChange(0.1)
+    )]
+    #[case(
+        "-anomaly ROW_COUNT absolute_change 1000",
+        AnomalyMetric::RowCount,
+        AnomalyStrategy::AbsoluteChange(1000.0)
+    )]
+    #[case(
+        "-ANOMALY Mean rate_of_change 1.",
+        AnomalyMetric::Mean,
+        AnomalyStrategy::RateOfChange(1.0)
+    )]
+    fn test_parse(
+        #[case] rule: &str,
+        #[case] metric: AnomalyMetric,
+        #[case] strategy: AnomalyStrategy,
+    ) {
+        let anomaly = parse(rule).unwrap();
+        assert_eq!((anomaly.metric, anomaly.strategy), (metric, strategy));
+        assert_eq!(
+            parse(&anomaly.to_string()),
+            Ok(anomaly),
+            "rule has to round trip"
+        );
+    }
+
+    #[rstest]
+    #[case("-ANOMALY median RATE_OF_CHANGE 0.1", "Unknown anomaly metric median")]
+    #[case("-ANOMALY mean 0.1", "Unrecognized token `0.1` found at 14:17\nExpected one of ABSOLUTE_CHANGE or RATE_OF_CHANGE")]
+    fn test_parse_error(#[case] rule: &str, #[case] expected: &str) {
+        assert_eq!(parse(rule), Err(expected.to_owned()));
+    }
+
+    #[rstest]
+    #[case(AnomalyMetric::Completeness, "VarChar", true)]
+    #[case(AnomalyMetric::Mean, "Int", true)]
+    #[case(AnomalyMetric::Mean, "VarChar", false)]
+    #[case(AnomalyMetric::RowCount, "Int", false)]
+    fn test_validate_col_type(
+        #[case] metric: AnomalyMetric,
+        #[case] data_type: &str,
+        #[case] is_valid: bool,
+    ) {
+        let column = ColumnDef::new(
+            "Amount".to_owned(),
+            DataType::new(data_type, Some(10), None),
+            false,
+            false,
+        );
+        let rule = Anomaly::new(None, metric, AnomalyStrategy::RateOfChange(0.1), None);
+        assert_eq!(rule.validate_col_type(&column).is_ok(), is_valid);
+    }
+
+    #[rstest]
+    #[case(AnomalyMetric::RowCount, AnomalyStrategy::RateOfChange(0.1), true)]
+    #[case(AnomalyMetric::RowCount, AnomalyStrategy::AbsoluteChange(0.0), false)]
+    #[case(AnomalyMetric::Completeness, AnomalyStrategy::RateOfChange(0.1), false)]
+    fn test_validate_table(
+        #[case] metric: AnomalyMetric,
+        #[case] strategy: AnomalyStrategy,
+        #[case] is_valid: bool,
+    ) {
+        let table = TableDef {
+            table_ref: TableRef::new("Orders", None, None),
+            ..Default::default()
+        };
+        let rule = Anomaly::new(None, metric, strategy, None);
+        assert_eq!(rule.validate_table(&table).is_ok(), is_valid);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::model::domain::{DomainDef, RuleSetDef};
+use crate::model::lineage::LineageWarning;
+use crate::model::table_expr::TableDef;
+use crate::model::udf::FunctionDef;
+use crate::model::view_expr::ViewDef;
+use crate::parser::error_utils::DDLxParseError;
+
+/// Collection of all tables and views defined in a DDLx input.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Catalog {
+    pub tables: Vec<TableDef>,
+    pub views: Vec<ViewDef>,
+    pub domains: Vec<DomainDef>,
+    pub rulesets: Vec<RuleSetDef>,
+    pub functions: Vec<FunctionDef>,
+    /// names of all tables, views, domains, rulesets and functions with the file they are defined in
+    pub symbols: Vec<Symbol>,
+    /// warnings of the lineage validation of the complete catalog
+    pub lineage_warnings: Vec<LineageWarning>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SymbolKind {
+    Table,
+    View,
+    Domain,
+    RuleSet,
+    Function,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub file: Option<String>,
+}
+
+impl Catalog {
+    #[cfg(test)]
+    pub fn new(tables: Vec<TableDef>) -> Self {
+        Self {
+            tables,
+            ..Default::default()
+        }
+    }
+
+    /// Registers the name of a table or view, names have to be unique across all input files.
+    pub fn define(
+        &mut self,
+        name: String,
+        kind: SymbolKind,
+        file: Option<&str>,
+    ) -> Result<(), DDLxParseError> {
+        if let Some(symbol) = self.find_symbol(&name) {
+            let location = match &symbol.file {
+                Some(file) => format!(" in {}", file),
+                None => String::new(),
+            };
+            return Err(DDLxParseError::TableValidationError(format!(
+                "{} is already defined{}",
+                name, location
+            )));
+        }
+        self.symbols.push(Symbol {
+            name,
+            kind,
+            file: file.map(|file| file.to_owned()),
+        });
+        Ok(())
+    }
+
+    pub fn find_domain(&self, name: &str) -> Option<&DomainDef> {
+        self.domains.iter().find(|domain| domain.name == name)
+    }
+
+    /// Finds a function by its name including the module, e.g. `checks.luhn`.
+    pub fn find_function(&self, name: &str) -> Option<&FunctionDef> {
+        self.functions
+            .iter()
+            .find(|function| function.qualified_name() == name)
+    }
+
+    pub fn find_ruleset(&self, name: &str) -> Option<&RuleSetDef> {
+        self.rulesets.iter().find(|ruleset| ruleset.name == name)
+    }
+
+    pub fn find_symbol(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|symbol| symbol.name == name)
+    }
+
+    /// Finds a table by its name including the schema, e.g. `shop.orders`.
+    pub fn find_table(&self, table_name: &str) -> Option<&TableDef> {
+        self.tables
+            .iter()
+            .find(|table| table.table_ref.to_string() == table_name)
+    }
+
+    pub fn find_table_mut(&mut self, table_name: &str) -> Option<&mut TableDef> {
+        self.tables
+            .iter_mut()
+            .find(|table| table.table_ref.to_string() == table_name)
+    }
+
+    pub fn find_view(&self, view_name: &str) -> Option<&ViewDef> {
+        self.views
+            .iter()
+            .find(|view| view.view_ref.to_string() == view_name)
+    }
+}
@@ -1,281 +1,573 @@
-use crate::model::rule_ext_config::RuleExtConfig;
+use crate::model::anomaly::Anomaly;
+use crate::model::custom_sql::CustomSql;
+use crate::model::distribution::ValueDistribution;
+use crate::model::pattern::{check_like, check_regex};
+use crate::model::rule_ext_config::{RuleExtConfig, Severity};
 use crate::model::rule_traits::{ColumnValidationError, ValidColumnRule};
+use crate::model::semantic_format::SemanticFormat;
+use crate::model::sequence::Sequence;
+use crate::model::statistic::Statistic;
 use crate::model::table_expr::{ColumnDef, DataType};
+use crate::model::threshold::Threshold;
+use crate::model::udf::UserFunction;
 use serde::Serialize;
 use valid_column_rule_derive::ValidColumnRule;
 
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(string_like)]
+#[threshold_in(0.0, 1.0)]
+#[validate_with(validate_pattern)]
 pub struct LikePattern {
     pub name: String,
     pub pattern: String,
     pub rule_ext_config: RuleExtConfig,
-    pub threshold: f32,
+    pub threshold: Threshold,
 }
 
-impl Default for LikePattern {
-    fn default() -> Self {
+impl LikePattern {
+    pub fn new(
+        name: Option<String>,
+        pattern: String,
+        rule_ext_config: Option<RuleExtConfig>,
+        threshold: Option<Threshold>,
+    ) -> Self {
         Self {
-            name: String::new(),
-            pattern: String::new(),
-            rule_ext_config: RuleExtConfig::new_empty(),
-            threshold: 1.0,
+            name: name.unwrap_or_default(),
+            pattern,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold: threshold.unwrap_or_default(),
         }
     }
-}
 
-impl ValidColumnRule for LikePattern {
-    fn validate_col_type(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
-        if column.data_type.class.is_string_like() {
-            Ok(String::from("valid"))
-        } else {
-            Err(ColumnValidationError::InvalidType(format!(
-                "Column {} is not a string-like type for like pattern rule",
-                column.name
-            )))
-        }
+    fn validate_pattern(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        check_like(&self.pattern).map_err(|message| {
+            ColumnValidationError::InvalidPattern(format!(
+                "Invalid like pattern \"{}\" for column {}: {}",
+                self.pattern, column.name, message
+            ))
+        })?;
+        Ok(String::from("valid"))
     }
 }
 
-impl LikePattern {
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(string_like)]
+#[threshold_in(0.0, 1.0)]
+#[validate_with(validate_pattern)]
+pub struct RegexPattern {
+    pub name: String,
+    pub pattern: String,
+    pub rule_ext_config: RuleExtConfig,
+    pub threshold: Threshold,
+}
+
+impl RegexPattern {
     pub fn new(
         name: Option<String>,
         pattern: String,
         rule_ext_config: Option<RuleExtConfig>,
-        threshold: Option<f32>,
+        threshold: Option<Threshold>,
     ) -> Self {
         Self {
             name: name.unwrap_or_default(),
             pattern,
             rule_ext_config: rule_ext_config.unwrap_or_default(),
-            threshold: threshold.unwrap_or(1.0),
+            threshold: threshold.unwrap_or_default(),
         }
     }
+
+    // target specific limitations of valid patterns are reported as warnings
+    fn validate_pattern(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        check_regex(&self.pattern).map_err(|message| {
+            ColumnValidationError::InvalidPattern(format!(
+                "Invalid regex \"{}\" for column {}: {}",
+                self.pattern, column.name, message
+            ))
+        })?;
+        Ok(String::from("valid"))
+    }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub struct RegexPattern {
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(string_like)]
+#[threshold_in(0.0, 1.0)]
+pub struct ContainsValue {
     pub name: String,
-    pub pattern: String,
+    pub value: String,
     pub rule_ext_config: RuleExtConfig,
-    pub threshold: f32,
+    pub threshold: Threshold,
 }
 
-impl ValidColumnRule for RegexPattern {
-    fn validate_col_type(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
-        if column.data_type.class.is_string_like() {
-            Ok(String::from("valid"))
-        } else {
-            Err(ColumnValidationError::InvalidType(format!(
-                "Column {} is not a string-like type for contains regex rule",
-                column.name
-            )))
+impl ContainsValue {
+    pub fn new(
+        name: Option<String>,
+        value: String,
+        rule_ext_config: Option<RuleExtConfig>,
+        threshold: Option<Threshold>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            value,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold: threshold.unwrap_or_default(),
         }
     }
 }
 
-impl Default for RegexPattern {
-    fn default() -> Self {
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[threshold_in(0.0, 1.0)]
+pub struct NonNull {
+    pub name: String,
+    pub rule_ext_config: RuleExtConfig,
+    pub threshold: Threshold,
+}
+
+impl NonNull {
+    pub fn new(
+        name: Option<String>,
+        rule_ext_config: Option<RuleExtConfig>,
+        threshold: Option<Threshold>,
+    ) -> Self {
         Self {
-            name: String::new(),
-            pattern: String::new(),
-            rule_ext_config: RuleExtConfig::new_empty(),
-            threshold: 1.0,
+            name: name.unwrap_or_default(),
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold: threshold.unwrap_or_default(),
         }
     }
 }
 
-impl RegexPattern {
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(string_like)]
+#[threshold_in(0.0, 1.0)]
+pub struct NotEmpty {
+    pub name: String,
+    pub rule_ext_config: RuleExtConfig,
+    pub threshold: Threshold,
+}
+
+impl NotEmpty {
     pub fn new(
         name: Option<String>,
-        pattern: String,
         rule_ext_config: Option<RuleExtConfig>,
-        threshold: Option<f32>,
+        threshold: Option<Threshold>,
     ) -> Self {
         Self {
             name: name.unwrap_or_default(),
-            pattern,
             rule_ext_config: rule_ext_config.unwrap_or_default(),
-            threshold: threshold.unwrap_or(1.0),
+            threshold: threshold.unwrap_or_default(),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub struct ContainsValue {
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+pub struct Uniqueness {
     pub name: String,
-    pub value: String,
     pub rule_ext_config: RuleExtConfig,
-    pub threshold: f32,
+    /// Checked on a sample of the rows, set by `#pragma implicit_uniqueness = sampled`.
+    pub sampled: bool,
 }
 
-impl ValidColumnRule for ContainsValue {
-    fn validate_col_type(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
-        if column.data_type.class.is_string_like() {
-            Ok(String::from("valid"))
-        } else {
-            Err(ColumnValidationError::InvalidType(format!(
-                "Column {} is not a string-like type for contains value rule",
-                column.name
-            )))
+impl Uniqueness {
+    pub fn new(name: Option<String>, rule_ext_config: Option<RuleExtConfig>) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+            sampled: false,
+        }
+    }
+
+    pub fn sampled(self) -> Self {
+        Self {
+            sampled: true,
+            ..self
         }
     }
 }
 
-impl Default for ContainsValue {
+#[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
+pub struct IsType {
+    pub name: String,
+    pub data_type: DataType,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl Default for IsType {
     fn default() -> Self {
         Self {
             name: String::new(),
-            value: String::new(),
+            data_type: DataType::new("Varchar", Some(3), None),
             rule_ext_config: RuleExtConfig::new_empty(),
-            threshold: 1.0,
         }
     }
 }
 
-impl ContainsValue {
+impl IsType {
     pub fn new(
         name: Option<String>,
-        value: String,
+        data_type: DataType,
         rule_ext_config: Option<RuleExtConfig>,
-        threshold: Option<f32>,
     ) -> Self {
         Self {
             name: name.unwrap_or_default(),
-            value,
+            data_type,
             rule_ext_config: rule_ext_config.unwrap_or_default(),
-            threshold: threshold.unwrap_or(1.0),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
-pub struct NonNull {
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(numeric_like)]
+#[threshold_in(0.0, 1.0)]
+pub struct Positive {
     pub name: String,
     pub rule_ext_config: RuleExtConfig,
-    pub threshold: f32,
+    pub threshold: Threshold,
 }
 
-impl Default for NonNull {
-    fn default() -> Self {
+impl Positive {
+    pub fn new(
+        name: Option<String>,
+        rule_ext_config: Option<RuleExtConfig>,
+        threshold: Option<Threshold>,
+    ) -> Self {
         Self {
-            name: String::new(),
-            rule_ext_config: RuleExtConfig::new_empty(),
-            threshold: 1.0,
+            name: name.unwrap_or_default(),
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold: threshold.unwrap_or_default(),
         }
     }
 }
 
-impl NonNull {
+/// Lower bound of the length of the values, `-MIN_LENGTH 3`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(string_like)]
+#[validate_with(validate_length)]
+pub struct MinLength {
+    pub name: String,
+    pub length: u32,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl MinLength {
+    pub fn new(name: Option<String>, length: u32, rule_ext_config: Option<RuleExtConfig>) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            length,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+
+    /// The minimum cannot exceed the declared size or a maximum length that holds for all rows.
+    fn validate_length(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        if let Some(size) = column.data_type.max_length() {
+            if self.length > size {
+                return Err(ColumnValidationError::InvalidThreshold(format!(
+                    "Column {}: minimum length {} is greater than the size of {}",
+                    column.name, self.length, column.data_type
+                )));
+            }
+        }
+        for rule in unfiltered_rules(column) {
+            if let ColumnRule::MaxLength(max_length) = rule {
+                if self.length > max_length.length {
+                    return Err(ColumnValidationError::InvalidThreshold(format!(
+                        "Column {}: minimum length {} is greater than maximum length {}",
+                        column.name, self.length, max_length.length
+                    )));
+                }
+            }
+        }
+        Ok(String::from("valid"))
+    }
+}
+
+/// Upper bound of the length of the values, `-MAX_LENGTH 10` or implied by a type like
+/// `VARCHAR(10)`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(string_like)]
+#[validate_with(validate_length)]
+pub struct MaxLength {
+    pub name: String,
+    pub length: u32,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl MaxLength {
+    pub fn new(name: Option<String>, length: u32, rule_ext_config: Option<RuleExtConfig>) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            length,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+
+    /// The maximum cannot be below a minimum length that holds for all rows.
+    fn validate_length(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        for rule in unfiltered_rules(column) {
+            if let ColumnRule::MinLength(min_length) = rule {
+                if min_length.length > self.length {
+                    return Err(ColumnValidationError::InvalidThreshold(format!(
+                        "Column {}: minimum length {} is greater than maximum length {}",
+                        column.name, min_length.length, self.length
+                    )));
+                }
+            }
+        }
+        Ok(String::from("valid"))
+    }
+}
+
+/// Rules of the column that are not restricted by a filter.
+fn unfiltered_rules(column: &ColumnDef) -> impl Iterator<Item = &ColumnRule> {
+    column
+        .rules
+        .iter()
+        .filter(|rule_filter| rule_filter.filter_string.is_none())
+        .flat_map(|rule_filter| &rule_filter.rules)
+}
+
+/// Range of the values of a fixed width integer type like `TINYINT`, implied by the column type.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(numeric_like)]
+pub struct IntegerRange {
+    pub name: String,
+    pub min: i64,
+    pub max: i64,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl IntegerRange {
     pub fn new(
         name: Option<String>,
+        min: i64,
+        max: i64,
         rule_ext_config: Option<RuleExtConfig>,
-        threshold: Option<f32>,
     ) -> Self {
         Self {
             name: name.unwrap_or_default(),
+            min,
+            max,
             rule_ext_config: rule_ext_config.unwrap_or_default(),
-            threshold: threshold.unwrap_or(1.0),
         }
     }
+
+    /// SQL condition that holds for values within the range.
+    pub fn condition(&self, column_name: &str) -> String {
+        format!("{} BETWEEN {} AND {}", column_name, self.min, self.max)
+    }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize)]
-pub struct NotEmpty {
+/// Limits of the integer and fractional digits of a type like `DECIMAL(10,2)`, implied by the
+/// column type.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(numeric_like)]
+pub struct NumericPrecision {
     pub name: String,
+    pub precision: u32,
+    pub scale: u32,
     pub rule_ext_config: RuleExtConfig,
-    pub threshold: f32,
 }
 
-impl ValidColumnRule for NotEmpty {
-    fn validate_col_type(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
-        if column.data_type.class.is_string_like() {
-            Ok(String::from("valid"))
-        } else {
-            Err(ColumnValidationError::InvalidType(format!(
-                "Column {} is not a string-like type for not empty rule",
-                column.name
-            )))
+impl NumericPrecision {
+    pub fn new(
+        name: Option<String>,
+        precision: u32,
+        scale: u32,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            precision,
+            scale,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
         }
     }
-}
 
-impl Default for NotEmpty {
-    fn default() -> Self {
-        Self {
-            name: String::new(),
-            rule_ext_config: RuleExtConfig::new_empty(),
-            threshold: 1.0,
-        }
+    pub fn integer_digits(&self) -> u32 {
+        self.precision.saturating_sub(self.scale)
+    }
+
+    /// SQL condition that holds for values that fit the integer digits without overflow and the
+    /// fractional digits without truncation.
+    pub fn condition(&self, column_name: &str) -> String {
+        format!(
+            "abs({}) < 1E{} AND {} = round({}, {})",
+            column_name,
+            self.integer_digits(),
+            column_name,
+            column_name,
+            self.scale
+        )
     }
 }
 
-impl NotEmpty {
+/// Consistency of a generated column with the SQL expression it is generated from.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+pub struct GeneratedValue {
+    pub name: String,
+    pub expression: String,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl GeneratedValue {
     pub fn new(
         name: Option<String>,
+        expression: String,
         rule_ext_config: Option<RuleExtConfig>,
-        threshold: Option<f32>,
     ) -> Self {
         Self {
             name: name.unwrap_or_default(),
+            expression,
             rule_ext_config: rule_ext_config.unwrap_or_default(),
-            threshold: threshold.unwrap_or(1.0),
         }
     }
 }
 
+/// Built-in check of a well known string format like `-EMAIL` or `-ISO_COUNTRY`.
 #[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
-pub struct Uniqueness {
+#[valid_for(string_like)]
+#[threshold_in(0.0, 1.0)]
+pub struct SemanticValidator {
     pub name: String,
+    pub format: SemanticFormat,
     pub rule_ext_config: RuleExtConfig,
+    pub threshold: Threshold,
 }
 
-impl Default for Uniqueness {
+impl Default for SemanticValidator {
     fn default() -> Self {
         Self {
             name: String::new(),
+            format: SemanticFormat::Email,
             rule_ext_config: RuleExtConfig::new_empty(),
+            threshold: Threshold::default(),
         }
     }
 }
 
-impl Uniqueness {
-    pub fn new(name: Option<String>, rule_ext_config: Option<RuleExtConfig>) -> Self {
+impl SemanticValidator {
+    pub fn new(
+        name: Option<String>,
+        format: SemanticFormat,
+        rule_ext_config: Option<RuleExtConfig>,
+        threshold: Option<Threshold>,
+    ) -> Self {
         Self {
             name: name.unwrap_or_default(),
+            format,
             rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold: threshold.unwrap_or_default(),
         }
     }
 }
 
+/// Assertion on a statistic of the column like `-NULL_RATIO <= 0.05` or `-ENTROPY > 2.5`.
 #[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
-pub struct IsType {
+#[validate_with(validate_threshold)]
+pub struct ColumnStatistic {
     pub name: String,
-    pub data_type: DataType,
+    pub statistic: Statistic,
     pub rule_ext_config: RuleExtConfig,
+    pub threshold: Threshold,
 }
 
-impl Default for IsType {
+impl Default for ColumnStatistic {
     fn default() -> Self {
         Self {
             name: String::new(),
-            data_type: DataType::new("Varchar", Some(3), None),
+            statistic: Statistic::NullRatio,
             rule_ext_config: RuleExtConfig::new_empty(),
+            threshold: Threshold::default(),
         }
     }
 }
 
-impl IsType {
+impl ColumnStatistic {
     pub fn new(
         name: Option<String>,
-        data_type: DataType,
+        statistic: Statistic,
         rule_ext_config: Option<RuleExtConfig>,
+        threshold: Threshold,
     ) -> Self {
         Self {
             name: name.unwrap_or_default(),
-            data_type,
+            statistic,
             rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold,
         }
     }
+
+    /// The threshold has to be within the range of the statistic.
+    fn validate_threshold(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        let (min, max) = self.statistic.bounds();
+        self.threshold.check_bounds(min, max).map_err(|message| {
+            ColumnValidationError::InvalidThreshold(format!(
+                "Threshold {} of {} rule for column {}: {}",
+                self.threshold, self.statistic, column.name, message
+            ))
+        })?;
+        Ok(String::from("valid"))
+    }
+}
+
+/// Order of the values in the order of another column like `-INCREASING ORDER BY ts`. Without
+/// `ORDER BY` the increasing rules use the order of the rows and `-NO_GAPS` the sorted values.
+#[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(orderable)]
+#[validate_with(validate_sequence)]
+pub struct ColumnSequence {
+    pub name: String,
+    pub sequence: Sequence,
+    pub order_by: Option<String>,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl ColumnSequence {
+    pub fn new(
+        name: Option<String>,
+        sequence: Sequence,
+        order_by: Option<String>,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            sequence,
+            order_by,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+
+    /// Gaps are only defined for values with a fixed step like integers and dates.
+    fn validate_sequence(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        if self.sequence == Sequence::NoGaps && !column.data_type.class.is_discrete() {
+            return Err(ColumnValidationError::InvalidType(format!(
+                "Column {} is not an integer or date type for {} rule",
+                column.name, self.sequence
+            )));
+        }
+        Ok(String::from("valid"))
+    }
+
+    /// Name of the column that holds the previous value of the sequence.
+    pub fn previous_column(&self, column_name: &str) -> String {
+        format!("{}_previous_{}", column_name, self.sequence)
+    }
+
+    /// Window expression of the previous value of the sequence.
+    pub fn previous_value(&self, column_name: &str) -> String {
+        let order_by = match (&self.order_by, self.sequence) {
+            (Some(order_by), _) => order_by.as_str(),
+            (None, Sequence::NoGaps) => column_name,
+            (None, _) => "monotonically_increasing_id()",
+        };
+        format!("lag({}) over (order by {})", column_name, order_by)
+    }
+
+    /// SQL condition of a row that violates the sequence, given the column of the previous value.
+    pub fn violation(&self, column_name: &str) -> String {
+        self.sequence
+            .violation(column_name, &self.previous_column(column_name))
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -287,6 +579,19 @@ pub enum ColumnRule {
     NotEmpty(NotEmpty),
     Uniqueness(Uniqueness),
     IsType(IsType),
+    Positive(Positive),
+    GeneratedValue(GeneratedValue),
+    SemanticValidator(SemanticValidator),
+    ColumnStatistic(ColumnStatistic),
+    MinLength(MinLength),
+    MaxLength(MaxLength),
+    IntegerRange(IntegerRange),
+    NumericPrecision(NumericPrecision),
+    ColumnSequence(ColumnSequence),
+    CustomSql(CustomSql),
+    UserFunction(UserFunction),
+    Anomaly(Anomaly),
+    ValueDistribution(ValueDistribution),
 }
 
 impl ValidColumnRule for ColumnRule {
@@ -299,6 +604,19 @@ impl ValidColumnRule for ColumnRule {
             ColumnRule::NotEmpty(rule) => rule.validate_col_type(column),
             ColumnRule::Uniqueness(rule) => rule.validate_col_type(column),
             ColumnRule::IsType(rule) => rule.validate_col_type(column),
+            ColumnRule::Positive(rule) => rule.validate_col_type(column),
+            ColumnRule::GeneratedValue(rule) => rule.validate_col_type(column),
+            ColumnRule::SemanticValidator(rule) => rule.validate_col_type(column),
+            ColumnRule::ColumnStatistic(rule) => rule.validate_col_type(column),
+            ColumnRule::MinLength(rule) => rule.validate_col_type(column),
+            ColumnRule::MaxLength(rule) => rule.validate_col_type(column),
+            ColumnRule::IntegerRange(rule) => rule.validate_col_type(column),
+            ColumnRule::NumericPrecision(rule) => rule.validate_col_type(column),
+            ColumnRule::ColumnSequence(rule) => rule.validate_col_type(column),
+            ColumnRule::CustomSql(rule) => rule.validate_col_type(column),
+            ColumnRule::UserFunction(rule) => rule.validate_col_type(column),
+            ColumnRule::Anomaly(rule) => rule.validate_col_type(column),
+            ColumnRule::ValueDistribution(rule) => rule.validate_col_type(column),
             // _ => Err(ColumnValidationError::RuleValidationNotImplemented(
             //     String::from("rule validation not implemented"),
             // )),
@@ -306,13 +624,134 @@ impl ValidColumnRule for ColumnRule {
     }
 }
 
+impl ColumnRule {
+    /// Overrides the threshold of rules that do not have to hold for every row.
+    pub fn with_threshold(self, threshold: Threshold) -> Self {
+        match self {
+            ColumnRule::LikePattern(rule) => {
+                ColumnRule::LikePattern(LikePattern { threshold, ..rule })
+            }
+            ColumnRule::RegexPattern(rule) => {
+                ColumnRule::RegexPattern(RegexPattern { threshold, ..rule })
+            }
+            ColumnRule::ContainsValue(rule) => {
+                ColumnRule::ContainsValue(ContainsValue { threshold, ..rule })
+            }
+            ColumnRule::NonNull(rule) => ColumnRule::NonNull(NonNull { threshold, ..rule }),
+            ColumnRule::NotEmpty(rule) => ColumnRule::NotEmpty(NotEmpty { threshold, ..rule }),
+            ColumnRule::Positive(rule) => ColumnRule::Positive(Positive { threshold, ..rule }),
+            ColumnRule::SemanticValidator(rule) => {
+                ColumnRule::SemanticValidator(SemanticValidator { threshold, ..rule })
+            }
+            ColumnRule::ColumnStatistic(rule) => {
+                ColumnRule::ColumnStatistic(ColumnStatistic { threshold, ..rule })
+            }
+            ColumnRule::UserFunction(rule) => {
+                ColumnRule::UserFunction(UserFunction { threshold, ..rule })
+            }
+            rule => rule,
+        }
+    }
+
+    pub fn rule_ext_config(&self) -> &RuleExtConfig {
+        match self {
+            ColumnRule::LikePattern(rule) => &rule.rule_ext_config,
+            ColumnRule::RegexPattern(rule) => &rule.rule_ext_config,
+            ColumnRule::ContainsValue(rule) => &rule.rule_ext_config,
+            ColumnRule::NonNull(rule) => &rule.rule_ext_config,
+            ColumnRule::NotEmpty(rule) => &rule.rule_ext_config,
+            ColumnRule::Uniqueness(rule) => &rule.rule_ext_config,
+            ColumnRule::IsType(rule) => &rule.rule_ext_config,
+            ColumnRule::Positive(rule) => &rule.rule_ext_config,
+            ColumnRule::GeneratedValue(rule) => &rule.rule_ext_config,
+            ColumnRule::SemanticValidator(rule) => &rule.rule_ext_config,
+            ColumnRule::ColumnStatistic(rule) => &rule.rule_ext_config,
+            ColumnRule::MinLength(rule) => &rule.rule_ext_config,
+            ColumnRule::MaxLength(rule) => &rule.rule_ext_config,
+            ColumnRule::IntegerRange(rule) => &rule.rule_ext_config,
+            ColumnRule::NumericPrecision(rule) => &rule.rule_ext_config,
+            ColumnRule::ColumnSequence(rule) => &rule.rule_ext_config,
+            ColumnRule::CustomSql(rule) => &rule.rule_ext_config,
+            ColumnRule::UserFunction(rule) => &rule.rule_ext_config,
+            ColumnRule::Anomaly(rule) => &rule.rule_ext_config,
+            ColumnRule::ValueDistribution(rule) => &rule.rule_ext_config,
+        }
+    }
+
+    fn rule_ext_config_mut(&mut self) -> &mut RuleExtConfig {
+        match self {
+            ColumnRule::LikePattern(rule) => &mut rule.rule_ext_config,
+            ColumnRule::RegexPattern(rule) => &mut rule.rule_ext_config,
+            ColumnRule::ContainsValue(rule) => &mut rule.rule_ext_config,
+            ColumnRule::NonNull(rule) => &mut rule.rule_ext_config,
+            ColumnRule::NotEmpty(rule) => &mut rule.rule_ext_config,
+            ColumnRule::Uniqueness(rule) => &mut rule.rule_ext_config,
+            ColumnRule::IsType(rule) => &mut rule.rule_ext_config,
+            ColumnRule::Positive(rule) => &mut rule.rule_ext_config,
+            ColumnRule::GeneratedValue(rule) => &mut rule.rule_ext_config,
+            ColumnRule::SemanticValidator(rule) => &mut rule.rule_ext_config,
+            ColumnRule::ColumnStatistic(rule) => &mut rule.rule_ext_config,
+            ColumnRule::MinLength(rule) => &mut rule.rule_ext_config,
+            ColumnRule::MaxLength(rule) => &mut rule.rule_ext_config,
+            ColumnRule::IntegerRange(rule) => &mut rule.rule_ext_config,
+            ColumnRule::NumericPrecision(rule) => &mut rule.rule_ext_config,
+            ColumnRule::ColumnSequence(rule) => &mut rule.rule_ext_config,
+            ColumnRule::CustomSql(rule) => &mut rule.rule_ext_config,
+            ColumnRule::UserFunction(rule) => &mut rule.rule_ext_config,
+            ColumnRule::Anomaly(rule) => &mut rule.rule_ext_config,
+            ColumnRule::ValueDistribution(rule) => &mut rule.rule_ext_config,
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        let rule_ext_config = self.rule_ext_config_mut();
+        *rule_ext_config = rule_ext_config.clone().with_severity(severity);
+        self
+    }
+
+    /// Marks a rule that is generated from the column definition, e.g. `NonNull` of `NOT NULL`.
+    pub fn implicit(mut self) -> Self {
+        let rule_ext_config = self.rule_ext_config_mut();
+        *rule_ext_config = rule_ext_config.clone().implicit();
+        self
+    }
+
+    pub fn is_implicit(&self) -> bool {
+        self.rule_ext_config().is_implicit()
+    }
+
+    /// Rules that are checked on a sample of the rows instead of all rows.
+    pub fn is_sampled(&self) -> bool {
+        matches!(self, ColumnRule::Uniqueness(rule) if rule.sampled)
+    }
+
+    /// Custom SQL aggregates over all rows instead of checking each value of the column.
+    pub fn is_custom_sql(&self) -> bool {
+        matches!(self, ColumnRule::CustomSql(_))
+    }
+
+    /// Anomaly rules compare a metric of the column with its history instead of checking values.
+    pub fn is_anomaly(&self) -> bool {
+        matches!(self, ColumnRule::Anomaly(_))
+    }
+
+    /// Severity of the rule or the default of the table if the rule has none.
+    pub fn severity(&self, default: Severity) -> Severity {
+        self.rule_ext_config().severity().unwrap_or(default)
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use crate::model::column_rule::{
-        ColumnRule, ContainsValue, LikePattern, RegexPattern, Uniqueness,
+        ColumnRule, ColumnSequence, ContainsValue, LikePattern, NonNull, NotEmpty, Positive,
+        RegexPattern, SemanticValidator, Uniqueness,
     };
     use crate::model::rule_traits::ValidColumnRule;
+    use crate::model::semantic_format::SemanticFormat;
+    use crate::model::sequence::Sequence;
     use crate::model::table_expr::{ColumnDef, DataType};
+    use crate::model::threshold::Threshold;
     use rstest::rstest;
 
     #[rstest]
@@ -331,6 +770,29 @@ pub mod test {
     )]
     #[case(ColumnRule::ContainsValue(ContainsValue::new(None, "".to_owned(), None, None)),
     ColumnDef::new(String::from("test"), DataType::new("LongText", None, None), false, false))]
+    #[case(
+        ColumnRule::Positive(Positive::new(None, None, None)),
+        ColumnDef::new(
+            String::from("test"),
+            DataType::new("BigInt", Some(20), None),
+            false,
+            false
+        )
+    )]
+    #[case(
+        ColumnRule::SemanticValidator(SemanticValidator::new(
+            None,
+            SemanticFormat::Iban,
+            None,
+            None
+        )),
+        ColumnDef::new(
+            String::from("test"),
+            DataType::new("Char", Some(34), None),
+            false,
+            false
+        )
+    )]
     pub fn test_rule_type_validation_success(
         #[case] column_rule: ColumnRule,
         #[case] column: ColumnDef,
@@ -346,6 +808,40 @@ pub mod test {
     ColumnDef::new(String::from("test"), DataType::new("Int", Some(3), None), false, false))]
     #[case(ColumnRule::ContainsValue(ContainsValue::new(None, "".to_owned(), None, None)),
     ColumnDef::new(String::from("test"), DataType::new("bool", None, None), false, false))]
+    #[case(
+        ColumnRule::Positive(Positive::new(None, None, None)),
+        ColumnDef::new(
+            String::from("test"),
+            DataType::new("Varchar", Some(3), None),
+            false,
+            false
+        )
+    )]
+    #[case(
+        ColumnRule::SemanticValidator(SemanticValidator::new(
+            None,
+            SemanticFormat::Uuid,
+            None,
+            None
+        )),
+        ColumnDef::new(
+            String::from("test"),
+            DataType::new("BigInt", Some(20), None),
+            false,
+            false
+        )
+    )]
+    #[case(
+        ColumnRule::NotEmpty(NotEmpty::new(None, None, Some(1.5.into()))),
+        ColumnDef::new(
+            String::from("test"),
+            DataType::new("Varchar", Some(3), None),
+            false,
+            false
+        )
+    )]
+    #[case(ColumnRule::NonNull(NonNull::new(None, None, Some(Threshold::from(-0.1)))),
+    ColumnDef::new(String::from("test"), DataType::new("Int", Some(3), None), false, false))]
     #[should_panic]
     pub fn test_rule_type_validation_failure(
         #[case] column_rule: ColumnRule,
@@ -354,4 +850,27 @@ pub mod test {
         let is_valid = column_rule.validate_col_type(&column).is_ok();
         assert!(is_valid);
     }
+
+    #[rstest]
+    #[case(
+        ColumnRule::ColumnSequence(ColumnSequence::new(None, Sequence::Increasing, None, None)),
+        "InvalidType: Type of column test is not orderable for column sequence rule"
+    )]
+    #[case(
+        ColumnRule::Positive(Positive::new(None, None, None)),
+        "InvalidType: Type of column test is not numeric-like for positive rule"
+    )]
+    pub fn test_rule_type_validation_message(
+        #[case] column_rule: ColumnRule,
+        #[case] message: &str,
+    ) {
+        let column = ColumnDef::new(
+            String::from("test"),
+            DataType::new("bool", None, None),
+            false,
+            false,
+        );
+        let err = column_rule.validate_col_type(&column).unwrap_err();
+        assert_eq!(err.to_string(), message);
+    }
 }
@@ -0,0 +1,191 @@
+use crate::model::rule_ext_config::RuleExtConfig;
+use crate::model::rule_filter::operator::ComparisonOperator;
+use crate::model::rule_traits::{ColumnValidationError, ValidColumnRule, ValidTableRule};
+use crate::model::select_expr::{select, BinaryOperator, Expression, Literal};
+use crate::model::table_expr::{ColumnDef, TableDef};
+use crate::model::table_rule::validate_columns_exist;
+use crate::model::threshold::{parse_number, Threshold, ThresholdOperator};
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+use valid_column_rule_derive::ValidColumnRule;
+
+/// Aggregate over the rows of a table that is compared with a value, e.g.
+/// `-SQL "count(*) FILTER (WHERE a > b) = 0"`. It can be used as column or table level rule.
+#[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
+pub struct CustomSql {
+    pub name: String,
+    pub sql: String,
+    /// Aggregate of the SQL, its columns can be qualified with the name of the table.
+    #[serde(skip)]
+    aggregate: Expression,
+    threshold: Threshold,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl CustomSql {
+    /// Splits the SQL into the aggregate and the assertion on its value, the SQL has to end with
+    /// a comparison with a number like `= 0`.
+    pub fn try_new(
+        name: Option<String>,
+        sql: String,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Result<Self, DDLxParseError> {
+        let (aggregate, threshold) = parse_assertion(&sql).map_err(DDLxParseError::SyntaxError)?;
+        Ok(Self {
+            name: name.unwrap_or_default(),
+            sql,
+            aggregate,
+            threshold,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        })
+    }
+
+    /// Aggregate and the assertion on its value. Columns of the aggregate are unqualified, since
+    /// the targets name the table differently, e.g. `primary` in DQDL.
+    pub fn assertion(&self) -> (Expression, Threshold) {
+        (self.aggregate.unqualified(), self.threshold)
+    }
+}
+
+fn parse_assertion(sql: &str) -> Result<(Expression, Threshold), String> {
+    let missing_assertion = || {
+        format!(
+            "Custom SQL \"{}\" has to compare the aggregate with a number like = 0",
+            sql
+        )
+    };
+    let expression = select::ExprParser::new()
+        .parse(sql)
+        .map_err(|_| format!("Failed to parse custom SQL \"{}\"", sql))?;
+    let Expression::Binary {
+        left,
+        operator: BinaryOperator::Comparison(operator),
+        right,
+    } = expression
+    else {
+        return Err(missing_assertion());
+    };
+    let value = match *right {
+        Expression::Literal(Literal::Number(number)) => parse_number(&number),
+        Expression::Negate(number) => match *number {
+            Expression::Literal(Literal::Number(number)) => {
+                parse_number(&number).map(|value| -value)
+            }
+            _ => return Err(missing_assertion()),
+        },
+        _ => return Err(missing_assertion()),
+    }
+    .map_err(|_| missing_assertion())?;
+    let operator = match operator {
+        ComparisonOperator::Equal => ThresholdOperator::Equal,
+        ComparisonOperator::GreaterThan => ThresholdOperator::Greater,
+        ComparisonOperator::GreaterThanOrEqual => ThresholdOperator::GreaterEqual,
+        ComparisonOperator::LessThan => ThresholdOperator::Less,
+        ComparisonOperator::LessThanOrEqual => ThresholdOperator::LessEqual,
+        _ => return Err(missing_assertion()),
+    };
+    Ok((*left, Threshold::Compare(operator, value)))
+}
+
+impl ValidTableRule for CustomSql {
+    /// Every column the SQL references has to be defined in the
+    /// table, so that typos are caught at compile time. Columns can only be qualified with the
+    /// name of the table itself.
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError> {
+        let table_name = table.table_ref.to_string();
+        for (qualifier, name) in self.aggregate.columns() {
+            if let Some(qualifier) = qualifier {
+                if qualifier != &table.table_ref.table_name && qualifier != &table_name {
+                    return Err(ColumnValidationError::InvalidSql(format!(
+                        "Custom SQL \"{}\" references {}.{}, only columns of table {} can be referenced",
+                        self.sql, qualifier, name, table_name
+                    )));
+                }
+            }
+        }
+        let columns = self
+            .aggregate
+            .columns()
+            .into_iter()
+            .map(|(_, name)| name.clone())
+            .collect::<Vec<String>>();
+        validate_columns_exist(&columns, table)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::custom_sql::CustomSql;
+    use crate::model::rule_traits::ValidTableRule;
+    use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+    use crate::model::threshold::{Threshold, ThresholdOperator};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "count(*) filter (where a > b) = 0",
+        "count(*) FILTER (WHERE (a > b))",
+        Threshold::Compare(ThresholdOperator::Equal, 0.0)
+    )]
+    #[case(
+        "sum(a) / count(*) >= 0.5",
+        "(sum(a) / count(*))",
+        Threshold::Compare(ThresholdOperator::GreaterEqual, 0.5)
+    )]
+    #[case(
+        "count(*) filter (where Test.a > b) = 0",
+        "count(*) FILTER (WHERE (a > b))",
+        Threshold::Compare(ThresholdOperator::Equal, 0.0)
+    )]
+    #[case(
+        "count(*) = 16777217",
+        "count(*)",
+        Threshold::Compare(ThresholdOperator::Equal, 16_777_217.0)
+    )]
+    #[case(
+        "min(a) > -1.5",
+        "min(a)",
+        Threshold::Compare(ThresholdOperator::Greater, -1.5)
+    )]
+    fn test_assertion(#[case] sql: &str, #[case] aggregate: &str, #[case] threshold: Threshold) {
+        let (expression, parsed_threshold) = CustomSql::try_new(None, sql.to_owned(), None)
+            .unwrap()
+            .assertion();
+        assert_eq!(expression.to_string(), aggregate);
+        assert_eq!(parsed_threshold, threshold);
+    }
+
+    #[rstest]
+    #[case("count(*) filter (where a > b)")]
+    #[case("count(*) <> 0")]
+    #[case("count(* = 0")]
+    #[case("count(*) = a")]
+    fn test_assertion_error(#[case] sql: &str) {
+        assert!(CustomSql::try_new(None, sql.to_owned(), None).is_err());
+    }
+
+    #[rstest]
+    #[case("count(*) filter (where a > b) = 0", true)]
+    #[case("count(*) filter (where a > c) = 0", false)]
+    #[case("count(*) filter (where Test.a > b) = 0", true)]
+    #[case("count(*) filter (where Other.a > b) = 0", false)]
+    fn test_validate_table(#[case] sql: &str, #[case] is_valid: bool) {
+        let table = TableDef {
+            table_ref: TableRef::new("Test", None, None),
+            columns: ["a", "b"]
+                .iter()
+                .map(|name| {
+                    ColumnDef::new(
+                        name.to_string(),
+                        DataType::new("Int", Some(10), None),
+                        false,
+                        false,
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let rule = CustomSql::try_new(None, sql.to_owned(), None).unwrap();
+        assert_eq!(rule.validate_table(&table).is_ok(), is_valid);
+    }
+}
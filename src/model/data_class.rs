@@ -113,4 +113,46 @@ impl DataClass {
                 | DataClass::Year
         )
     }
+
+    /// Classes with a total order of their values, e.g. for `-INCREASING` rules.
+    pub fn is_orderable(&self) -> bool {
+        self.is_numeric_like() || self.is_date_like()
+    }
+
+    /// Fixed width integer classes, e.g. for categories of a `-DISTRIBUTION` rule.
+    pub fn is_integer_like(&self) -> bool {
+        self.integer_range().is_some()
+    }
+
+    /// Classes whose consecutive values differ by one, integers and days of a date.
+    pub fn is_discrete(&self) -> bool {
+        self.integer_range().is_some() || matches!(self, DataClass::Date | DataClass::Year)
+    }
+
+    /// Range of the values of fixed width integer classes, e.g. -128 to 127 for `TINYINT`.
+    pub fn integer_range(&self) -> Option<(i64, i64)> {
+        match self {
+            DataClass::TinyInt => Some((i8::MIN.into(), i8::MAX.into())),
+            DataClass::SmallInt => Some((i16::MIN.into(), i16::MAX.into())),
+            DataClass::MediumInt => Some((-(1 << 23), (1 << 23) - 1)),
+            DataClass::Int | DataClass::Integer => Some((i32::MIN.into(), i32::MAX.into())),
+            DataClass::BigInt => Some((i64::MIN, i64::MAX)),
+            _ => None,
+        }
+    }
+
+    /// Checks if values of this class can be stored in a column of the target class, sizes are
+    /// not considered. Unknown classes are compatible with every class.
+    pub fn is_assignable_to(&self, target: &DataClass) -> bool {
+        if *self == DataClass::Unknown || *target == DataClass::Unknown || self == target {
+            return true;
+        }
+        let is_integer = |class: &DataClass| class.is_numeric_like() && !class.is_fraction_like();
+
+        (self.is_string_like() && target.is_string_like())
+            || (self.is_boolean_like() && target.is_boolean_like())
+            || (self.is_date_like() && target.is_date_like())
+            || (self.is_numeric_like() && target.is_fraction_like())
+            || (is_integer(self) && is_integer(target))
+    }
 }
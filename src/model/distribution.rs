@@ -0,0 +1,335 @@
+use crate::model::data_class::DataClass;
+use crate::model::rule_ext_config::RuleExtConfig;
+use crate::model::rule_traits::{ColumnValidationError, ValidColumnRule};
+use crate::model::table_expr::ColumnDef;
+use crate::model::threshold::{round_value, Threshold};
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+use std::fmt::Display;
+use valid_column_rule_derive::ValidColumnRule;
+
+/// Value of a category in the notation of the column type, e.g. `'web'`, `3` or `true`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum Category {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+}
+
+impl Category {
+    /// Integer category of the digits of a token, which may not fit into an integer.
+    pub fn try_integer(s: &str) -> Result<Self, DDLxParseError> {
+        s.parse::<i64>().map(Category::Integer).map_err(|_| {
+            DDLxParseError::ColumnValidationError(format!(
+                "Category {} has to be an integer between {} and {}",
+                s,
+                i64::MIN,
+                i64::MAX
+            ))
+        })
+    }
+
+    fn is_assignable_to(&self, class: &DataClass) -> bool {
+        match self {
+            Category::String(_) => class.is_string_like(),
+            Category::Integer(_) => class.is_integer_like(),
+            Category::Boolean(_) => class.is_boolean_like(),
+        }
+    }
+
+    /// Value without quotes, e.g. for constraint names.
+    pub fn value(&self) -> String {
+        match self {
+            Category::String(value) => value.clone(),
+            Category::Integer(value) => value.to_string(),
+            Category::Boolean(value) => value.to_string(),
+        }
+    }
+}
+
+impl Display for Category {
+    /// Literal in SQL notation.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Category::String(value) => write!(f, "'{}'", value),
+            category => write!(f, "{}", category.value()),
+        }
+    }
+}
+
+/// Fraction of the rows that have the value of a category, e.g. `'web': 0.4..0.6`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CategoryRatio {
+    pub category: Category,
+    pub ratio: Threshold,
+}
+
+impl Display for CategoryRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.ratio {
+            Threshold::Between(min, max) => write!(f, "{}: {}..{}", self.category, min, max),
+            ratio => write!(f, "{}: {}", self.category, ratio),
+        }
+    }
+}
+
+/// Expected distribution of the values of a column.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum Distribution {
+    /// `{ 'web': 0.4..0.6, 'app': 0.3..0.5 }`, values that are not listed can have any ratio
+    Categories(Vec<CategoryRatio>),
+    /// `MAX 0.8`, no value may have a ratio above 80%
+    MaxCategoryRatio(f64),
+}
+
+impl Display for Distribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Distribution::Categories(categories) => write!(
+                f,
+                "{{{}}}",
+                categories
+                    .iter()
+                    .map(|category| category.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Distribution::MaxCategoryRatio(ratio) => write!(f, "MAX {}", ratio),
+        }
+    }
+}
+
+/// `-DISTRIBUTION` rule on the ratio of the values of a categorical column.
+#[derive(Clone, Debug, PartialEq, Serialize, ValidColumnRule)]
+#[valid_for(string_like, boolean_like, integer_like)]
+#[validate_with(validate_distribution)]
+pub struct ValueDistribution {
+    pub name: String,
+    pub distribution: Distribution,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl ValueDistribution {
+    pub fn new(
+        name: Option<String>,
+        distribution: Distribution,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            distribution,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+
+    /// The categories have to match the column type and their minimum ratios must not sum to
+    /// more than all rows.
+    fn validate_distribution(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        let invalid_ratio = |message: String| {
+            Err(ColumnValidationError::InvalidThreshold(format!(
+                "Distribution of column {}: {}",
+                column.name, message
+            )))
+        };
+        let categories = match &self.distribution {
+            Distribution::MaxCategoryRatio(ratio) if !(*ratio > 0.0 && *ratio <= 1.0) => {
+                return invalid_ratio(format!("{} is not between 0 and 1", ratio));
+            }
+            Distribution::MaxCategoryRatio(_) => return Ok(String::from("valid")),
+            Distribution::Categories(categories) => categories,
+        };
+
+        for (i, category_ratio) in categories.iter().enumerate() {
+            if !category_ratio
+                .category
+                .is_assignable_to(&column.data_type.class)
+            {
+                return Err(ColumnValidationError::InvalidType(format!(
+                    "Distribution of column {}: category {} does not match type {}",
+                    column.name, category_ratio.category, column.data_type
+                )));
+            }
+            if categories[..i]
+                .iter()
+                .any(|other| other.category == category_ratio.category)
+            {
+                return invalid_ratio(format!(
+                    "category {} is listed twice",
+                    category_ratio.category
+                ));
+            }
+            if let Err(message) = category_ratio.ratio.check_bounds(0.0, 1.0) {
+                return invalid_ratio(format!("{}: {}", category_ratio.category, message));
+            }
+        }
+
+        let min_total = round_value(
+            categories
+                .iter()
+                .map(|category_ratio| match category_ratio.ratio {
+                    Threshold::Between(min, _) => min,
+                    Threshold::Compare(..) => 0.0,
+                })
+                .sum::<f64>(),
+        );
+        if min_total > 1.0 {
+            return invalid_ratio(format!(
+                "the minimum ratios sum to {}, which is more than all rows",
+                min_total
+            ));
+        }
+        Ok(String::from("valid"))
+    }
+
+    /// Name of the column that holds the ratio of the value of each row.
+    pub fn ratio_column(&self, column_name: &str) -> String {
+        format!("{}_category_ratio", column_name)
+    }
+
+    /// Window expression of the ratio of the rows with the same value.
+    pub fn ratio_value(&self, column_name: &str) -> String {
+        format!(
+            "count(*) over (partition by {}) / count(*) over ()",
+            column_name
+        )
+    }
+}
+
+impl Display for ValueDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-DISTRIBUTION {}", self.distribution)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::column_rule::ColumnRule;
+    use crate::model::distribution::{Category, CategoryRatio, Distribution, ValueDistribution};
+    use crate::model::rule_traits::ValidColumnRule;
+    use crate::model::table_expr::{ColumnDef, DataType};
+    use crate::model::threshold::Threshold;
+    use crate::parser::table::ColumnRuleExprParser;
+    use lalrpop_util::ParseError;
+    use rstest::rstest;
+
+    fn parse(rule: &str) -> Result<Distribution, String> {
+        let rule_filter = ColumnRuleExprParser::new()
+            .parse(rule)
+            .map_err(|err| match err {
+                ParseError::User { error } => error.to_string(),
+                err => err.to_string(),
+            })?;
+        match &rule_filter.rules[..] {
+            [ColumnRule::ValueDistribution(rule)] => Ok(rule.distribution.clone()),
+            rules => Err(format!("Expected a distribution, found {:?}", rules)),
+        }
+    }
+
+    fn ratio(category: Category, min: f64, max: f64) -> CategoryRatio {
+        CategoryRatio {
+            category,
+            ratio: Threshold::Between(min, max),
+        }
+    }
+
+    #[rstest]
+    #[case("-DISTRIBUTION { 'web': 0.4..0.6, \"app, mobile\": 30%..50% }", Distribution::Categories(vec![
+        ratio(Category::String("web".to_owned()), 0.4, 0.6),
+        ratio(Category::String("app, mobile".to_owned()), 0.3, 0.5),
+    ]))]
+    #[case("-distribution {1: 0..0.1, -1: 0.5..1, TRUE: 0.1..0.2}", Distribution::Categories(vec![
+        ratio(Category::Integer(1), 0.0, 0.1),
+        ratio(Category::Integer(-1), 0.5, 1.0),
+        ratio(Category::Boolean(true), 0.1, 0.2),
+    ]))]
+    #[case("-DISTRIBUTION {'web': 0..1., 'app': 5% .. 1}", Distribution::Categories(vec![
+        ratio(Category::String("web".to_owned()), 0.0, 1.0),
+        ratio(Category::String("app".to_owned()), 0.05, 1.0),
+    ]))]
+    #[case("-DISTRIBUTION MAX 80%", Distribution::MaxCategoryRatio(0.8))]
+    fn test_parse(#[case] rule: &str, #[case] expected: Distribution) {
+        assert_eq!(parse(rule), Ok(expected.clone()));
+        let rule = ValueDistribution::new(None, expected.clone(), None);
+        assert_eq!(
+            parse(&rule.to_string()),
+            Ok(expected),
+            "rule has to round trip"
+        );
+    }
+
+    #[rstest]
+    #[case("-DISTRIBUTION {99999999999999999999999: 0.1..0.2}")]
+    #[case("-DISTRIBUTION {-99999999999999999999999: 0.1..0.2}")]
+    #[case("-DISTRIBUTION {-1.5: 0.1..0.2}")]
+    #[case("-DISTRIBUTION {}")]
+    #[case("-DISTRIBUTION {'web': 0.1}")]
+    #[case("-DISTRIBUTION MAX")]
+    fn test_parse_error(#[case] rule: &str) {
+        assert!(parse(rule).is_err());
+    }
+
+    #[test]
+    fn test_parse_integer_overflow_error() {
+        assert_eq!(
+            parse("-DISTRIBUTION {99999999999999999999999: 0.1..0.2}"),
+            Err("Category 99999999999999999999999 has to be an integer between -9223372036854775808 and 9223372036854775807".to_owned())
+        );
+    }
+
+    #[rstest]
+    #[case(
+        DataType::new("VarChar", Some(10), None),
+        "-DISTRIBUTION {'web': 0.4..0.6, 'app': 0.3..0.5}",
+        true
+    )]
+    #[case(
+        DataType::new("VarChar", Some(10), None),
+        "-DISTRIBUTION {'web': 0.7..1, 'app': 0.3..0.5}",
+        true
+    )]
+    #[case(
+        DataType::new("VarChar", Some(10), None),
+        "-DISTRIBUTION {'web': 0.8..1, 'app': 0.3..0.5}",
+        false
+    )]
+    #[case(
+        DataType::new("VarChar", Some(10), None),
+        "-DISTRIBUTION {'web': 0.6..0.4}",
+        false
+    )]
+    #[case(
+        DataType::new("VarChar", Some(10), None),
+        "-DISTRIBUTION {'web': 0.1..0.2, 'web': 0.1..0.2}",
+        false
+    )]
+    #[case(
+        DataType::new("VarChar", Some(10), None),
+        "-DISTRIBUTION {1: 0.1..0.2}",
+        false
+    )]
+    #[case(
+        DataType::new("Int", Some(10), None),
+        "-DISTRIBUTION {1: 0.1..0.2}",
+        true
+    )]
+    #[case(
+        DataType::new("Bool", None, None),
+        "-DISTRIBUTION {true: 0.1..0.2}",
+        true
+    )]
+    #[case(
+        DataType::new("Decimal", Some(10), Some(2)),
+        "-DISTRIBUTION MAX 0.8",
+        false
+    )]
+    #[case(DataType::new("Int", Some(10), None), "-DISTRIBUTION MAX 0", false)]
+    fn test_validate_col_type(
+        #[case] data_type: DataType,
+        #[case] rule: &str,
+        #[case] is_valid: bool,
+    ) {
+        let column = ColumnDef::new("Channel".to_owned(), data_type, false, false);
+        let rule = ValueDistribution::new(None, parse(rule).unwrap(), None);
+        assert_eq!(rule.validate_col_type(&column).is_ok(), is_valid);
+    }
+}
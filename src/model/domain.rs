@@ -0,0 +1,275 @@
+use crate::model::catalog::Catalog;
+use crate::model::rule_filter::filter::ColumnRuleFilter;
+use crate::model::rule_traits::ValidColumnRule;
+use crate::model::table_expr::{ColumnDef, DataType};
+use crate::model::threshold::Threshold;
+use crate::model::udf::resolve_functions;
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+
+/// Entry of a rule list, either a rule or a reference to a ruleset or domain with `-USE`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuleItem {
+    Rule(ColumnRuleFilter),
+    Use(RuleSetRef),
+}
+
+/// `-USE <name> [threshold] [| filter]` reference to the rules of a ruleset or domain. The
+/// threshold overrides the thresholds of the referenced rules.
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
+pub struct RuleSetRef {
+    pub name: String,
+    pub threshold: Option<Threshold>,
+    pub filter_string: Option<String>,
+}
+
+impl RuleSetRef {
+    pub fn new(name: String, threshold: Option<Threshold>, filter_string: Option<String>) -> Self {
+        Self {
+            name,
+            threshold,
+            filter_string,
+        }
+    }
+}
+
+/// `RULESET <name> { rules };` definition of rules that can be used by columns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleSetDef {
+    pub name: String,
+    pub rules: Vec<ColumnRuleFilter>,
+    pub uses: Vec<RuleSetRef>,
+}
+
+impl RuleSetDef {
+    pub fn new(name: String, items: Vec<RuleItem>) -> Self {
+        let (rules, uses) = split_items(items);
+        Self { name, rules, uses }
+    }
+
+    /// Replaces the references to other rulesets with their rules.
+    pub fn expand(&self, catalog: &Catalog) -> Result<RuleSetDef, DDLxParseError> {
+        let mut rules = self.rules.clone();
+        for ruleset_ref in &self.uses {
+            rules.extend(resolve_ref(ruleset_ref, catalog)?);
+        }
+        resolve_functions(&mut rules, catalog)?;
+        Ok(RuleSetDef {
+            name: self.name.clone(),
+            rules,
+            uses: vec![],
+        })
+    }
+}
+
+/// `CREATE DOMAIN <name> AS <type> [NOT NULL] { rules };` definition of a reusable column type
+/// with its rules.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DomainDef {
+    pub name: String,
+    pub data_type: DataType,
+    pub not_null: bool,
+    pub rules: Vec<ColumnRuleFilter>,
+    pub uses: Vec<RuleSetRef>,
+}
+
+impl DomainDef {
+    pub fn new(name: String, data_type: DataType, not_null: bool, items: Vec<RuleItem>) -> Self {
+        let (rules, uses) = split_items(items);
+        Self {
+            name,
+            data_type,
+            not_null,
+            rules,
+            uses,
+        }
+    }
+
+    /// Replaces the references to rulesets with their rules and checks that all rules are valid
+    /// for the type of the domain.
+    pub fn expand(&self, catalog: &Catalog) -> Result<DomainDef, DDLxParseError> {
+        let mut rules = self.rules.clone();
+        for ruleset_ref in &self.uses {
+            rules.extend(resolve_ref(ruleset_ref, catalog)?);
+        }
+        resolve_functions(&mut rules, catalog)?;
+
+        let column = ColumnDef::new(
+            self.name.clone(),
+            self.data_type.clone(),
+            self.not_null,
+            false,
+        );
+        for rule in rules.iter().flat_map(|rule_filter| &rule_filter.rules) {
+            if let Err(err) = rule.validate_col_type(&column) {
+                return Err(DDLxParseError::ColumnValidationError(format!(
+                    "Domain {}: {}",
+                    self.name, err
+                )));
+            }
+        }
+
+        Ok(DomainDef {
+            rules,
+            uses: vec![],
+            ..self.clone()
+        })
+    }
+}
+
+/// Splits a rule list into the rules and the references to rulesets.
+pub fn split_items(items: Vec<RuleItem>) -> (Vec<ColumnRuleFilter>, Vec<RuleSetRef>) {
+    let mut rules: Vec<ColumnRuleFilter> = vec![];
+    let mut uses: Vec<RuleSetRef> = vec![];
+    for item in items {
+        match item {
+            RuleItem::Rule(rule) => rules.push(rule),
+            RuleItem::Use(ruleset_ref) => uses.push(ruleset_ref),
+        }
+    }
+    (rules, uses)
+}
+
+/// Expands the domain type and the rulesets used by a column into its type and rules. Domain
+/// rules are placed after the rules implied by the column definition. Functions used by the rules
+/// are resolved from the catalog.
+pub fn expand_column(column: &ColumnDef, catalog: &Catalog) -> Result<ColumnDef, DDLxParseError> {
+    let mut expanded = column.clone();
+
+    if let Some(domain_name) = &column.domain {
+        let domain = catalog.find_domain(domain_name).ok_or_else(|| {
+            DDLxParseError::ColumnValidationError(format!(
+                "Column {} has unknown type or domain {}",
+                column.name, domain_name
+            ))
+        })?;
+        let position = expanded.rules.len().min(1);
+        expanded
+            .rules
+            .splice(position..position, domain.rules.clone());
+        expanded.set_data_type(domain.data_type.clone());
+        if domain.not_null {
+            expanded.set_not_null();
+        }
+        expanded.domain = None;
+    }
+
+    for ruleset_ref in &column.rulesets {
+        expanded.rules.extend(resolve_ref(ruleset_ref, catalog)?);
+    }
+    expanded.rulesets = vec![];
+    resolve_functions(&mut expanded.rules, catalog)?;
+
+    Ok(expanded)
+}
+
+/// Rules of a referenced ruleset or domain with the threshold and filter of the reference.
+fn resolve_ref(
+    ruleset_ref: &RuleSetRef,
+    catalog: &Catalog,
+) -> Result<Vec<ColumnRuleFilter>, DDLxParseError> {
+    let rules = match (
+        catalog.find_ruleset(&ruleset_ref.name),
+        catalog.find_domain(&ruleset_ref.name),
+    ) {
+        (Some(ruleset), _) => ruleset.rules.clone(),
+        (None, Some(domain)) => domain.rules.clone(),
+        (None, None) => {
+            return Err(DDLxParseError::ColumnValidationError(format!(
+                "Unknown ruleset or domain {}",
+                ruleset_ref.name
+            )))
+        }
+    };
+
+    rules
+        .into_iter()
+        .map(|rule_filter| {
+            let filter_string = match (&ruleset_ref.filter_string, rule_filter.filter_string) {
+                (Some(_), Some(_)) => {
+                    return Err(DDLxParseError::InvalidFilterCondition(format!(
+                        "Ruleset {} contains filtered rules and cannot be used with a filter",
+                        ruleset_ref.name
+                    )))
+                }
+                (filter_string, rule_filter_string) => filter_string.clone().or(rule_filter_string),
+            };
+            let rules = rule_filter
+                .rules
+                .into_iter()
+                .map(|rule| match ruleset_ref.threshold {
+                    Some(threshold) => rule.with_threshold(threshold),
+                    None => rule,
+                })
+                .collect();
+            Ok(ColumnRuleFilter::new(filter_string, rules))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::column_rule::ColumnRule;
+    use crate::model::data_class::DataClass;
+    use crate::model::threshold::Threshold;
+    use crate::parser::parse_catalog;
+    use rstest::rstest;
+
+    const DEFINITIONS: &str = "RULESET Filled {-NOT_EMPTY};
+        CREATE DOMAIN Email AS VARCHAR(255) NOT NULL {-REGEX \"^[^@]+@[^@]+$\", -USE Filled};";
+
+    #[test]
+    fn test_expand_domain_column() {
+        let catalog = parse_catalog(&format!(
+            "{}\nCREATE TABLE users {{id INT(10), email Email UNIQUE {{-CONTAINS \"@\"}}}};",
+            DEFINITIONS
+        ))
+        .unwrap();
+        let column = &catalog.tables[0].columns[1];
+
+        assert_eq!(column.data_type.class, DataClass::VarChar);
+        assert!(column.not_null);
+        assert_eq!(column.domain, None);
+        let rules = column
+            .rules
+            .iter()
+            .flat_map(|rule_filter| &rule_filter.rules)
+            .collect::<Vec<&ColumnRule>>();
+        assert_eq!(rules.len(), 7);
+        assert!(rules
+            .iter()
+            .any(|rule| matches!(rule, ColumnRule::IsType(rule) if rule.data_type.class == DataClass::VarChar)));
+    }
+
+    #[test]
+    fn test_expand_ruleset_threshold() {
+        let catalog = parse_catalog(&format!(
+            "{}\nCREATE TABLE users {{email VARCHAR(100) {{-USE Email 0.9 | id > 3,}}}};",
+            DEFINITIONS
+        ))
+        .unwrap();
+        let rule_filter = catalog.tables[0].columns[0]
+            .rules
+            .iter()
+            .find(|rule_filter| rule_filter.filter_string.is_some())
+            .unwrap();
+
+        assert_eq!(rule_filter.rules.len(), 2);
+        assert!(rule_filter.rules.iter().all(|rule| match rule {
+            ColumnRule::RegexPattern(rule) => rule.threshold == Threshold::from(0.9),
+            ColumnRule::NotEmpty(rule) => rule.threshold == Threshold::from(0.9),
+            _ => false,
+        }));
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE t {email Email};")]
+    #[case("CREATE TABLE t {email VARCHAR(10) {-USE Missing}};")]
+    #[case("CREATE DOMAIN Amount AS INT(10) {-NOT_EMPTY};")]
+    #[case("RULESET Filled {-NOT_EMPTY};\nCREATE TABLE t {amount INT(10) {-USE Filled}};")]
+    #[case("RULESET Filled {-NOT_EMPTY | id > 1,};\nCREATE TABLE t {name VARCHAR(10) {-USE Filled | id > 2,}};")]
+    #[case("RULESET Filled {-NOT_EMPTY};\nRULESET Filled {-UNIQUE};")]
+    fn test_expand_error(#[case] input_string: &str) {
+        assert!(parse_catalog(input_string).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::model::catalog::Catalog;
+use crate::model::table_expr::DataType;
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Column of a table or view another column is derived from.
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
+pub struct ColumnLineage {
+    pub table: String,
+    pub column: String,
+}
+
+impl ColumnLineage {
+    /// Splits a column path like `crm.Customers.Id` into table and column.
+    pub fn from_path(path: &str) -> Self {
+        let (table, column) = path.rsplit_once('.').unwrap_or(("", path));
+        Self {
+            table: table.to_owned(),
+            column: column.to_owned(),
+        }
+    }
+}
+
+impl Display for ColumnLineage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.table, self.column)
+    }
+}
+
+/// Edge from a source column to a derived column, either declared with `LINEAGE FROM` or
+/// inferred from the select statement of a view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineageEdge {
+    pub source: ColumnLineage,
+    pub target: ColumnLineage,
+    pub declared: bool,
+}
+
+/// Derived column that does not keep the `NOT NULL` guarantee of its declared source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineageWarning {
+    pub source: ColumnLineage,
+    pub target: ColumnLineage,
+}
+
+impl Display for LineageWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Column {} is nullable but its source {} is NOT NULL",
+            self.target, self.source
+        )
+    }
+}
+
+/// Column level lineage of all tables and views of a catalog.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct LineageGraph {
+    pub edges: Vec<LineageEdge>,
+}
+
+impl LineageGraph {
+    pub fn from_catalog(catalog: &Catalog) -> Self {
+        let mut edges: Vec<LineageEdge> = vec![];
+        for table in &catalog.tables {
+            for column in &table.columns {
+                if let Some(source) = &column.lineage {
+                    edges.push(LineageEdge {
+                        source: source.clone(),
+                        target: ColumnLineage {
+                            table: table.table_ref.to_string(),
+                            column: column.name.clone(),
+                        },
+                        declared: true,
+                    });
+                }
+            }
+        }
+        for view in &catalog.views {
+            for column in &view.columns {
+                for source in &column.lineage {
+                    edges.push(LineageEdge {
+                        source: source.clone(),
+                        target: ColumnLineage {
+                            table: view.view_ref.to_string(),
+                            column: column.name.clone(),
+                        },
+                        declared: false,
+                    });
+                }
+            }
+        }
+        Self { edges }
+    }
+
+    /// Source columns of a derived column.
+    pub fn sources(&self, table: &str, column: &str) -> Vec<&ColumnLineage> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.target.table == table && edge.target.column == column)
+            .map(|edge| &edge.source)
+            .collect()
+    }
+
+    /// Checks that the sources of declared lineage exist in the catalog and have a type that is
+    /// compatible with the derived column. Returns warnings for dropped `NOT NULL` guarantees.
+    pub fn validate(&self, catalog: &Catalog) -> Result<Vec<LineageWarning>, DDLxParseError> {
+        let mut warnings: Vec<LineageWarning> = vec![];
+        for edge in self.edges.iter().filter(|edge| edge.declared) {
+            let (source_type, source_not_null) =
+                find_column(catalog, &edge.source).ok_or_else(|| {
+                    DDLxParseError::LineageValidationError(format!(
+                        "Lineage source {} of column {} is not defined",
+                        edge.source, edge.target
+                    ))
+                })?;
+            let (target_type, target_not_null) = find_column(catalog, &edge.target)
+                .expect("declared lineage belongs to a column of the catalog");
+
+            if !source_type.class.is_assignable_to(&target_type.class) {
+                return Err(DDLxParseError::LineageValidationError(format!(
+                    "Column {} of type {} cannot be derived from {} of type {}",
+                    edge.target, target_type, edge.source, source_type
+                )));
+            }
+            if source_not_null && !target_not_null {
+                warnings.push(LineageWarning {
+                    source: edge.source.clone(),
+                    target: edge.target.clone(),
+                });
+            }
+        }
+        Ok(warnings)
+    }
+}
+
+/// Type and `NOT NULL` constraint of a column of a table or view.
+fn find_column(catalog: &Catalog, column: &ColumnLineage) -> Option<(DataType, bool)> {
+    if let Some(table) = catalog.find_table(&column.table) {
+        return table
+            .columns
+            .iter()
+            .find(|c| c.name == column.column)
+            .map(|c| (c.data_type.clone(), c.not_null));
+    }
+    catalog.find_view(&column.table).and_then(|view| {
+        view.columns
+            .iter()
+            .find(|c| c.name == column.column)
+            .map(|c| (c.data_type.clone(), !c.nullable))
+    })
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::lineage::{ColumnLineage, LineageGraph};
+    use crate::parser::parse_catalog;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("crm.Customers.Id", "crm.Customers", "Id")]
+    #[case("Customers.Id", "Customers", "Id")]
+    fn test_column_lineage_from_path(
+        #[case] path: &str,
+        #[case] table: &str,
+        #[case] column: &str,
+    ) {
+        let lineage = ColumnLineage::from_path(path);
+        assert_eq!(lineage.table, table);
+        assert_eq!(lineage.column, column);
+    }
+
+    #[test]
+    fn test_lineage_graph() {
+        let catalog = parse_catalog(
+            "CREATE TABLE crm.Customers {Id INT(10) NOT NULL, Name VARCHAR(100)};
+            CREATE TABLE Orders {customer_id BIGINT(20) LINEAGE FROM crm.Customers.Id, \
+            customer_name VARCHAR(100) NOT NULL LINEAGE FROM crm.Customers.Name};
+            CREATE VIEW CustomerOrders AS SELECT customer_id AS id FROM Orders;",
+        )
+        .unwrap();
+        let graph = LineageGraph::from_catalog(&catalog);
+
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(
+            graph.sources("CustomerOrders", "id"),
+            vec![&ColumnLineage::from_path("Orders.customer_id")]
+        );
+
+        let warnings = graph.validate(&catalog).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "Column Orders.customer_id is nullable but its source crm.Customers.Id is NOT NULL"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::model::catalog::Catalog;
+use crate::model::column_rule::ColumnRule;
+use regex::Regex;
+use regex_syntax::ast::parse::Parser;
+use regex_syntax::ast::ErrorKind;
+use std::fmt::Display;
+
+/// Valid pattern that is not supported by every regex engine.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternWarning {
+    pub table: String,
+    pub column: String,
+    pub pattern: String,
+    pub message: String,
+}
+
+impl Display for PatternWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pattern \"{}\" of column {}.{}: {}",
+            self.pattern, self.table, self.column, self.message
+        )
+    }
+}
+
+/// Compiles a regex with the RE2 like `regex` crate. PyDeequ and DQDL evaluate patterns with the
+/// Java regex engine, so look-around and backreferences are accepted with a warning and Python
+/// style named groups, which Java rejects, produce a warning as well.
+pub fn check_regex(pattern: &str) -> Result<Option<String>, String> {
+    if let Err(err) = Regex::new(pattern) {
+        return match Parser::new().parse(pattern) {
+            Err(ast_err)
+                if matches!(
+                    ast_err.kind(),
+                    ErrorKind::UnsupportedLookAround | ErrorKind::UnsupportedBackreference
+                ) =>
+            {
+                Ok(Some(format!(
+                    "{}, the pattern is only supported by Java based targets and not checked at compile time",
+                    ast_err.kind()
+                )))
+            }
+            _ => Err(err.to_string()),
+        };
+    }
+
+    if pattern.contains("(?P<") {
+        return Ok(Some(
+            "named groups with (?P<name>..) are not supported by Java based targets, use (?<name>..)"
+                .to_owned(),
+        ));
+    }
+    Ok(None)
+}
+
+/// Checks the escapes of a LIKE pattern, `\` may only escape `%`, `_` or `\` like in Spark SQL.
+pub fn check_like(pattern: &str) -> Result<(), String> {
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('%' | '_' | '\\') => {}
+                Some(escaped) => {
+                    return Err(format!(
+                        "the escape character is not allowed to precede '{}'",
+                        escaped
+                    ))
+                }
+                None => return Err("the pattern ends with an escape character".to_owned()),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Warnings for all regex rules of the tables of a catalog.
+pub fn pattern_warnings(catalog: &Catalog) -> Vec<PatternWarning> {
+    let mut warnings: Vec<PatternWarning> = vec![];
+    for table in &catalog.tables {
+        for column in &table.columns {
+            let rules = column
+                .rules
+                .iter()
+                .flat_map(|rule_filter| &rule_filter.rules);
+            for rule in rules {
+                if let ColumnRule::RegexPattern(rule) = rule {
+                    if let Ok(Some(message)) = check_regex(&rule.pattern) {
+                        warnings.push(PatternWarning {
+                            table: table.table_ref.to_string(),
+                            column: column.name.clone(),
+                            pattern: rule.pattern.clone(),
+                            message,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::pattern::{check_like, check_regex, pattern_warnings};
+    use crate::parser::parse_catalog;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("^[0-9]{3}-[a-z]+$", Ok(false))]
+    #[case("^(?=(?:\\D*\\d){10}(?:(?:\\D*\\d){3})?$)[\\d-]+$", Ok(true))]
+    #[case("(a)\\1", Ok(true))]
+    #[case("(?P<year>[0-9]{4})", Ok(true))]
+    #[case("[0-9", Err(()))]
+    #[case("a{2,1}", Err(()))]
+    fn test_check_regex(#[case] pattern: &str, #[case] expected: Result<bool, ()>) {
+        let result = check_regex(pattern)
+            .map(|warning| warning.is_some())
+            .map_err(|_| ());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("%test_\\%", true)]
+    #[case("C:\\\\%", true)]
+    #[case("\\d%", false)]
+    #[case("test\\", false)]
+    fn test_check_like(#[case] pattern: &str, #[case] expected: bool) {
+        assert_eq!(check_like(pattern).is_ok(), expected);
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE t {isbn VARCHAR(20) {-REGEX \"[0-9\"}};")]
+    #[case("CREATE TABLE t {isbn VARCHAR(20) {-LIKE \"\\d%\"}};")]
+    fn test_invalid_pattern_rule(#[case] input_string: &str) {
+        assert!(parse_catalog(input_string).is_err());
+    }
+
+    #[test]
+    fn test_pattern_warnings() {
+        let catalog = parse_catalog(
+            "CREATE TABLE t {code VARCHAR(20) {-REGEX \"^(?!0)[0-9]+$\", -LIKE \"a%\"}};",
+        )
+        .unwrap();
+        let warnings = pattern_warnings(&catalog);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0]
+            .to_string()
+            .starts_with("Pattern \"^(?!0)[0-9]+$\" of column t.code: look-around"));
+    }
+}
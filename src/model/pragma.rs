@@ -0,0 +1,240 @@
+use crate::model::column_rule::ColumnRule;
+use crate::model::table_expr::ColumnDef;
+use crate::parser::error_utils::DDLxParseError;
+use std::fmt::Display;
+
+/// Fraction of the rows that sampled checks are run on.
+pub const SAMPLE_FRACTION: f64 = 0.1;
+
+/// `#pragma <name> = <value>` of a file or a table. The name and value are validated when the
+/// pragma is applied, so unknown pragmas are reported with the statement they belong to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pragma {
+    pub name: String,
+    pub value: String,
+}
+
+impl Pragma {
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        }
+    }
+}
+
+impl Display for Pragma {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#pragma {} = {}", self.name, self.value)
+    }
+}
+
+/// Metrics repository set with `#pragma metrics_repository = "<path>"`, later pragmas override
+/// earlier ones.
+pub fn metrics_repository(pragmas: &[Pragma]) -> Option<&str> {
+    pragmas
+        .iter()
+        .rev()
+        .find(|pragma| pragma.name == "metrics_repository")
+        .map(|pragma| pragma.value.as_str())
+}
+
+/// Whether implicit rules are generated, checked on a sample of the rows or not generated.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ImplicitMode {
+    #[default]
+    On,
+    Off,
+    Sampled,
+}
+
+/// Groups of implicit rules that are controlled by the same pragma.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImplicitKind {
+    /// `implicit_type_checks`: type, length, integer range and precision checks of the type
+    TypeChecks,
+    /// `implicit_not_null`: completeness of `NOT NULL` and primary key columns
+    NotNull,
+    /// `implicit_uniqueness`: uniqueness of `UNIQUE`, primary key and auto increment columns
+    Uniqueness,
+}
+
+impl ImplicitKind {
+    /// Group of a rule, rules like `Positive` of auto increment columns are not controlled.
+    pub fn of(rule: &ColumnRule) -> Option<Self> {
+        match rule {
+            ColumnRule::IsType(_)
+            | ColumnRule::MaxLength(_)
+            | ColumnRule::IntegerRange(_)
+            | ColumnRule::NumericPrecision(_) => Some(ImplicitKind::TypeChecks),
+            ColumnRule::NonNull(_) => Some(ImplicitKind::NotNull),
+            ColumnRule::Uniqueness(_) => Some(ImplicitKind::Uniqueness),
+            _ => None,
+        }
+    }
+}
+
+/// Modes of the implicit rules of a table, later pragmas override earlier ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImplicitRules {
+    pub type_checks: ImplicitMode,
+    pub not_null: ImplicitMode,
+    pub uniqueness: ImplicitMode,
+}
+
+impl ImplicitRules {
+    pub fn from_pragmas(pragmas: &[Pragma]) -> Result<Self, DDLxParseError> {
+        let mut implicit_rules = Self::default();
+        for pragma in pragmas {
+            let mode = match pragma.name.as_str() {
+                "implicit_type_checks" => &mut implicit_rules.type_checks,
+                "implicit_not_null" => &mut implicit_rules.not_null,
+                "implicit_uniqueness" => &mut implicit_rules.uniqueness,
+                "metrics_repository" if !pragma.value.is_empty() => continue,
+                _ => {
+                    return Err(DDLxParseError::InvalidPragma(format!(
+                        "Unknown pragma {}",
+                        pragma
+                    )))
+                }
+            };
+            // only the uniqueness can be checked on a sample of the rows
+            *mode = match pragma.value.as_str() {
+                "on" => ImplicitMode::On,
+                "off" => ImplicitMode::Off,
+                "sampled" if pragma.name == "implicit_uniqueness" => ImplicitMode::Sampled,
+                _ => {
+                    return Err(DDLxParseError::InvalidPragma(format!(
+                        "Invalid value of {}",
+                        pragma
+                    )))
+                }
+            };
+        }
+        Ok(implicit_rules)
+    }
+
+    fn mode(&self, kind: ImplicitKind) -> ImplicitMode {
+        match kind {
+            ImplicitKind::TypeChecks => self.type_checks,
+            ImplicitKind::NotNull => self.not_null,
+            ImplicitKind::Uniqueness => self.uniqueness,
+        }
+    }
+
+    /// Removes the implicit rules that are turned off and marks the sampled ones. Explicit rules
+    /// are not changed.
+    pub fn apply(&self, column: &mut ColumnDef) {
+        for rule_filter in column.rules.iter_mut() {
+            rule_filter.rules = rule_filter
+                .rules
+                .drain(..)
+                .filter_map(|rule| {
+                    let mode = match ImplicitKind::of(&rule) {
+                        Some(kind) if rule.is_implicit() => self.mode(kind),
+                        _ => ImplicitMode::On,
+                    };
+                    match (mode, rule) {
+                        (ImplicitMode::Off, _) => None,
+                        (ImplicitMode::Sampled, ColumnRule::Uniqueness(rule)) => {
+                            Some(ColumnRule::Uniqueness(rule.sampled()))
+                        }
+                        (_, rule) => Some(rule),
+                    }
+                })
+                .collect();
+        }
+        column
+            .rules
+            .retain(|rule_filter| !rule_filter.rules.is_empty());
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::column_rule::ColumnRule;
+    use crate::model::pragma::{metrics_repository, ImplicitMode, ImplicitRules, Pragma};
+    use crate::model::statement::Statement;
+    use crate::model::table_expr::{ColumnDef, ColumnOption, DataType};
+    use crate::parser::table::CatalogExprParser;
+    use rstest::rstest;
+
+    fn parse(input: &str) -> Result<Pragma, String> {
+        let statements = CatalogExprParser::new()
+            .parse(input)
+            .map_err(|err| err.to_string())?;
+        match &statements[..] {
+            [Statement::Pragma(pragma)] => Ok(pragma.clone()),
+            statements => Err(format!("Expected a pragma, found {:?}", statements)),
+        }
+    }
+
+    #[rstest]
+    #[case("#pragma implicit_type_checks = off", "implicit_type_checks", "off")]
+    #[case(
+        "#PRAGMA Implicit_Uniqueness=Sampled",
+        "implicit_uniqueness",
+        "sampled"
+    )]
+    #[case(
+        "#pragma metrics_repository = \"s3://DQ/metrics.json\";",
+        "metrics_repository",
+        "s3://DQ/metrics.json"
+    )]
+    #[case("# Pragma implicit_not_null=ON", "implicit_not_null", "on")]
+    fn test_parse_pragma(#[case] input: &str, #[case] name: &str, #[case] value: &str) {
+        let pragma = parse(input).unwrap();
+        assert_eq!(pragma.name, name);
+        assert_eq!(pragma.value, value);
+    }
+
+    #[rstest]
+    #[case("#pragma implicit_checks = off")]
+    #[case("#pragma implicit_not_null = sampled")]
+    #[case("#pragma implicit_type_checks = maybe")]
+    fn test_invalid_pragma(#[case] input: &str) {
+        assert!(ImplicitRules::from_pragmas(&[parse(input).unwrap()]).is_err());
+    }
+
+    #[rstest]
+    #[case("#pragma metrics_repository = \"\"")]
+    #[case("#pragma implicit_not_null")]
+    #[case("#pragma = off")]
+    #[case("#pragma implicit_not_null = 'a' 'b'")]
+    fn test_parse_pragma_error(#[case] input: &str) {
+        assert!(parse(input).is_err());
+    }
+
+    #[test]
+    fn test_metrics_repository() {
+        let pragmas = [
+            "#pragma metrics_repository = \"s3://dq/old.json\"",
+            "#pragma implicit_type_checks = off",
+            "#pragma metrics_repository = \"s3://dq/metrics.json\"",
+        ]
+        .map(|pragma| parse(pragma).unwrap());
+        assert!(ImplicitRules::from_pragmas(&pragmas).is_ok());
+        assert_eq!(metrics_repository(&pragmas), Some("s3://dq/metrics.json"));
+        assert_eq!(metrics_repository(&pragmas[1..2]), None);
+    }
+
+    #[test]
+    fn test_apply_implicit_rules() {
+        let implicit_rules = ImplicitRules {
+            type_checks: ImplicitMode::Off,
+            not_null: ImplicitMode::On,
+            uniqueness: ImplicitMode::Sampled,
+        };
+        let mut column = ColumnDef::from_options(
+            "Id".to_owned(),
+            DataType::new("VARCHAR", Some(10), None),
+            vec![ColumnOption::PrimaryKey],
+        );
+        implicit_rules.apply(&mut column);
+
+        let rules = column.rules[0].rules.clone();
+        assert_eq!(rules.len(), 2);
+        assert!(matches!(rules[0], ColumnRule::NonNull(_)));
+        assert!(matches!(&rules[1], ColumnRule::Uniqueness(rule) if rule.sampled));
+    }
+}
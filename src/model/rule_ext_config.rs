@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::fmt::Display;
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct RuleExtConfig {
@@ -8,6 +9,9 @@ pub struct RuleExtConfig {
     enabled: bool,
     schedule_enabled: bool,
     schedule_frequency: ScheduleFrequency,
+    severity: Option<Severity>,
+    /// Set for rules that are generated from the column definition instead of written as rule.
+    implicit: bool,
     // future ideas:
     // - schedule_time: String
     // - schedule_date: String
@@ -32,8 +36,53 @@ impl RuleExtConfig {
             enabled: false,
             schedule_enabled: false,
             schedule_frequency: ScheduleFrequency::Daily,
+            severity: None,
+            implicit: false,
         }
     }
+
+    pub fn with_severity(self, severity: Severity) -> Self {
+        Self {
+            severity: Some(severity),
+            ..self
+        }
+    }
+
+    /// Severity of the rule, rules without a severity use the default of their table.
+    pub fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    pub fn implicit(self) -> Self {
+        Self {
+            implicit: true,
+            ..self
+        }
+    }
+
+    pub fn is_implicit(&self) -> bool {
+        self.implicit
+    }
+}
+
+/// Severity of a failing rule, written as `@severity(error|warning|info)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Severity {
+    Error,
+    #[default]
+    Warning,
+    Info,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+        write!(f, "{}", severity)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -9,7 +9,7 @@ use crate::parser::error_utils::DDLxParseError;
 
 use super::operator::ComparisonOperator;
 
-lalrpop_mod!(pub rule, "/parser/rule_filter_expr.rs");
+lalrpop_mod!(#[allow(clippy::all)] pub rule, "/parser/rule_filter_expr.rs");
 
 #[derive(Clone, Debug, Serialize)]
 pub enum FilterCondition {
@@ -140,6 +140,46 @@ impl FilterCondition {
         Ok(parsed.unwrap().reduce_nesting())
     }
 
+    /// Renders the condition as a SQL boolean expression, e.g. `Price > 1 AND (Name = 'a' OR Id < 3)`.
+    /// Values that are not numeric are quoted as SQL string literals.
+    pub fn to_sql(&self) -> String {
+        match self {
+            FilterCondition::And(conditions) => Self::join_sql(conditions, " AND "),
+            FilterCondition::Or(conditions) => Self::join_sql(conditions, " OR "),
+            FilterCondition::Not(condition) => format!("NOT ({})", condition.to_sql()),
+            FilterCondition::FieldCondition {
+                first_field,
+                operator,
+                second_field,
+            } => format!("{} {} {}", first_field, operator, second_field),
+            FilterCondition::ValueCondition {
+                field,
+                operator,
+                value,
+            } => {
+                let value = value.trim();
+                if value.parse::<f64>().is_ok() {
+                    format!("{} {} {}", field, operator, value)
+                } else {
+                    format!("{} {} '{}'", field, operator, value.replace('\'', "''"))
+                }
+            }
+        }
+    }
+
+    fn join_sql(conditions: &[FilterCondition], separator: &str) -> String {
+        conditions
+            .iter()
+            .map(|condition| match condition {
+                FilterCondition::And(_) | FilterCondition::Or(_) => {
+                    format!("({})", condition.to_sql())
+                }
+                _ => condition.to_sql(),
+            })
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+
     #[allow(clippy::needless_return)]
     pub fn sort(&self) -> Self {
         match self {
@@ -213,6 +253,12 @@ impl ColumnRuleFilter {
         }
     }
 
+    /// Unfiltered rules that are generated from the column definition.
+    #[cfg(test)]
+    pub fn implicit(rules: Vec<ColumnRule>) -> ColumnRuleFilter {
+        ColumnRuleFilter::empty_fr_rules(rules.into_iter().map(ColumnRule::implicit).collect())
+    }
+
     pub fn from_rule(filter_string: Option<String>, rule: ColumnRule) -> ColumnRuleFilter {
         ColumnRuleFilter::new(filter_string, vec![rule])
     }
@@ -243,6 +289,19 @@ impl ColumnRuleFilter {
             filter_condition: Some(filter_condition),
         })
     }
+
+    /// Returns the filter as SQL expression or `None` if the rules are not filtered.
+    /// Falls back to the raw filter string if the filter has not been parsed yet.
+    pub fn to_sql(&self) -> Option<String> {
+        if let Some(filter_condition) = &self.filter_condition {
+            return Some(filter_condition.to_sql());
+        }
+
+        self.filter_string
+            .as_ref()
+            .map(|filter_string| filter_string.trim().to_owned())
+            .filter(|filter_string| !filter_string.is_empty())
+    }
 }
 
 impl ValidColumnRule for ColumnRuleFilter {
@@ -259,8 +318,6 @@ impl ValidColumnRule for ColumnRuleFilter {
 mod tests {
     use rstest::rstest;
 
-    use crate::model::column_rule;
-
     use super::*;
 
     #[rstest]
@@ -586,4 +643,27 @@ mod tests {
         let actual = column_rule.to_string();
         assert_eq!(actual, expected);
     }
+
+    #[rstest]
+    #[case(FilterCondition::from_str("Price > 1".to_owned()).unwrap(), "Price > 1")]
+    #[case(FilterCondition::from_str("Price > 1 && Name = 'test'".to_owned()).unwrap(), "Price > 1 AND Name = 'test'")]
+    #[case(FilterCondition::from_str("Price > 1 && (Name = 'test' || Id <= Price)".to_owned()).unwrap(),
+        "Price > 1 AND (Name = 'test' OR Id <= Price)")]
+    #[case(FilterCondition::from_str("!(Price = 0.5)".to_owned()).unwrap(), "NOT (Price = 0.5)")]
+    fn test_filter_condition_to_sql(#[case] condition: FilterCondition, #[case] expected: &str) {
+        assert_eq!(condition.to_sql(), expected);
+    }
+
+    #[rstest]
+    #[case(ColumnRuleFilter::new(None, vec![]), None)]
+    #[case(ColumnRuleFilter::new(Some(" ".to_owned()), vec![]), None)]
+    #[case(ColumnRuleFilter::new(Some(" Price > 1".to_owned()), vec![]), Some("Price > 1"))]
+    #[case(ColumnRuleFilter::new(Some(" Price > 1 && Id = 'a'".to_owned()), vec![]).parse().unwrap(),
+        Some("Price > 1 AND Id = 'a'"))]
+    fn test_column_rule_filter_to_sql(
+        #[case] column_rule: ColumnRuleFilter,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(column_rule.to_sql(), expected.map(String::from));
+    }
 }
@@ -1,11 +1,39 @@
-use crate::model::table_expr::ColumnDef;
+use crate::model::table_expr::{ColumnDef, TableDef};
+use std::fmt::Display;
 
 #[derive(Debug)]
 pub enum ColumnValidationError {
     InvalidType(String),
+    UnknownColumn(String),
+    InvalidPattern(String),
+    InvalidThreshold(String),
+    InvalidSql(String),
     // RuleValidationNotImplemented(String),
 }
 
+impl Display for ColumnValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnValidationError::InvalidType(message) => write!(f, "InvalidType: {}", message),
+            ColumnValidationError::UnknownColumn(message) => {
+                write!(f, "UnknownColumn: {}", message)
+            }
+            ColumnValidationError::InvalidPattern(message) => {
+                write!(f, "InvalidPattern: {}", message)
+            }
+            ColumnValidationError::InvalidThreshold(message) => {
+                write!(f, "InvalidThreshold: {}", message)
+            }
+            ColumnValidationError::InvalidSql(message) => write!(f, "InvalidSql: {}", message),
+        }
+    }
+}
+
 pub trait ValidColumnRule {
     fn validate_col_type(&self, column: &ColumnDef) -> Result<String, ColumnValidationError>;
 }
+
+/// Validation of rules that reference one or more columns of a table.
+pub trait ValidTableRule {
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError>;
+}
@@ -0,0 +1,381 @@
+use crate::model::rule_filter::operator::ComparisonOperator;
+use crate::parser::error_utils::DDLxParseError;
+use lalrpop_util::lalrpop_mod;
+use std::fmt::Display;
+
+lalrpop_mod!(#[allow(clippy::all)] pub select, "/parser/select_expr.rs");
+
+/// `SELECT` query of a view or transformation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Select {
+    pub distinct: bool,
+    pub projections: Vec<Projection>,
+    pub source: TableSource,
+    pub joins: Vec<Join>,
+    pub filter: Option<Expression>,
+}
+
+impl Select {
+    pub fn from_str(select_string: &str) -> Result<Select, DDLxParseError> {
+        select::SelectExprParser::new()
+            .parse(select_string)
+            .map_err(|_| {
+                DDLxParseError::InvalidSelect(format!(
+                    "Failed to parse select statement: {}",
+                    select_string
+                ))
+            })
+    }
+
+    /// All tables of the query in the order they are referenced.
+    pub fn sources(&self) -> Vec<&TableSource> {
+        let mut sources = vec![&self.source];
+        sources.extend(self.joins.iter().map(|join| &join.source));
+        sources
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Projection {
+    /// `*` or `<table>.*`
+    Wildcard(Option<String>),
+    Expression {
+        expression: Expression,
+        alias: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableSource {
+    pub table_name: String,
+    pub alias: Option<String>,
+}
+
+impl TableSource {
+    /// Checks if a column qualifier like `o` or `shop.orders` references this source.
+    pub fn is_referenced_by(&self, qualifier: &str) -> bool {
+        match &self.alias {
+            Some(alias) => alias == qualifier,
+            None => {
+                self.table_name == qualifier
+                    || self.table_name.rsplit('.').next() == Some(qualifier)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Join {
+    pub kind: JoinKind,
+    pub source: TableSource,
+    pub condition: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Literal {
+    Number(String),
+    String(String),
+    Boolean(bool),
+    Null,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinaryOperator {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Modulo,
+    Concat,
+    And,
+    Or,
+    Comparison(ComparisonOperator),
+}
+
+impl BinaryOperator {
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(
+            self,
+            BinaryOperator::Plus
+                | BinaryOperator::Minus
+                | BinaryOperator::Multiply
+                | BinaryOperator::Divide
+                | BinaryOperator::Modulo
+        )
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryOperator::Plus => write!(f, "+"),
+            BinaryOperator::Minus => write!(f, "-"),
+            BinaryOperator::Multiply => write!(f, "*"),
+            BinaryOperator::Divide => write!(f, "/"),
+            BinaryOperator::Modulo => write!(f, "%"),
+            BinaryOperator::Concat => write!(f, "||"),
+            BinaryOperator::And => write!(f, "AND"),
+            BinaryOperator::Or => write!(f, "OR"),
+            BinaryOperator::Comparison(operator) => write!(f, "{}", operator),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+    Column {
+        qualifier: Option<String>,
+        name: String,
+    },
+    Literal(Literal),
+    Binary {
+        left: Box<Expression>,
+        operator: BinaryOperator,
+        right: Box<Expression>,
+    },
+    Not(Box<Expression>),
+    Negate(Box<Expression>),
+    IsNull {
+        expression: Box<Expression>,
+        negated: bool,
+    },
+    Function {
+        name: String,
+        arguments: Vec<Expression>,
+    },
+    Cast {
+        expression: Box<Expression>,
+        data_type: String,
+    },
+    /// `*` argument of functions like `count(*)`
+    Wildcard,
+    /// Aggregate of the rows that satisfy the condition, e.g. `count(*) FILTER (WHERE a > b)`
+    Filter {
+        function: Box<Expression>,
+        condition: Box<Expression>,
+    },
+}
+
+impl Expression {
+    pub fn binary(left: Expression, operator: BinaryOperator, right: Expression) -> Self {
+        Expression::Binary {
+            left: Box::new(left),
+            operator,
+            right: Box::new(right),
+        }
+    }
+
+    pub fn filtered(function: Expression, condition: Expression) -> Self {
+        Expression::Filter {
+            function: Box::new(function),
+            condition: Box::new(condition),
+        }
+    }
+
+    /// All column references of the expression in the order they appear.
+    pub fn columns(&self) -> Vec<(Option<&String>, &String)> {
+        match self {
+            Expression::Column { qualifier, name } => vec![(qualifier.as_ref(), name)],
+            Expression::Binary { left, right, .. } => {
+                let mut columns = left.columns();
+                columns.extend(right.columns());
+                columns
+            }
+            Expression::Not(expression)
+            | Expression::Negate(expression)
+            | Expression::IsNull { expression, .. }
+            | Expression::Cast { expression, .. } => expression.columns(),
+            Expression::Function { arguments, .. } => arguments
+                .iter()
+                .flat_map(|argument| argument.columns())
+                .collect(),
+            Expression::Filter {
+                function,
+                condition,
+            } => {
+                let mut columns = function.columns();
+                columns.extend(condition.columns());
+                columns
+            }
+            Expression::Literal(_) | Expression::Wildcard => vec![],
+        }
+    }
+
+    /// The expression with the qualifiers of all column references removed.
+    pub fn unqualified(&self) -> Expression {
+        let unqualified = |expression: &Expression| Box::new(expression.unqualified());
+        match self {
+            Expression::Column { name, .. } => Expression::Column {
+                qualifier: None,
+                name: name.clone(),
+            },
+            Expression::Binary {
+                left,
+                operator,
+                right,
+            } => Expression::Binary {
+                left: unqualified(left),
+                operator: operator.clone(),
+                right: unqualified(right),
+            },
+            Expression::Not(expression) => Expression::Not(unqualified(expression)),
+            Expression::Negate(expression) => Expression::Negate(unqualified(expression)),
+            Expression::IsNull {
+                expression,
+                negated,
+            } => Expression::IsNull {
+                expression: unqualified(expression),
+                negated: *negated,
+            },
+            Expression::Function { name, arguments } => Expression::Function {
+                name: name.clone(),
+                arguments: arguments
+                    .iter()
+                    .map(|argument| argument.unqualified())
+                    .collect(),
+            },
+            Expression::Cast {
+                expression,
+                data_type,
+            } => Expression::Cast {
+                expression: unqualified(expression),
+                data_type: data_type.clone(),
+            },
+            Expression::Filter {
+                function,
+                condition,
+            } => Expression::Filter {
+                function: unqualified(function),
+                condition: unqualified(condition),
+            },
+            Expression::Literal(_) | Expression::Wildcard => self.clone(),
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Column {
+                qualifier: Some(qualifier),
+                name,
+            } => write!(f, "{}.{}", qualifier, name),
+            Expression::Column {
+                qualifier: None,
+                name,
+            } => write!(f, "{}", name),
+            Expression::Literal(Literal::Number(number)) => write!(f, "{}", number),
+            Expression::Literal(Literal::String(value)) => {
+                write!(f, "'{}'", value.replace('\'', "''"))
+            }
+            Expression::Literal(Literal::Boolean(value)) => {
+                write!(f, "{}", if *value { "TRUE" } else { "FALSE" })
+            }
+            Expression::Literal(Literal::Null) => write!(f, "NULL"),
+            Expression::Binary {
+                left,
+                operator,
+                right,
+            } => write!(f, "({} {} {})", left, operator, right),
+            Expression::Not(expression) => write!(f, "NOT {}", expression),
+            Expression::Negate(expression) => write!(f, "-{}", expression),
+            Expression::IsNull {
+                expression,
+                negated,
+            } => write!(
+                f,
+                "{} IS {}NULL",
+                expression,
+                if *negated { "NOT " } else { "" }
+            ),
+            Expression::Function { name, arguments } => write!(
+                f,
+                "{}({})",
+                name,
+                arguments
+                    .iter()
+                    .map(|argument| argument.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Expression::Cast {
+                expression,
+                data_type,
+            } => write!(f, "CAST({} AS {})", expression, data_type),
+            Expression::Wildcard => write!(f, "*"),
+            Expression::Filter {
+                function,
+                condition,
+            } => write!(f, "{} FILTER (WHERE {})", function, condition),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::select_expr::{Expression, JoinKind, Projection, Select};
+    use rstest::rstest;
+
+    #[test]
+    fn test_parse_select() {
+        let select = Select::from_str(
+            "SELECT o.Id, c.Name AS customer, o.Price * o.Quantity total FROM shop.Orders o \
+            LEFT JOIN Customers c ON o.CustomerId = c.Id WHERE o.Price > 0 AND c.Name IS NOT NULL",
+        )
+        .unwrap();
+
+        assert_eq!(select.projections.len(), 3);
+        assert_eq!(select.source.table_name, "shop.Orders");
+        assert_eq!(select.source.alias, Some("o".to_owned()));
+        assert_eq!(select.joins[0].kind, JoinKind::Left);
+        assert_eq!(
+            select.projections[2],
+            Projection::Expression {
+                expression: Expression::binary(
+                    Expression::Column {
+                        qualifier: Some("o".to_owned()),
+                        name: "Price".to_owned()
+                    },
+                    super::BinaryOperator::Multiply,
+                    Expression::Column {
+                        qualifier: Some("o".to_owned()),
+                        name: "Quantity".to_owned()
+                    }
+                ),
+                alias: Some("total".to_owned())
+            }
+        );
+        assert_eq!(
+            select.filter.unwrap().to_string(),
+            "((o.Price > 0) AND c.Name IS NOT NULL)"
+        );
+    }
+
+    #[rstest]
+    #[case("SELECT * FROM Orders")]
+    #[case("SELECT o.* FROM Orders o")]
+    #[case("select distinct upper(Name) name, count(*) AS n from Orders")]
+    #[case("SELECT CAST(Price AS DOUBLE(10, 2)) AS price, 'a''b' AS text FROM Orders")]
+    #[case("SELECT a FROM t1 JOIN t2 ON t1.id = t2.id FULL OUTER JOIN t3 ON t2.id = t3.id")]
+    fn test_parse_select_success(#[case] select_string: &str) {
+        let select = Select::from_str(select_string);
+        assert!(select.is_ok(), "{:?}", select.err());
+    }
+
+    #[rstest]
+    #[case("SELECT FROM Orders")]
+    #[case("SELECT a Orders")]
+    #[case("SELECT a FROM Orders WHERE")]
+    #[case("SELECT a FROM t1 JOIN t2")]
+    fn test_parse_select_failure(#[case] select_string: &str) {
+        assert!(Select::from_str(select_string).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use serde::Serialize;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Well known string formats that are checked by the built-in rules like `-EMAIL` or `-IBAN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum SemanticFormat {
+    Email,
+    Uuid,
+    Url,
+    Ipv4,
+    Iban,
+    IsoCountry,
+    IsoCurrency,
+    PhoneE164,
+}
+
+const EMAIL_PATTERN: &str = r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}$";
+const UUID_PATTERN: &str =
+    r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$";
+const URL_PATTERN: &str = r"^(https?|ftp)://[^\s/$.?#][^\s]*$";
+const IPV4_PATTERN: &str = r"^((25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])\.){3}(25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])$";
+// country code, check digits and up to 30 alphanumeric characters, the checksum is not validated
+const IBAN_PATTERN: &str = r"^[A-Z]{2}[0-9]{2}[A-Z0-9]{11,30}$";
+const PHONE_E164_PATTERN: &str = r"^\+[1-9][0-9]{1,14}$";
+
+/// ISO 3166-1 alpha-2 country codes.
+const ISO_COUNTRY_CODES: [&str; 249] = [
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// Active ISO 4217 currency codes including funds and precious metals.
+const ISO_CURRENCY_CODES: [&str; 182] = [
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD",
+    "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP",
+    "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP",
+    "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS",
+    "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW",
+    "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD",
+    "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN",
+    "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR",
+    "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL",
+    "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY",
+    "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES",
+    "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR",
+    "XOF", "XPD", "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG", "ZWL",
+];
+
+impl SemanticFormat {
+    /// Anchored regex of the format, `None` for formats that are checked against a value set.
+    pub fn pattern(&self) -> Option<&'static str> {
+        match self {
+            SemanticFormat::Email => Some(EMAIL_PATTERN),
+            SemanticFormat::Uuid => Some(UUID_PATTERN),
+            SemanticFormat::Url => Some(URL_PATTERN),
+            SemanticFormat::Ipv4 => Some(IPV4_PATTERN),
+            SemanticFormat::Iban => Some(IBAN_PATTERN),
+            SemanticFormat::PhoneE164 => Some(PHONE_E164_PATTERN),
+            SemanticFormat::IsoCountry | SemanticFormat::IsoCurrency => None,
+        }
+    }
+
+    /// Allowed values of code formats, `None` for formats that are checked with a pattern.
+    pub fn values(&self) -> Option<&'static [&'static str]> {
+        match self {
+            SemanticFormat::IsoCountry => Some(&ISO_COUNTRY_CODES),
+            SemanticFormat::IsoCurrency => Some(&ISO_CURRENCY_CODES),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for SemanticFormat {
+    type Err = ();
+
+    /// Parses the rule keyword with or without the leading `-`, e.g. `-ISO_COUNTRY`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_start_matches('-').to_uppercase().as_str() {
+            "EMAIL" => Ok(SemanticFormat::Email),
+            "UUID" => Ok(SemanticFormat::Uuid),
+            "URL" => Ok(SemanticFormat::Url),
+            "IPV4" => Ok(SemanticFormat::Ipv4),
+            "IBAN" => Ok(SemanticFormat::Iban),
+            "ISO_COUNTRY" => Ok(SemanticFormat::IsoCountry),
+            "ISO_CURRENCY" => Ok(SemanticFormat::IsoCurrency),
+            "PHONE_E164" => Ok(SemanticFormat::PhoneE164),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for SemanticFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            SemanticFormat::Email => "email",
+            SemanticFormat::Uuid => "uuid",
+            SemanticFormat::Url => "url",
+            SemanticFormat::Ipv4 => "ipv4",
+            SemanticFormat::Iban => "iban",
+            SemanticFormat::IsoCountry => "iso_country",
+            SemanticFormat::IsoCurrency => "iso_currency",
+            SemanticFormat::PhoneE164 => "phone_e164",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::semantic_format::SemanticFormat;
+    use regex::Regex;
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case(SemanticFormat::Email, "jane.doe+news@mail.example.com", true)]
+    #[case(SemanticFormat::Email, "jane@localhost", false)]
+    #[case(SemanticFormat::Email, "jane@@example.com", false)]
+    #[case(SemanticFormat::Uuid, "123e4567-e89b-12d3-a456-426614174000", true)]
+    #[case(SemanticFormat::Uuid, "123e4567e89b12d3a456426614174000", false)]
+    #[case(SemanticFormat::Url, "https://example.com/path?q=1", true)]
+    #[case(SemanticFormat::Url, "example.com", false)]
+    #[case(SemanticFormat::Ipv4, "192.168.0.255", true)]
+    #[case(SemanticFormat::Ipv4, "192.168.0.256", false)]
+    #[case(SemanticFormat::Ipv4, "10.0.0", false)]
+    #[case(SemanticFormat::Iban, "DE89370400440532013000", true)]
+    #[case(SemanticFormat::Iban, "de89370400440532013000", false)]
+    #[case(SemanticFormat::PhoneE164, "+4915112345678", true)]
+    #[case(SemanticFormat::PhoneE164, "015112345678", false)]
+    #[case(SemanticFormat::PhoneE164, "+0123", false)]
+    fn test_pattern(#[case] format: SemanticFormat, #[case] value: &str, #[case] expected: bool) {
+        let pattern = Regex::new(format.pattern().unwrap()).unwrap();
+        assert_eq!(pattern.is_match(value), expected);
+    }
+
+    #[rstest]
+    #[case(SemanticFormat::IsoCountry, "CH", true)]
+    #[case(SemanticFormat::IsoCountry, "UK", false)]
+    #[case(SemanticFormat::IsoCurrency, "EUR", true)]
+    #[case(SemanticFormat::IsoCurrency, "DEM", false)]
+    fn test_values(#[case] format: SemanticFormat, #[case] value: &str, #[case] expected: bool) {
+        let values = format.values().unwrap();
+        assert_eq!(values.contains(&value), expected);
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[rstest]
+    #[case("-email", SemanticFormat::Email)]
+    #[case("-ISO_CURRENCY", SemanticFormat::IsoCurrency)]
+    #[case("PHONE_E164", SemanticFormat::PhoneE164)]
+    fn test_from_str(#[case] keyword: &str, #[case] expected: SemanticFormat) {
+        assert_eq!(SemanticFormat::from_str(keyword), Ok(expected));
+    }
+}
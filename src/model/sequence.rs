@@ -0,0 +1,68 @@
+use serde::Serialize;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Order of the values of a column, e.g. `-STRICTLY_INCREASING ORDER BY ts`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Sequence {
+    Increasing,
+    StrictlyIncreasing,
+    NoGaps,
+}
+
+impl Sequence {
+    /// SQL condition that holds if a value violates the sequence given the previous value.
+    pub fn violation(&self, value: &str, previous: &str) -> String {
+        match self {
+            Sequence::Increasing => format!("{} < {}", value, previous),
+            Sequence::StrictlyIncreasing => format!("{} <= {}", value, previous),
+            Sequence::NoGaps => format!("{} > {} + 1", value, previous),
+        }
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ();
+
+    /// Parses the rule keyword with or without the leading `-`, e.g. `-NO_GAPS`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_start_matches('-').to_uppercase().as_str() {
+            "INCREASING" => Ok(Sequence::Increasing),
+            "STRICTLY_INCREASING" => Ok(Sequence::StrictlyIncreasing),
+            "NO_GAPS" => Ok(Sequence::NoGaps),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Sequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Sequence::Increasing => "increasing",
+            Sequence::StrictlyIncreasing => "strictly_increasing",
+            Sequence::NoGaps => "no_gaps",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::sequence::Sequence;
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case("-increasing", Sequence::Increasing)]
+    #[case("-STRICTLY_INCREASING", Sequence::StrictlyIncreasing)]
+    #[case("NO_GAPS", Sequence::NoGaps)]
+    fn test_from_str(#[case] keyword: &str, #[case] expected: Sequence) {
+        assert_eq!(Sequence::from_str(keyword), Ok(expected));
+        assert_eq!(
+            Sequence::from_str(&expected.to_string()),
+            Ok(expected),
+            "keyword of {:?} has to round trip",
+            expected
+        );
+    }
+}
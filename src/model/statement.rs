@@ -0,0 +1,191 @@
+use crate::model::domain::{split_items, DomainDef, RuleItem, RuleSetDef};
+use crate::model::pragma::Pragma;
+use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+use crate::model::table_rule::TableRule;
+use crate::model::udf::FunctionDef;
+use crate::model::view_expr::ViewDef;
+use crate::parser::error_utils::DDLxParseError;
+
+/// Top level statement of a DDLx input. Statements are applied in order to a catalog.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Statement {
+    CreateTable(Box<TableDef>),
+    AlterTable(AlterTable),
+    CreateView(Box<ViewDef>),
+    /// `IMPORT "<path>";` of another input file, the path is relative to the importing file
+    Import(String),
+    CreateDomain(DomainDef),
+    CreateRuleSet(RuleSetDef),
+    /// `CREATE FUNCTION <module>.<function>(<types>) RETURNS <type>;` signature of a Python function
+    CreateFunction(FunctionDef),
+    /// `#pragma <name> = <value>` that applies to the tables defined after it in the same file
+    Pragma(Pragma),
+}
+
+/// `ALTER TABLE` statement with the actions that are applied to an existing table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterTable {
+    pub table_ref: TableRef,
+    pub actions: Vec<AlterAction>,
+}
+
+impl AlterTable {
+    pub fn new(table_ref: TableRef, actions: Vec<AlterAction>) -> Self {
+        Self { table_ref, actions }
+    }
+
+    /// Applies all actions in order. The resulting table has to be validated again by the caller.
+    pub fn apply(&self, table: &mut TableDef) -> Result<(), DDLxParseError> {
+        for action in &self.actions {
+            action.apply(table)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AlterAction {
+    AddColumn(ColumnDef),
+    DropColumn(String),
+    AlterColumnType {
+        column: String,
+        data_type: DataType,
+    },
+    AddConstraint(TableRule),
+    AddRule {
+        column: String,
+        rules: Vec<RuleItem>,
+    },
+}
+
+impl AlterAction {
+    pub fn apply(&self, table: &mut TableDef) -> Result<(), DDLxParseError> {
+        match self {
+            AlterAction::AddColumn(column) => {
+                if find_column(table, &column.name).is_ok() {
+                    return Err(DDLxParseError::SchemaEvolutionError(format!(
+                        "Column {} already exists in table {}",
+                        column.name, table.table_ref
+                    )));
+                }
+                table.columns.push(column.clone());
+            }
+            AlterAction::DropColumn(column) => {
+                find_column(table, column)?;
+                table.columns.retain(|c| &c.name != column);
+            }
+            AlterAction::AlterColumnType { column, data_type } => {
+                find_column(table, column)?.set_data_type(data_type.clone());
+            }
+            AlterAction::AddConstraint(rule) => {
+                table.add_table_rule(rule.clone());
+            }
+            AlterAction::AddRule { column, rules } => {
+                let (rules, rulesets) = split_items(rules.clone());
+                let column = find_column(table, column)?;
+                column.rules.extend(rules);
+                column.rulesets.extend(rulesets);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn find_column<'a>(
+    table: &'a mut TableDef,
+    column: &str,
+) -> Result<&'a mut ColumnDef, DDLxParseError> {
+    let table_ref = table.table_ref.to_string();
+    table
+        .columns
+        .iter_mut()
+        .find(|c| c.name == column)
+        .ok_or_else(|| {
+            DDLxParseError::SchemaEvolutionError(format!(
+                "Column {} is not defined in table {}",
+                column, table_ref
+            ))
+        })
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::column_rule::{ColumnRule, IsType, NotEmpty};
+    use crate::model::domain::RuleItem;
+    use crate::model::rule_filter::filter::ColumnRuleFilter;
+    use crate::model::statement::AlterAction;
+    use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+    use crate::model::table_rule::{PrimaryKey, TableRule};
+    use rstest::rstest;
+
+    fn orders() -> TableDef {
+        TableDef {
+            table_ref: TableRef::new("Orders", None, None),
+            columns: vec![
+                ColumnDef::new(
+                    "Id".to_owned(),
+                    DataType::new("Int", Some(10), None),
+                    false,
+                    false,
+                ),
+                ColumnDef::new(
+                    "Name".to_owned(),
+                    DataType::new("VarChar", Some(20), None),
+                    false,
+                    false,
+                ),
+            ],
+            table_level_rules: vec![],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(AlterAction::AddColumn(ColumnDef::new("Price".to_owned(), DataType::new("Double", Some(10), Some(2)), false, false)), true)]
+    #[case(AlterAction::AddColumn(ColumnDef::new("Id".to_owned(), DataType::new("Int", Some(10), None), false, false)), false)]
+    #[case(AlterAction::DropColumn("Name".to_owned()), true)]
+    #[case(AlterAction::DropColumn("Price".to_owned()), false)]
+    #[case(AlterAction::AlterColumnType {column: "Id".to_owned(), data_type: DataType::new("BigInt", Some(20), None)}, true)]
+    #[case(AlterAction::AddRule {column: "Price".to_owned(), rules: vec![]}, false)]
+    #[case(AlterAction::AddConstraint(TableRule::PrimaryKey(PrimaryKey::new(None, vec!["Id".to_owned()], None))), true)]
+    fn test_apply_alter_action(#[case] action: AlterAction, #[case] is_ok: bool) {
+        assert_eq!(action.apply(&mut orders()).is_ok(), is_ok);
+    }
+
+    #[test]
+    fn test_alter_column_type_retargets_type_check() {
+        let mut table = orders();
+        AlterAction::AddRule {
+            column: "Name".to_owned(),
+            rules: vec![RuleItem::Rule(ColumnRuleFilter::new(
+                None,
+                vec![ColumnRule::NotEmpty(NotEmpty::new(None, None, None))],
+            ))],
+        }
+        .apply(&mut table)
+        .unwrap();
+        AlterAction::AlterColumnType {
+            column: "Name".to_owned(),
+            data_type: DataType::new("Text", Some(100), None),
+        }
+        .apply(&mut table)
+        .unwrap();
+
+        let column = &table.columns[1];
+        assert_eq!(column.data_type, DataType::new("Text", Some(100), None));
+        assert_eq!(
+            column.rules,
+            vec![
+                ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType::new(
+                    None,
+                    DataType::new("Text", Some(100), None),
+                    None
+                ))]),
+                ColumnRuleFilter::new(
+                    None,
+                    vec![ColumnRule::NotEmpty(NotEmpty::new(None, None, None))]
+                ),
+            ]
+        );
+    }
+}
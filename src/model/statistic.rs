@@ -0,0 +1,76 @@
+use serde::Serialize;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Column statistics that are asserted with a threshold, e.g. `-NULL_RATIO <= 0.05`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Statistic {
+    NullRatio,
+    Distinctness,
+    UniqueValueRatio,
+    Entropy,
+    ApproxDistinct,
+}
+
+impl Statistic {
+    /// Range of the values of the statistic, ratios are between 0 and 1 and entropy and the
+    /// number of distinct values are only bounded below.
+    pub fn bounds(&self) -> (f64, f64) {
+        match self {
+            Statistic::NullRatio | Statistic::Distinctness | Statistic::UniqueValueRatio => {
+                (0.0, 1.0)
+            }
+            Statistic::Entropy | Statistic::ApproxDistinct => (0.0, f64::INFINITY),
+        }
+    }
+}
+
+impl FromStr for Statistic {
+    type Err = ();
+
+    /// Parses the rule keyword with or without the leading `-`, e.g. `-NULL_RATIO`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_start_matches('-').to_uppercase().as_str() {
+            "NULL_RATIO" => Ok(Statistic::NullRatio),
+            "DISTINCTNESS" => Ok(Statistic::Distinctness),
+            "UNIQUE_VALUE_RATIO" => Ok(Statistic::UniqueValueRatio),
+            "ENTROPY" => Ok(Statistic::Entropy),
+            "APPROX_DISTINCT" => Ok(Statistic::ApproxDistinct),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Statistic::NullRatio => "null_ratio",
+            Statistic::Distinctness => "distinctness",
+            Statistic::UniqueValueRatio => "unique_value_ratio",
+            Statistic::Entropy => "entropy",
+            Statistic::ApproxDistinct => "approx_distinct",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::statistic::Statistic;
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case("-null_ratio", Statistic::NullRatio)]
+    #[case("-UNIQUE_VALUE_RATIO", Statistic::UniqueValueRatio)]
+    #[case("APPROX_DISTINCT", Statistic::ApproxDistinct)]
+    fn test_from_str(#[case] keyword: &str, #[case] expected: Statistic) {
+        assert_eq!(Statistic::from_str(keyword), Ok(expected));
+        assert_eq!(
+            Statistic::from_str(&expected.to_string()),
+            Ok(expected),
+            "keyword of {:?} has to round trip",
+            expected
+        );
+    }
+}
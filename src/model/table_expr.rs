@@ -1,5 +1,14 @@
-use crate::model::column_rule::{ColumnRule, IsType, NonNull, Uniqueness};
+use crate::model::column_rule::{
+    ColumnRule, GeneratedValue, IntegerRange, IsType, MaxLength, NonNull, NumericPrecision,
+    Positive, Uniqueness,
+};
 use crate::model::data_class::DataClass;
+use crate::model::domain::{split_items, RuleItem, RuleSetRef};
+use crate::model::lineage::ColumnLineage;
+use crate::model::pragma::{ImplicitKind, Pragma};
+use crate::model::rule_ext_config::Severity;
+use crate::model::table_rule::{PrimaryKey, TableRule};
+use crate::parser::error_utils::DDLxParseError;
 use lalrpop_util::lalrpop_mod;
 use serde::Serialize;
 use std::fmt::{Debug, Display};
@@ -7,13 +16,82 @@ use std::str::FromStr;
 
 use super::rule_filter::filter::ColumnRuleFilter;
 
-lalrpop_mod!(pub data_class, "/parser/data_class_parsing.rs");
+lalrpop_mod!(#[allow(clippy::all)] pub data_class, "/parser/data_class_parsing.rs");
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct TableDef {
     pub table_ref: TableRef,
     pub columns: Vec<ColumnDef>,
-    // table_level_rules: Vec<TableLevelRule>,
+    pub table_level_rules: Vec<TableRule>,
+    /// Severity of the rules that do not define their own severity.
+    pub severity: Severity,
+    /// Pragmas of the file and the table that control the implicit rules, in order.
+    pub pragmas: Vec<Pragma>,
+}
+
+/// Element of a table body, either a column or a table level constraint.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableElement {
+    Column(ColumnDef),
+    Rule(TableRule),
+    Pragma(Pragma),
+}
+
+impl TableDef {
+    pub fn from_elements(table_ref: TableRef, elements: Vec<TableElement>) -> Self {
+        let mut columns: Vec<ColumnDef> = vec![];
+        let mut table_level_rules: Vec<TableRule> = vec![];
+        let mut pragmas: Vec<Pragma> = vec![];
+
+        for element in elements {
+            match element {
+                TableElement::Column(mut column) => {
+                    // a column primary key is checked like a single column table primary key
+                    if column.primary_key {
+                        column.remove_implicit_uniqueness();
+                        table_level_rules.push(TableRule::PrimaryKey(PrimaryKey::new(
+                            None,
+                            vec![column.name.clone()],
+                            None,
+                        )));
+                    }
+                    columns.push(column)
+                }
+                TableElement::Rule(rule) => table_level_rules.push(rule),
+                TableElement::Pragma(pragma) => pragmas.push(pragma),
+            }
+        }
+
+        let mut table = Self {
+            table_ref,
+            columns,
+            table_level_rules: vec![],
+            severity: Severity::default(),
+            pragmas,
+        };
+        for rule in table_level_rules {
+            table.add_table_rule(rule);
+        }
+        table
+    }
+
+    pub fn with_severity(self, severity: Severity) -> Self {
+        Self { severity, ..self }
+    }
+
+    pub fn add_table_rule(&mut self, rule: TableRule) {
+        // columns of a primary key cannot be null
+        if let TableRule::PrimaryKey(primary_key) = &rule {
+            for column in self
+                .columns
+                .iter_mut()
+                .filter(|column| primary_key.columns.contains(&column.name))
+            {
+                column.set_not_null();
+            }
+        }
+        self.table_level_rules.push(rule);
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Default)]
@@ -66,6 +144,17 @@ pub struct ColumnDef {
     pub not_null: bool,
     pub primary_key: bool,
     pub rules: Vec<ColumnRuleFilter>,
+    /// SQL expression of the `DEFAULT` option.
+    pub default: Option<String>,
+    pub auto_increment: bool,
+    /// SQL expression of the `GENERATED ALWAYS AS (..)` option.
+    pub generated: Option<String>,
+    /// Source column of the `LINEAGE FROM` option.
+    pub lineage: Option<ColumnLineage>,
+    /// Domain used as type of the column, it is replaced by the domain type when expanded.
+    pub domain: Option<String>,
+    /// Rulesets used with `-USE`, they are replaced by their rules when expanded.
+    pub rulesets: Vec<RuleSetRef>,
 }
 
 impl ColumnDef {
@@ -77,14 +166,8 @@ impl ColumnDef {
         if primary_key {
             rules.push(ColumnRule::Uniqueness(Uniqueness::new(None, None)));
         }
-
-        if !data_type.class.is_date_like() {
-            rules.push(ColumnRule::IsType(IsType::new(
-                None,
-                data_type.clone(),
-                None,
-            )))
-        }
+        rules.extend(type_rules(&data_type));
+        let rules = rules.into_iter().map(ColumnRule::implicit).collect();
 
         Self {
             name,
@@ -92,6 +175,109 @@ impl ColumnDef {
             not_null,
             primary_key,
             rules: vec![ColumnRuleFilter::empty_fr_rules(rules)],
+            ..Default::default()
+        }
+    }
+
+    /// Creates a column with a data type or with a domain that is expanded later.
+    pub fn from_type(name: String, column_type: ColumnType, options: Vec<ColumnOption>) -> Self {
+        match column_type {
+            ColumnType::Data(data_type) => Self::from_options(name, data_type, options),
+            ColumnType::Domain(domain) => Self {
+                domain: Some(domain),
+                ..Self::from_options(name, DataType::default(), options)
+            },
+        }
+    }
+
+    pub fn from_options(name: String, data_type: DataType, options: Vec<ColumnOption>) -> Self {
+        let primary_key = options.contains(&ColumnOption::PrimaryKey);
+        let not_null = primary_key || options.contains(&ColumnOption::NotNull);
+        let mut column = Self::new(name, data_type, not_null, primary_key);
+
+        let unique = primary_key || options.contains(&ColumnOption::Unique);
+        if unique && !primary_key {
+            column.push_implicit_rule(ColumnRule::Uniqueness(Uniqueness::new(None, None)));
+        }
+
+        for option in options {
+            match option {
+                ColumnOption::Default(expression) => column.default = Some(expression),
+                ColumnOption::AutoIncrement => {
+                    column.auto_increment = true;
+                    // auto increment values are unique and start at 1
+                    if !unique {
+                        column.push_implicit_rule(ColumnRule::Uniqueness(Uniqueness::new(
+                            None, None,
+                        )));
+                    }
+                    column
+                        .push_implicit_rule(ColumnRule::Positive(Positive::new(None, None, None)));
+                }
+                ColumnOption::Generated(expression) => {
+                    column.push_implicit_rule(ColumnRule::GeneratedValue(GeneratedValue::new(
+                        None,
+                        expression.clone(),
+                        None,
+                    )));
+                    column.generated = Some(expression);
+                }
+                ColumnOption::Lineage(source) => column.lineage = Some(source),
+                _ => {}
+            }
+        }
+
+        column
+    }
+
+    /// Marks the column as not nullable and adds the implied completeness rule.
+    pub fn set_not_null(&mut self) {
+        if self.not_null {
+            return;
+        }
+        self.not_null = true;
+        self.push_implicit_rule(ColumnRule::NonNull(NonNull::new(None, None, None)));
+    }
+
+    /// Changes the type of the column and retargets the implied type and length checks to the
+    /// new type.
+    pub fn set_data_type(&mut self, data_type: DataType) {
+        for rule_filter in self.rules.iter_mut() {
+            rule_filter.rules.retain(|rule| {
+                !rule.is_implicit() || ImplicitKind::of(rule) != Some(ImplicitKind::TypeChecks)
+            });
+        }
+
+        for rule in type_rules(&data_type) {
+            self.push_implicit_rule(rule);
+        }
+        self.rules
+            .retain(|rule_filter| !rule_filter.rules.is_empty());
+        self.data_type = data_type;
+    }
+
+    /// Removes the implied uniqueness rule of a primary key column that is checked by the
+    /// primary key of the table instead.
+    fn remove_implicit_uniqueness(&mut self) {
+        for rule_filter in self.rules.iter_mut() {
+            rule_filter
+                .rules
+                .retain(|rule| !(rule.is_implicit() && matches!(rule, ColumnRule::Uniqueness(_))));
+        }
+        self.rules
+            .retain(|rule_filter| !rule_filter.rules.is_empty());
+    }
+
+    /// Adds a rule to the unfiltered rules that are generated from the column definition.
+    fn push_implicit_rule(&mut self, rule: ColumnRule) {
+        let rule = rule.implicit();
+        match self.rules.first_mut() {
+            Some(rule_filter) if rule_filter.filter_string.is_none() => {
+                rule_filter.rules.push(rule)
+            }
+            _ => self
+                .rules
+                .insert(0, ColumnRuleFilter::empty_fr_rules(vec![rule])),
         }
     }
 
@@ -107,16 +293,90 @@ impl ColumnDef {
     //     column
     // }
 
-    pub fn new_with_rules_and_filter(
-        name: String,
-        data_type: DataType,
-        not_null: bool,
-        primary_key: bool,
-        rules: Vec<ColumnRuleFilter>,
-    ) -> Self {
-        let mut column = Self::new(name, data_type, not_null, primary_key);
-        column.rules.extend(rules);
-        column
+    /// Adds explicit rules to a column that was created from a column definition with options.
+    pub fn with_rules(mut self, items: Vec<RuleItem>) -> Self {
+        let (rules, rulesets) = split_items(items);
+        self.rules.extend(rules);
+        self.rulesets.extend(rulesets);
+        self
+    }
+}
+
+/// Rules implied by a data type, the type check and the maximum length of sized string types.
+fn type_rules(data_type: &DataType) -> Vec<ColumnRule> {
+    let mut rules = vec![];
+    if !data_type.class.is_date_like() {
+        rules.push(ColumnRule::IsType(IsType::new(
+            None,
+            data_type.clone(),
+            None,
+        )));
+    }
+    if let Some(length) = data_type.max_length() {
+        rules.push(ColumnRule::MaxLength(MaxLength::new(None, length, None)));
+    }
+    if let Some((min, max)) = data_type.class.integer_range() {
+        rules.push(ColumnRule::IntegerRange(IntegerRange::new(
+            None, min, max, None,
+        )));
+    }
+    if let Some((precision, scale)) = data_type.precision_scale() {
+        rules.push(ColumnRule::NumericPrecision(NumericPrecision::new(
+            None, precision, scale, None,
+        )));
+    }
+    rules
+}
+
+/// Constraint keywords that can follow the data type in a column definition.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnOption {
+    NotNull,
+    Null,
+    PrimaryKey,
+    Unique,
+    Default(String),
+    AutoIncrement,
+    Generated(String),
+    Lineage(ColumnLineage),
+}
+
+impl ColumnOption {
+    /// Rejects options of a column definition that contradict each other.
+    pub fn validate(column: &str, options: &[ColumnOption]) -> Result<(), DDLxParseError> {
+        if options.contains(&ColumnOption::Null) {
+            if let Some(option) = options
+                .iter()
+                .find(|option| matches!(option, ColumnOption::NotNull | ColumnOption::PrimaryKey))
+            {
+                return Err(DDLxParseError::ColumnValidationError(format!(
+                    "Column {} cannot be NULL and {}",
+                    column,
+                    if *option == ColumnOption::NotNull {
+                        "NOT NULL"
+                    } else {
+                        "PRIMARY KEY"
+                    }
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Type of a column definition, names that are not a data type refer to a domain.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnType {
+    Data(DataType),
+    Domain(String),
+}
+
+impl ColumnType {
+    pub fn from_name(name: String) -> Self {
+        match DataType::from_str(&name) {
+            Ok(data_type) => ColumnType::Data(data_type),
+            Err(_) => ColumnType::Domain(name),
+        }
     }
 }
 
@@ -128,16 +388,63 @@ pub struct DataType {
 
 impl DataType {
     pub fn new(name: &str, size1: Option<u32>, size2: Option<u32>) -> Self {
-        if size1.is_some() && size2.is_some() {
-            return DataType::from_str(
-                format!("{} ({}, {})", name, size1.unwrap(), size2.unwrap()).as_str(),
-            )
-            .unwrap();
+        Self::try_new(name, size1, size2).unwrap()
+    }
+
+    /// Parses a declared data type, unknown types and invalid sizes are rejected.
+    pub fn try_new(
+        name: &str,
+        size1: Option<u32>,
+        size2: Option<u32>,
+    ) -> Result<Self, DDLxParseError> {
+        let declaration = match (size1, size2) {
+            (Some(size1), Some(size2)) => format!("{}({}, {})", name, size1, size2),
+            (Some(size1), None) => format!("{}({})", name, size1),
+            _ => name.to_owned(),
+        };
+        let data_type = DataType::from_str(&declaration).map_err(|_| {
+            DDLxParseError::ColumnValidationError(format!("Unknown data type {}", declaration))
+        })?;
+        if let Some((precision, scale)) = data_type.precision_scale() {
+            if scale > precision {
+                return Err(DDLxParseError::ColumnValidationError(format!(
+                    "Scale {} of data type {} is greater than its precision {}",
+                    scale, declaration, precision
+                )));
+            }
         }
-        if size1.is_some() {
-            return DataType::from_str(format!("{} ({})", name, size1.unwrap()).as_str()).unwrap();
+        Ok(data_type)
+    }
+
+    /// Declared size of fixed and variable length string and binary types like `VARCHAR(10)`.
+    pub fn max_length(&self) -> Option<u32> {
+        match (&self.class, self.size) {
+            (
+                DataClass::Char | DataClass::VarChar | DataClass::Binary | DataClass::VarBinary,
+                Some([Some(length), _]),
+            ) => Some(length),
+            _ => None,
+        }
+    }
+
+    /// Declared precision and scale of fractional types like `DECIMAL(10,2)`.
+    pub fn precision_scale(&self) -> Option<(u32, u32)> {
+        match self.size {
+            Some([Some(precision), Some(scale)]) if self.class.is_fraction_like() => {
+                Some((precision, scale))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for DataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.size {
+            Some([Some(size1), Some(size2)]) => write!(f, "{}({}, {})", self.class, size1, size2),
+            Some([Some(size), None]) => write!(f, "{}({})", self.class, size),
+            _ => write!(f, "{}", self.class),
         }
-        DataType::from_str(name).unwrap()
     }
 }
 
@@ -145,35 +452,42 @@ impl FromStr for DataType {
     type Err = ();
 
     fn from_str(name: &str) -> Result<Self, ()> {
-        Ok(data_class::DataTypeExprParser::new().parse(name).unwrap())
+        data_class::DataTypeExprParser::new()
+            .parse(name)
+            .map_err(|_| ())
     }
 }
 
 #[cfg(test)]
 pub mod test {
     use crate::model::{
-        column_rule::{IsType, NonNull, Uniqueness},
+        column_rule::{
+            IntegerRange, IsType, MaxLength, NonNull, NumericPrecision, Positive, Uniqueness,
+        },
         rule_filter::filter::ColumnRuleFilter,
-        table_expr::{ColumnDef, ColumnRule, DataType},
+        table_expr::{ColumnDef, ColumnOption, ColumnRule, DataType},
     };
     use rstest::rstest;
 
     #[rstest]
     #[case(ColumnDef {name: "Example".to_owned(), data_type: DataType::new("INT", Some(3), None), not_null: true, primary_key: true, rules: 
-    vec![ColumnRuleFilter::empty_fr_rules(vec![
+    vec![ColumnRuleFilter::implicit(vec![
         ColumnRule::NonNull(NonNull::new(None, None, None)),
         ColumnRule::Uniqueness(Uniqueness::new(None, None)),
         ColumnRule::IsType(IsType {name: "".to_owned(), data_type: DataType::new("Int", Some(3), None), ..Default::default()}),
-    ])]}, "Example".to_owned(), true, true)]
+        ColumnRule::IntegerRange(IntegerRange::new(None, i32::MIN.into(), i32::MAX.into(), None)),
+    ])], ..Default::default()}, "Example".to_owned(), true, true)]
     #[case(ColumnDef {name: "Example".to_owned(), data_type: DataType::new("INT", Some(3), None), not_null: true, primary_key: false, rules: 
-    vec![ColumnRuleFilter::empty_fr_rules(vec![
+    vec![ColumnRuleFilter::implicit(vec![
         ColumnRule::NonNull(NonNull::new(None, None, None)),
         ColumnRule::IsType(IsType {name: "".to_owned(), data_type: DataType::new("Int", Some(3), None), ..Default::default()}),
-    ])]}, "Example".to_owned(), true, false)]
+        ColumnRule::IntegerRange(IntegerRange::new(None, i32::MIN.into(), i32::MAX.into(), None)),
+    ])], ..Default::default()}, "Example".to_owned(), true, false)]
     #[case(ColumnDef {name: "Example".to_owned(), data_type: DataType::new("INT", Some(3), None), not_null: false, primary_key: false, rules: 
-    vec![ColumnRuleFilter::empty_fr_rules(vec![
+    vec![ColumnRuleFilter::implicit(vec![
         ColumnRule::IsType(IsType {name: "".to_owned(), data_type: DataType::new("Int", Some(3), None), ..Default::default()}),
-    ])]}, "Example".to_owned(), false, false)]
+        ColumnRule::IntegerRange(IntegerRange::new(None, i32::MIN.into(), i32::MAX.into(), None)),
+    ])], ..Default::default()}, "Example".to_owned(), false, false)]
     fn test_col_def_init(
         #[case] desired_col_def: ColumnDef,
         #[case] name: String,
@@ -188,4 +502,80 @@ pub mod test {
         );
         assert_eq!(desired_col_def, col_def);
     }
+
+    #[test]
+    fn test_col_def_from_options() {
+        let col_def = ColumnDef::from_options(
+            "Id".to_owned(),
+            DataType::new("BigInt", Some(20), None),
+            vec![
+                ColumnOption::AutoIncrement,
+                ColumnOption::Default("1".to_owned()),
+            ],
+        );
+
+        assert!(col_def.auto_increment);
+        assert_eq!(col_def.default, Some("1".to_owned()));
+        assert_eq!(
+            col_def.rules,
+            vec![ColumnRuleFilter::implicit(vec![
+                ColumnRule::IsType(IsType::new(
+                    None,
+                    DataType::new("BigInt", Some(20), None),
+                    None
+                )),
+                ColumnRule::IntegerRange(IntegerRange::new(None, i64::MIN, i64::MAX, None)),
+                ColumnRule::Uniqueness(Uniqueness::new(None, None)),
+                ColumnRule::Positive(Positive::new(None, None, None)),
+            ])]
+        );
+    }
+
+    #[rstest]
+    #[case(DataType::new("VARCHAR", Some(40), None), Some(40))]
+    #[case(DataType::new("CHAR", Some(2), None), Some(2))]
+    #[case(DataType::new("INT", Some(3), None), None)]
+    fn test_set_data_type_max_length(#[case] data_type: DataType, #[case] max_length: Option<u32>) {
+        let mut col_def = ColumnDef::new(
+            "Name".to_owned(),
+            DataType::new("VARCHAR", Some(20), None),
+            false,
+            false,
+        );
+        col_def.set_data_type(data_type);
+
+        let max_lengths = col_def.rules[0]
+            .rules
+            .iter()
+            .filter_map(|rule| match rule {
+                ColumnRule::MaxLength(MaxLength { length, .. }) => Some(*length),
+                _ => None,
+            })
+            .collect::<Vec<u32>>();
+        assert_eq!(max_lengths, max_length.into_iter().collect::<Vec<u32>>());
+    }
+
+    #[rstest]
+    #[case(DataType::new("DECIMAL", Some(10), Some(2)), vec![
+        ColumnRule::NumericPrecision(NumericPrecision::new(None, 10, 2, None)),
+    ])]
+    #[case(DataType::new("FLOAT", Some(8), Some(3)), vec![
+        ColumnRule::NumericPrecision(NumericPrecision::new(None, 8, 3, None)),
+    ])]
+    #[case(DataType::new("FLOAT", Some(10), None), vec![])]
+    #[case(DataType::new("TINYINT", Some(3), None), vec![
+        ColumnRule::IntegerRange(IntegerRange::new(None, -128, 127, None)),
+    ])]
+    #[case(DataType::new("MEDIUMINT", Some(8), None), vec![
+        ColumnRule::IntegerRange(IntegerRange::new(None, -8388608, 8388607, None)),
+    ])]
+    fn test_col_def_numeric_rules(#[case] data_type: DataType, #[case] expected: Vec<ColumnRule>) {
+        let col_def = ColumnDef::new("Amount".to_owned(), data_type, false, false);
+
+        let expected = expected
+            .into_iter()
+            .map(ColumnRule::implicit)
+            .collect::<Vec<ColumnRule>>();
+        assert_eq!(col_def.rules[0].rules[1..], expected);
+    }
 }
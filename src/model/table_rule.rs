@@ -0,0 +1,238 @@
+use crate::model::anomaly::Anomaly;
+use crate::model::custom_sql::CustomSql;
+use crate::model::rule_ext_config::{RuleExtConfig, Severity};
+use crate::model::rule_traits::{ColumnValidationError, ValidTableRule};
+use crate::model::table_expr::TableDef;
+use crate::model::threshold::Threshold;
+use crate::model::volume::Volume;
+use serde::Serialize;
+
+/// Checks that all referenced columns are defined in the table.
+pub(crate) fn validate_columns_exist(
+    columns: &[String],
+    table: &TableDef,
+) -> Result<String, ColumnValidationError> {
+    for column in columns {
+        if !table.columns.iter().any(|c| &c.name == column) {
+            return Err(ColumnValidationError::UnknownColumn(format!(
+                "Column {} is not defined in table {}",
+                column, table.table_ref
+            )));
+        }
+    }
+    Ok(String::from("valid"))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PrimaryKey {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl PrimaryKey {
+    pub fn new(
+        name: Option<String>,
+        columns: Vec<String>,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            columns,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+}
+
+impl ValidTableRule for PrimaryKey {
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError> {
+        validate_columns_exist(&self.columns, table)
+    }
+}
+
+/// Uniqueness of the combination of multiple columns, e.g. `UNIQUE (order_id, line_no)`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct UniqueKey {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl UniqueKey {
+    pub fn new(
+        name: Option<String>,
+        columns: Vec<String>,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            columns,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+}
+
+impl ValidTableRule for UniqueKey {
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError> {
+        validate_columns_exist(&self.columns, table)
+    }
+}
+
+/// Pearson correlation of two numeric columns, e.g. `-CORRELATION(price, quantity) >= 0.7`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Correlation {
+    pub name: String,
+    pub first_column: String,
+    pub second_column: String,
+    pub rule_ext_config: RuleExtConfig,
+    pub threshold: Threshold,
+}
+
+impl Correlation {
+    pub fn new(
+        name: Option<String>,
+        first_column: String,
+        second_column: String,
+        rule_ext_config: Option<RuleExtConfig>,
+        threshold: Threshold,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            first_column,
+            second_column,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold,
+        }
+    }
+}
+
+impl ValidTableRule for Correlation {
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError> {
+        let columns = [self.first_column.clone(), self.second_column.clone()];
+        validate_columns_exist(&columns, table)?;
+        for column in table.columns.iter().filter(|c| columns.contains(&c.name)) {
+            if !column.data_type.class.is_numeric_like() {
+                return Err(ColumnValidationError::InvalidType(format!(
+                    "Type of column {} is not numeric-like for correlation rule",
+                    column.name
+                )));
+            }
+        }
+        if let Err(message) = self.threshold.check_bounds(-1.0, 1.0) {
+            return Err(ColumnValidationError::InvalidThreshold(format!(
+                "Threshold {} of correlation rule for columns {} and {}: {}",
+                self.threshold, self.first_column, self.second_column, message
+            )));
+        }
+        Ok(String::from("valid"))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum TableRule {
+    PrimaryKey(PrimaryKey),
+    UniqueKey(UniqueKey),
+    CustomSql(CustomSql),
+    Volume(Volume),
+    Anomaly(Anomaly),
+    Correlation(Correlation),
+}
+
+impl TableRule {
+    pub fn rule_ext_config(&self) -> &RuleExtConfig {
+        match self {
+            TableRule::PrimaryKey(rule) => &rule.rule_ext_config,
+            TableRule::UniqueKey(rule) => &rule.rule_ext_config,
+            TableRule::CustomSql(rule) => &rule.rule_ext_config,
+            TableRule::Volume(rule) => &rule.rule_ext_config,
+            TableRule::Anomaly(rule) => &rule.rule_ext_config,
+            TableRule::Correlation(rule) => &rule.rule_ext_config,
+        }
+    }
+
+    fn rule_ext_config_mut(&mut self) -> &mut RuleExtConfig {
+        match self {
+            TableRule::PrimaryKey(rule) => &mut rule.rule_ext_config,
+            TableRule::UniqueKey(rule) => &mut rule.rule_ext_config,
+            TableRule::CustomSql(rule) => &mut rule.rule_ext_config,
+            TableRule::Volume(rule) => &mut rule.rule_ext_config,
+            TableRule::Anomaly(rule) => &mut rule.rule_ext_config,
+            TableRule::Correlation(rule) => &mut rule.rule_ext_config,
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        let rule_ext_config = self.rule_ext_config_mut();
+        *rule_ext_config = rule_ext_config.clone().with_severity(severity);
+        self
+    }
+
+    /// Severity of the rule or the default of the table if the rule has none.
+    pub fn severity(&self, default: Severity) -> Severity {
+        self.rule_ext_config().severity().unwrap_or(default)
+    }
+}
+
+impl ValidTableRule for TableRule {
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError> {
+        match self {
+            TableRule::PrimaryKey(rule) => rule.validate_table(table),
+            TableRule::UniqueKey(rule) => rule.validate_table(table),
+            TableRule::CustomSql(rule) => rule.validate_table(table),
+            TableRule::Volume(rule) => rule.validate_table(table),
+            TableRule::Anomaly(rule) => rule.validate_table(table),
+            TableRule::Correlation(rule) => rule.validate_table(table),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::rule_traits::ValidTableRule;
+    use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+    use crate::model::table_rule::{Correlation, PrimaryKey, TableRule, UniqueKey};
+    use crate::model::threshold::Threshold;
+    use rstest::rstest;
+
+    fn order_lines() -> TableDef {
+        TableDef {
+            table_ref: TableRef::new("OrderLines", None, None),
+            columns: vec![
+                ColumnDef::new(
+                    "OrderId".to_owned(),
+                    DataType::new("Int", Some(10), None),
+                    false,
+                    false,
+                ),
+                ColumnDef::new(
+                    "LineNo".to_owned(),
+                    DataType::new("Int", Some(3), None),
+                    false,
+                    false,
+                ),
+                ColumnDef::new(
+                    "Product".to_owned(),
+                    DataType::new("VarChar", Some(20), None),
+                    false,
+                    false,
+                ),
+            ],
+            table_level_rules: vec![],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(TableRule::PrimaryKey(PrimaryKey::new(None, vec!["OrderId".to_owned(), "LineNo".to_owned()], None)), true)]
+    #[case(TableRule::UniqueKey(UniqueKey::new(None, vec!["LineNo".to_owned()], None)), true)]
+    #[case(TableRule::PrimaryKey(PrimaryKey::new(None, vec!["OrderId".to_owned(), "Line".to_owned()], None)), false)]
+    #[case(TableRule::UniqueKey(UniqueKey::new(None, vec!["Id".to_owned()], None)), false)]
+    #[case(TableRule::Correlation(Correlation::new(None, "OrderId".to_owned(), "LineNo".to_owned(), None, 0.7.into())), true)]
+    #[case(TableRule::Correlation(Correlation::new(None, "OrderId".to_owned(), "LineNo".to_owned(), None, Threshold::Between(-1.0, -0.5))), true)]
+    #[case(TableRule::Correlation(Correlation::new(None, "OrderId".to_owned(), "Product".to_owned(), None, 0.7.into())), false)]
+    #[case(TableRule::Correlation(Correlation::new(None, "OrderId".to_owned(), "Quantity".to_owned(), None, 0.7.into())), false)]
+    #[case(TableRule::Correlation(Correlation::new(None, "OrderId".to_owned(), "LineNo".to_owned(), None, 1.5.into())), false)]
+    pub fn test_validate_table_rule(#[case] rule: TableRule, #[case] is_valid: bool) {
+        assert_eq!(rule.validate_table(&order_lines()).is_ok(), is_valid);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ThresholdOperator {
+    Equal,
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+}
+
+impl ThresholdOperator {
+    /// Operator that compares the complement of both sides, e.g. `<=` for `>=`.
+    pub fn flip(&self) -> Self {
+        match self {
+            ThresholdOperator::Equal => ThresholdOperator::Equal,
+            ThresholdOperator::Greater => ThresholdOperator::Less,
+            ThresholdOperator::GreaterEqual => ThresholdOperator::LessEqual,
+            ThresholdOperator::Less => ThresholdOperator::Greater,
+            ThresholdOperator::LessEqual => ThresholdOperator::GreaterEqual,
+        }
+    }
+}
+
+impl Display for ThresholdOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            ThresholdOperator::Equal => "=",
+            ThresholdOperator::Greater => ">",
+            ThresholdOperator::GreaterEqual => ">=",
+            ThresholdOperator::Less => "<",
+            ThresholdOperator::LessEqual => "<=",
+        };
+        write!(f, "{}", operator)
+    }
+}
+
+/// Assertion on the fraction of rows that satisfy a rule, e.g. `>= 95%` or
+/// `between 0.9 and 0.99`. A bare value like `0.9` means `>= 0.9`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum Threshold {
+    Compare(ThresholdOperator, f64),
+    Between(f64, f64),
+}
+
+impl Default for Threshold {
+    fn default() -> Self {
+        Threshold::Compare(ThresholdOperator::GreaterEqual, 1.0)
+    }
+}
+
+impl From<f64> for Threshold {
+    fn from(value: f64) -> Self {
+        Threshold::Compare(ThresholdOperator::GreaterEqual, value)
+    }
+}
+
+impl Display for Threshold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Threshold::Compare(operator, value) => write!(f, "{} {}", operator, value),
+            Threshold::Between(min, max) => write!(f, "between {} and {}", min, max),
+        }
+    }
+}
+
+impl Threshold {
+    /// Assertion on `1 - x` for an assertion on the fraction `x`, e.g. a null ratio of `<= 0.05`
+    /// is a completeness of `>= 0.95`.
+    pub fn complement(&self) -> Self {
+        let complement = |value: f64| round_value(1.0 - value);
+        match self {
+            Threshold::Compare(operator, value) => {
+                Threshold::Compare(operator.flip(), complement(*value))
+            }
+            Threshold::Between(min, max) => Threshold::Between(complement(*max), complement(*min)),
+        }
+    }
+
+    /// The rule has to hold for every row, which is the default of all rules.
+    pub fn is_every_row(&self) -> bool {
+        *self == Threshold::default()
+    }
+
+    /// Checks that all values of the assertion are within the bounds and that ranges are not
+    /// empty. An infinite upper bound only checks the lower bound.
+    pub fn check_bounds(&self, min: f64, max: f64) -> Result<(), String> {
+        let values = match self {
+            Threshold::Compare(_, value) => vec![*value],
+            Threshold::Between(lower, upper) => {
+                if lower > upper {
+                    return Err(format!("{} is greater than {}", lower, upper));
+                }
+                vec![*lower, *upper]
+            }
+        };
+        match values.iter().find(|value| !(min..=max).contains(*value)) {
+            Some(value) if max == f64::INFINITY => {
+                Err(format!("{} has to be at least {}", value, min))
+            }
+            Some(value) => Err(format!("{} is not between {} and {}", value, min, max)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses the digits of a number token, e.g. `0.95`, `1.` or `-1`.
+pub fn parse_number(number: &str) -> Result<f64, DDLxParseError> {
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(DDLxParseError::SyntaxError(format!(
+            "Number {} cannot be represented",
+            number
+        ))),
+    }
+}
+
+/// Removes the floating point error of arithmetic on values, so that e.g. `1 - 0.7` is
+/// compared and printed as `0.3`.
+pub fn round_value(value: f64) -> f64 {
+    (value * 1e9).round() / 1e9
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::threshold::{parse_number, round_value, Threshold, ThresholdOperator};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("0.95", Some(0.95))]
+    #[case("1.", Some(1.0))]
+    #[case("-1", Some(-1.0))]
+    #[case("16777217", Some(16777217.0))]
+    #[case(&"9".repeat(400), None)]
+    fn test_parse_number(#[case] number: &str, #[case] expected: Option<f64>) {
+        assert_eq!(parse_number(number).ok(), expected);
+    }
+
+    #[rstest]
+    #[case(1.0 - 0.7, 0.3)]
+    #[case(0.1 + 0.2, 0.3)]
+    #[case(1.0 + 0.2, 1.2)]
+    fn test_round_value(#[case] value: f64, #[case] expected: f64) {
+        assert_eq!(round_value(value), expected);
+    }
+
+    #[rstest]
+    #[case(Threshold::from(0.9), true)]
+    #[case(Threshold::Compare(ThresholdOperator::Less, 0.01), true)]
+    #[case(Threshold::Compare(ThresholdOperator::Less, 1.5), false)]
+    #[case(Threshold::Between(0.9, 0.99), true)]
+    #[case(Threshold::Between(0.99, 0.9), false)]
+    fn test_check_bounds(#[case] threshold: Threshold, #[case] expected: bool) {
+        assert_eq!(threshold.check_bounds(0.0, 1.0).is_ok(), expected);
+    }
+
+    #[rstest]
+    #[case(Threshold::Compare(ThresholdOperator::Greater, 1000.0), Ok(()))]
+    #[case(Threshold::Compare(ThresholdOperator::Greater, -1.0), Err("-1 has to be at least 0".to_owned()))]
+    fn test_check_lower_bound(#[case] threshold: Threshold, #[case] expected: Result<(), String>) {
+        assert_eq!(threshold.check_bounds(0.0, f64::INFINITY), expected);
+    }
+
+    #[rstest]
+    #[case(Threshold::Compare(ThresholdOperator::LessEqual, 0.05), ">= 0.95")]
+    #[case(Threshold::Compare(ThresholdOperator::Equal, 0.0), "= 1")]
+    #[case(Threshold::Between(0.1, 0.3), "between 0.7 and 0.9")]
+    fn test_complement(#[case] threshold: Threshold, #[case] expected: &str) {
+        assert_eq!(threshold.complement().to_string(), expected);
+    }
+}
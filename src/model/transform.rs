@@ -0,0 +1,326 @@
+use crate::model::catalog::Catalog;
+use crate::model::data_class::DataClass;
+use crate::model::select_expr::Select;
+use crate::model::table_expr::{ColumnDef, DataType};
+use crate::model::view_expr::{resolve_select, ViewColumn};
+use crate::parser::error_utils::DDLxParseError;
+use std::fmt::Display;
+
+/// `INSERT INTO <target> [(columns)] SELECT ..` transformation that loads a table of the catalog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Insert {
+    pub target: String,
+    /// explicit target columns, all columns of the target table in order if not defined
+    pub columns: Option<Vec<String>>,
+    pub select: Select,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransformIssueKind {
+    ColumnCount,
+    ColumnName,
+    TypeConflict,
+    TypeNarrowing,
+    Nullability,
+}
+
+/// Mismatch between the output of a transformation and the schema of its target table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransformIssue {
+    pub kind: TransformIssueKind,
+    pub target: String,
+    pub column: Option<String>,
+    pub message: String,
+}
+
+impl Display for TransformIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.column {
+            Some(column) => write!(
+                f,
+                "{:?} in insert into {}, column {}: {}",
+                self.kind, self.target, column, self.message
+            ),
+            None => write!(
+                f,
+                "{:?} in insert into {}: {}",
+                self.kind, self.target, self.message
+            ),
+        }
+    }
+}
+
+impl Insert {
+    pub fn new(target: String, columns: Option<Vec<String>>, select: Select) -> Self {
+        Self {
+            target,
+            columns,
+            select,
+        }
+    }
+
+    /// Resolves the select statement against the catalog and compares its output columns with the
+    /// target table. Unknown tables or columns in the statement are returned as error.
+    pub fn check(&self, catalog: &Catalog) -> Result<Vec<TransformIssue>, DDLxParseError> {
+        let table = catalog
+            .find_table(&self.target)
+            .ok_or_else(|| self.error(format!("Target table {} is not defined", self.target)))?;
+        let output =
+            resolve_select(&self.select, catalog).map_err(|message| self.error(message))?;
+
+        let mut issues: Vec<TransformIssue> = vec![];
+        let targets: Vec<&ColumnDef> = match &self.columns {
+            Some(columns) => {
+                let mut targets = vec![];
+                for name in columns {
+                    match find_column(&table.columns, name) {
+                        Some(column) => targets.push(column),
+                        None => {
+                            return Err(self.error(format!(
+                                "Column {} is not defined in table {}",
+                                name, self.target
+                            )))
+                        }
+                    }
+                }
+                for column in &table.columns {
+                    let is_filled = column.default.is_some()
+                        || column.auto_increment
+                        || column.generated.is_some();
+                    if column.not_null
+                        && !is_filled
+                        && !targets.iter().any(|target| target.name == column.name)
+                    {
+                        issues.push(self.issue(
+                            TransformIssueKind::Nullability,
+                            Some(&column.name),
+                            "NOT NULL column without default is not filled".to_owned(),
+                        ));
+                    }
+                }
+                targets
+            }
+            None => table.columns.iter().collect(),
+        };
+
+        if output.len() != targets.len() {
+            issues.push(self.issue(
+                TransformIssueKind::ColumnCount,
+                None,
+                format!(
+                    "select returns {} columns, target expects {}",
+                    output.len(),
+                    targets.len()
+                ),
+            ));
+        }
+
+        for (source, target) in output.iter().zip(targets) {
+            issues.extend(self.check_column(source, target));
+        }
+        Ok(issues)
+    }
+
+    fn check_column(&self, source: &ViewColumn, target: &ColumnDef) -> Vec<TransformIssue> {
+        let mut issues: Vec<TransformIssue> = vec![];
+        let column = Some(&target.name);
+
+        // without explicit column list the columns are mapped by position
+        if self.columns.is_none() && !source.name.eq_ignore_ascii_case(&target.name) {
+            issues.push(self.issue(
+                TransformIssueKind::ColumnName,
+                column,
+                format!(
+                    "select column {} is inserted into {}",
+                    source.name, target.name
+                ),
+            ));
+        }
+
+        if !source
+            .data_type
+            .class
+            .is_assignable_to(&target.data_type.class)
+        {
+            issues.push(self.issue(
+                TransformIssueKind::TypeConflict,
+                column,
+                format!(
+                    "{} cannot be stored as {}",
+                    source.data_type.class, target.data_type.class
+                ),
+            ));
+        } else if is_narrowing(&source.data_type, &target.data_type) {
+            issues.push(self.issue(
+                TransformIssueKind::TypeNarrowing,
+                column,
+                format!("{} is narrowed to {}", source.data_type, target.data_type),
+            ));
+        }
+
+        if source.nullable && target.not_null {
+            issues.push(self.issue(
+                TransformIssueKind::Nullability,
+                column,
+                format!(
+                    "nullable column {} is inserted into NOT NULL column",
+                    source.name
+                ),
+            ));
+        }
+        issues
+    }
+
+    fn issue(
+        &self,
+        kind: TransformIssueKind,
+        column: Option<&String>,
+        message: String,
+    ) -> TransformIssue {
+        TransformIssue {
+            kind,
+            target: self.target.clone(),
+            column: column.cloned(),
+            message,
+        }
+    }
+
+    fn error(&self, message: String) -> DDLxParseError {
+        DDLxParseError::TransformValidationError(format!(
+            "Insert into {}: {}",
+            self.target, message
+        ))
+    }
+}
+
+fn find_column<'a>(columns: &'a [ColumnDef], name: &str) -> Option<&'a ColumnDef> {
+    columns
+        .iter()
+        .find(|column| column.name.eq_ignore_ascii_case(name))
+}
+
+/// Checks if values of the source type might not fit into the target type, both types have to be
+/// assignable.
+fn is_narrowing(source: &DataType, target: &DataType) -> bool {
+    let (source_class, target_class) = (&source.class, &target.class);
+    if *source_class == DataClass::Unknown || *target_class == DataClass::Unknown {
+        return false;
+    }
+
+    if source_class.is_string_like() && target_class.is_string_like() {
+        return match (max_length(source), max_length(target)) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(source_length), Some(target_length)) => source_length > target_length,
+        };
+    }
+
+    if let (Some(source_rank), Some(target_rank)) =
+        (integer_rank(source_class), integer_rank(target_class))
+    {
+        return source_rank > target_rank;
+    }
+
+    if let (Some(source_rank), Some(target_rank)) =
+        (fraction_rank(source_class), fraction_rank(target_class))
+    {
+        if source_rank > target_rank {
+            return true;
+        }
+        // precision and scale of sized types like DECIMAL(10, 2)
+        if let (Some([Some(source_precision), source_scale]), Some([Some(precision), scale])) =
+            (source.size, target.size)
+        {
+            return source_precision > precision
+                || source_scale.unwrap_or_default() > scale.unwrap_or_default();
+        }
+    }
+    false
+}
+
+fn integer_rank(class: &DataClass) -> Option<u8> {
+    match class {
+        DataClass::TinyInt => Some(1),
+        DataClass::SmallInt => Some(2),
+        DataClass::MediumInt => Some(3),
+        DataClass::Int | DataClass::Integer => Some(4),
+        DataClass::BigInt => Some(5),
+        _ => None,
+    }
+}
+
+fn fraction_rank(class: &DataClass) -> Option<u8> {
+    match class {
+        DataClass::Float => Some(1),
+        DataClass::Double | DataClass::DoublePrecision | DataClass::Decimal | DataClass::Dec => {
+            Some(2)
+        }
+        _ => None,
+    }
+}
+
+/// Maximum number of characters of a string type, `None` if unbounded.
+fn max_length(data_type: &DataType) -> Option<u32> {
+    if let Some([Some(size), _]) = data_type.size {
+        return Some(size);
+    }
+    match data_type.class {
+        DataClass::TinyText | DataClass::TinyBlob => Some(255),
+        DataClass::Text | DataClass::Blob => Some(65_535),
+        DataClass::MediumText | DataClass::MediumBlob => Some(16_777_215),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::transform::TransformIssueKind;
+    use crate::parser::{parse_catalog, parse_transform};
+    use rstest::rstest;
+
+    const CATALOG: &str = "CREATE TABLE shop.orders {id BIGINT(20) NOT NULL, customer_id INT(10), \
+        note VARCHAR(255), price DOUBLE(10,2)};
+        CREATE TABLE shop.customers {id INT(10) NOT NULL, name VARCHAR(100)};
+        CREATE TABLE report {order_id INT(3) NOT NULL, customer VARCHAR(10), \
+        total DOUBLE(10,2), created BIGINT(20) NOT NULL DEFAULT 0};";
+
+    fn issues(transform: &str) -> Vec<TransformIssueKind> {
+        let catalog = parse_catalog(CATALOG).unwrap();
+        parse_transform(transform)
+            .unwrap()
+            .iter()
+            .flat_map(|insert| insert.check(&catalog).unwrap())
+            .map(|issue| issue.kind)
+            .collect()
+    }
+
+    #[rstest]
+    #[case("INSERT INTO report (order_id, total) SELECT CAST(id AS INT(3)), price FROM shop.orders;", vec![])]
+    #[case("INSERT INTO report SELECT o.id AS order_id, c.name AS customer, o.price AS total, 0 AS created \
+        FROM shop.orders o JOIN shop.customers c ON o.customer_id = c.id;",
+        vec![TransformIssueKind::TypeNarrowing, TransformIssueKind::TypeNarrowing])]
+    #[case("INSERT INTO report (order_id, customer) SELECT c.id, c.name FROM shop.orders o \
+        LEFT JOIN shop.customers c ON o.customer_id = c.id;",
+        vec![TransformIssueKind::Nullability, TransformIssueKind::TypeNarrowing])]
+    #[case("INSERT INTO report (order_id, customer) SELECT c.id, c.name FROM shop.orders o \
+        LEFT JOIN shop.customers c ON o.customer_id = c.id WHERE c.id IS NOT NULL AND c.name = 'a';",
+        vec![TransformIssueKind::TypeNarrowing])]
+    #[case("INSERT INTO report (customer) SELECT 'a' FROM shop.orders;", vec![TransformIssueKind::Nullability])]
+    #[case("INSERT INTO report SELECT customer_id FROM shop.orders;", 
+        vec![TransformIssueKind::ColumnCount, TransformIssueKind::ColumnName, TransformIssueKind::Nullability])]
+    #[case("INSERT OVERWRITE TABLE report (order_id, total) SELECT customer_id, note FROM shop.orders;",
+        vec![TransformIssueKind::Nullability, TransformIssueKind::TypeConflict])]
+    fn test_check_insert(#[case] transform: &str, #[case] expected: Vec<TransformIssueKind>) {
+        assert_eq!(issues(transform), expected);
+    }
+
+    #[rstest]
+    #[case("INSERT INTO missing SELECT id FROM shop.orders;")]
+    #[case("INSERT INTO report (unknown) SELECT id FROM shop.orders;")]
+    #[case("INSERT INTO report SELECT unknown FROM shop.orders;")]
+    fn test_check_insert_error(#[case] transform: &str) {
+        let catalog = parse_catalog(CATALOG).unwrap();
+        let inserts = parse_transform(transform).unwrap();
+        assert!(inserts[0].check(&catalog).is_err());
+    }
+}
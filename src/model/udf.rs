@@ -0,0 +1,275 @@
+use crate::model::catalog::Catalog;
+use crate::model::column_rule::ColumnRule;
+use crate::model::data_class::DataClass;
+use crate::model::rule_ext_config::RuleExtConfig;
+use crate::model::rule_filter::filter::ColumnRuleFilter;
+use crate::model::rule_traits::{ColumnValidationError, ValidColumnRule};
+use crate::model::table_expr::{data_class, ColumnDef};
+use crate::model::threshold::Threshold;
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+use std::fmt::Display;
+use valid_column_rule_derive::ValidColumnRule;
+
+/// `CREATE FUNCTION <module>.<function>(<types>) RETURNS <type>;` signature of a Python function
+/// that can be used as rule. Sizes of the types are not declared, e.g. `VARCHAR`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FunctionDef {
+    pub module: String,
+    pub function: String,
+    pub parameters: Vec<DataClass>,
+    pub returns: DataClass,
+}
+
+impl FunctionDef {
+    /// Unknown type names are kept as `DataClass::Unknown` and rejected by `validate`.
+    pub fn new(module: String, function: String, parameters: Vec<String>, returns: String) -> Self {
+        Self {
+            module,
+            function,
+            parameters: parameters.iter().map(|name| parse_class(name)).collect(),
+            returns: parse_class(&returns),
+        }
+    }
+
+    /// Name of the function in DDLx, e.g. `checks.luhn`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}", self.module, self.function)
+    }
+
+    /// Name the function is registered with in Spark SQL, e.g. `checks_luhn`.
+    pub fn sql_name(&self) -> String {
+        format!("{}_{}", self.module, self.function)
+    }
+
+    /// Rules are applied to a column, so the function takes it as first parameter.
+    pub fn validate(&self) -> Result<(), DDLxParseError> {
+        if self.parameters.is_empty() {
+            return Err(DDLxParseError::ColumnValidationError(format!(
+                "Function {} needs the column value as first parameter",
+                self.qualified_name()
+            )));
+        }
+        if self.returns == DataClass::Unknown || self.parameters.contains(&DataClass::Unknown) {
+            return Err(DDLxParseError::ColumnValidationError(format!(
+                "Function {} has an unknown parameter or return type",
+                self.qualified_name()
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn parse_class(name: &str) -> DataClass {
+    if name.eq_ignore_ascii_case("string") {
+        return DataClass::String;
+    }
+    data_class::AllDataClassExprParser::new()
+        .parse(name)
+        .unwrap_or(DataClass::Unknown)
+}
+
+/// Literal argument of a function call, the column value is passed implicitly.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum UdfArgument {
+    Number(String),
+    String(String),
+}
+
+impl UdfArgument {
+    fn is_assignable_to(&self, class: &DataClass) -> bool {
+        match self {
+            UdfArgument::Number(_) => class.is_numeric_like(),
+            UdfArgument::String(_) => class.is_string_like(),
+        }
+    }
+}
+
+impl Display for UdfArgument {
+    /// Literal in Spark SQL and Python notation.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UdfArgument::Number(value) => write!(f, "{}", value),
+            UdfArgument::String(value) => write!(f, "'{}'", value),
+        }
+    }
+}
+
+/// `-UDF <module>.<function>(<arguments>) [threshold]` rule that holds for the values the
+/// function returns true for. The signature is resolved from the declared functions.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, ValidColumnRule)]
+#[threshold_in(0.0, 1.0)]
+#[validate_with(validate_signature)]
+pub struct UserFunction {
+    pub name: String,
+    /// qualified name of the function, e.g. `checks.luhn`
+    pub function: String,
+    pub arguments: Vec<UdfArgument>,
+    pub signature: Option<FunctionDef>,
+    pub rule_ext_config: RuleExtConfig,
+    pub threshold: Threshold,
+}
+
+impl UserFunction {
+    pub fn new(
+        name: Option<String>,
+        function: String,
+        arguments: Vec<UdfArgument>,
+        rule_ext_config: Option<RuleExtConfig>,
+        threshold: Option<Threshold>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            function,
+            arguments,
+            signature: None,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+            threshold: threshold.unwrap_or_default(),
+        }
+    }
+
+    /// Call of the registered function on a column in Spark SQL, e.g. `checks_luhn(Iban, 10)`.
+    pub fn call(&self, column_name: &str) -> String {
+        let name = match &self.signature {
+            Some(signature) => signature.sql_name(),
+            None => self.function.replace('.', "_"),
+        };
+        let arguments = std::iter::once(column_name.to_owned())
+            .chain(self.arguments.iter().map(|argument| argument.to_string()))
+            .collect::<Vec<String>>()
+            .join(", ");
+        format!("{}({})", name, arguments)
+    }
+
+    /// The column has to match the first parameter, the arguments the remaining parameters and
+    /// the function has to return a boolean.
+    fn validate_signature(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
+        let invalid_call = |message: String| {
+            ColumnValidationError::InvalidType(format!(
+                "Function {} for column {}: {}",
+                self.function, column.name, message
+            ))
+        };
+        let signature = self
+            .signature
+            .as_ref()
+            .ok_or_else(|| invalid_call("function is not declared".to_owned()))?;
+        if !signature.returns.is_boolean_like() {
+            return Err(invalid_call(format!(
+                "returns {} instead of a boolean",
+                signature.returns
+            )));
+        }
+        let (column_class, parameters) = signature
+            .parameters
+            .split_first()
+            .ok_or_else(|| invalid_call("function has no parameters".to_owned()))?;
+        if !column.data_type.class.is_assignable_to(column_class) {
+            return Err(invalid_call(format!(
+                "column type {} does not match parameter type {}",
+                column.data_type, column_class
+            )));
+        }
+        if parameters.len() != self.arguments.len() {
+            return Err(invalid_call(format!(
+                "expected {} arguments after the column, got {}",
+                parameters.len(),
+                self.arguments.len()
+            )));
+        }
+        for (argument, parameter) in self.arguments.iter().zip(parameters) {
+            if !argument.is_assignable_to(parameter) {
+                return Err(invalid_call(format!(
+                    "argument {} does not match parameter type {}",
+                    argument, parameter
+                )));
+            }
+        }
+        Ok(String::from("valid"))
+    }
+}
+
+/// Looks up the signatures of the functions used by the rules, functions have to be declared
+/// before they are used.
+pub fn resolve_functions(
+    rule_filters: &mut [ColumnRuleFilter],
+    catalog: &Catalog,
+) -> Result<(), DDLxParseError> {
+    for rule in rule_filters
+        .iter_mut()
+        .flat_map(|rule_filter| rule_filter.rules.iter_mut())
+    {
+        if let ColumnRule::UserFunction(rule) = rule {
+            let signature = catalog.find_function(&rule.function).ok_or_else(|| {
+                DDLxParseError::ColumnValidationError(format!("Unknown function {}", rule.function))
+            })?;
+            rule.signature = Some(signature.clone());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::data_class::DataClass;
+    use crate::model::rule_traits::ValidColumnRule;
+    use crate::model::table_expr::{ColumnDef, DataType};
+    use crate::model::udf::{FunctionDef, UdfArgument, UserFunction};
+    use rstest::rstest;
+
+    fn luhn() -> FunctionDef {
+        FunctionDef::new(
+            "checks".to_owned(),
+            "luhn".to_owned(),
+            vec!["VarChar".to_owned(), "Int".to_owned()],
+            "Bool".to_owned(),
+        )
+    }
+
+    #[test]
+    fn test_function_def() {
+        let function = luhn();
+        assert_eq!(
+            function.parameters,
+            vec![DataClass::VarChar, DataClass::Int]
+        );
+        assert_eq!(function.returns, DataClass::Bool);
+        assert!(function.validate().is_ok());
+        assert!(
+            FunctionDef::new("a".to_owned(), "b".to_owned(), vec![], "Bool".to_owned())
+                .validate()
+                .is_err()
+        );
+        assert!(FunctionDef::new(
+            "a".to_owned(),
+            "b".to_owned(),
+            vec!["Money".to_owned()],
+            "Bool".to_owned()
+        )
+        .validate()
+        .is_err());
+    }
+
+    #[rstest]
+    #[case(DataType::new("VarChar", Some(20), None), vec![UdfArgument::Number("10".to_owned())], true)]
+    #[case(DataType::new("Text", Some(20), None), vec![UdfArgument::Number("10".to_owned())], true)]
+    #[case(DataType::new("Int", Some(10), None), vec![UdfArgument::Number("10".to_owned())], false)]
+    #[case(DataType::new("VarChar", Some(20), None), vec![UdfArgument::String("10".to_owned())], false)]
+    #[case(DataType::new("VarChar", Some(20), None), vec![], false)]
+    fn test_validate_signature(
+        #[case] data_type: DataType,
+        #[case] arguments: Vec<UdfArgument>,
+        #[case] is_valid: bool,
+    ) {
+        let column = ColumnDef::new("Iban".to_owned(), data_type, false, false);
+        let mut rule = UserFunction::new(None, "checks.luhn".to_owned(), arguments, None, None);
+        assert!(
+            rule.validate_col_type(&column).is_err(),
+            "undeclared function"
+        );
+
+        rule.signature = Some(luhn());
+        assert_eq!(rule.validate_col_type(&column).is_ok(), is_valid);
+        assert!(rule.call("Iban").starts_with("checks_luhn(Iban"));
+    }
+}
@@ -0,0 +1,650 @@
+use crate::model::catalog::Catalog;
+use crate::model::data_class::DataClass;
+use crate::model::lineage::ColumnLineage;
+use crate::model::select_expr::{
+    BinaryOperator, Expression, JoinKind, Literal, Projection, Select, TableSource,
+};
+use crate::model::table_expr::{data_class, ColumnDef, DataType, TableRef};
+use crate::parser::error_utils::DDLxParseError;
+
+/// `CREATE VIEW` statement. The select statement is kept as string until the view is resolved
+/// against the tables of the catalog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewDef {
+    pub view_ref: TableRef,
+    /// optional target schema the view has to comply with
+    pub declared_columns: Vec<ColumnDef>,
+    pub select_string: String,
+    pub select: Option<Select>,
+    pub columns: Vec<ViewColumn>,
+}
+
+/// Output column of a view with the inferred type and the source columns it is derived from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewColumn {
+    pub name: String,
+    pub data_type: DataType,
+    pub nullable: bool,
+    pub lineage: Vec<ColumnLineage>,
+}
+
+impl ViewDef {
+    pub fn new(
+        view_ref: TableRef,
+        declared_columns: Option<Vec<ColumnDef>>,
+        select_string: String,
+    ) -> Self {
+        Self {
+            view_ref,
+            declared_columns: declared_columns.unwrap_or_default(),
+            select_string,
+            select: None,
+            columns: vec![],
+        }
+    }
+
+    /// Parses the select statement, infers the output columns from the tables and views of the
+    /// catalog and checks them against the declared schema.
+    pub fn resolve(&self, catalog: &Catalog) -> Result<ViewDef, DDLxParseError> {
+        let select = Select::from_str(&self.select_string)?;
+        let columns = resolve_select(&select, catalog).map_err(|message| self.error(message))?;
+
+        let view = ViewDef {
+            select: Some(select),
+            columns,
+            ..self.clone()
+        };
+        view.validate_declared_columns()?;
+        Ok(view)
+    }
+
+    /// Checks that the inferred columns match the declared target schema of the view.
+    fn validate_declared_columns(&self) -> Result<(), DDLxParseError> {
+        if self.declared_columns.is_empty() {
+            return Ok(());
+        }
+
+        for column in &self.columns {
+            if !self
+                .declared_columns
+                .iter()
+                .any(|declared| declared.name == column.name)
+            {
+                return Err(self.error(format!(
+                    "Column {} is not part of the declared schema",
+                    column.name
+                )));
+            }
+        }
+
+        for declared in &self.declared_columns {
+            let column = self
+                .columns
+                .iter()
+                .find(|column| column.name == declared.name)
+                .ok_or_else(|| {
+                    self.error(format!("Declared column {} is not selected", declared.name))
+                })?;
+            if !column
+                .data_type
+                .class
+                .is_assignable_to(&declared.data_type.class)
+            {
+                return Err(self.error(format!(
+                    "Column {} has type {} which conflicts with the declared type {}",
+                    column.name, column.data_type.class, declared.data_type.class
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn error(&self, message: String) -> DDLxParseError {
+        DDLxParseError::ViewValidationError(format!("View {}: {}", self.view_ref, message))
+    }
+}
+
+/// Column of a table or view that is visible to a select statement.
+#[derive(Clone)]
+struct ScopeColumn {
+    name: String,
+    data_type: DataType,
+    nullable: bool,
+}
+
+/// Table or view the select statement reads from.
+struct ScopeSource {
+    table: String,
+    source: TableSource,
+    columns: Vec<ScopeColumn>,
+}
+
+/// Resolves the output columns of a select statement against the tables and views of the catalog.
+/// Returns a message for unknown tables or columns and for operands with conflicting types.
+pub fn resolve_select(select: &Select, catalog: &Catalog) -> Result<Vec<ViewColumn>, String> {
+    let mut scope = build_scope(select, catalog)?;
+
+    for join in &select.joins {
+        infer(&join.condition, &scope)?;
+    }
+    if let Some(filter) = &select.filter {
+        infer(filter, &scope)?;
+        // rows with null values in these columns are filtered out
+        for (qualifier, name) in not_null_columns(filter) {
+            let (source_index, column_index) = resolve_column(qualifier, name, &scope)?;
+            scope[source_index].columns[column_index].nullable = false;
+        }
+    }
+
+    let mut columns: Vec<ViewColumn> = vec![];
+    for projection in &select.projections {
+        match projection {
+            Projection::Wildcard(qualifier) => {
+                let mut sources = scope
+                    .iter()
+                    .filter(|scope_source| match qualifier {
+                        Some(qualifier) => scope_source.source.is_referenced_by(qualifier),
+                        None => true,
+                    })
+                    .peekable();
+                if sources.peek().is_none() {
+                    return Err(format!(
+                        "Unknown table {}",
+                        qualifier.clone().unwrap_or_default()
+                    ));
+                }
+                for scope_source in sources {
+                    for column in &scope_source.columns {
+                        columns.push(ViewColumn {
+                            name: column.name.clone(),
+                            data_type: column.data_type.clone(),
+                            nullable: column.nullable,
+                            lineage: vec![ColumnLineage {
+                                table: scope_source.table.clone(),
+                                column: column.name.clone(),
+                            }],
+                        });
+                    }
+                }
+            }
+            Projection::Expression { expression, alias } => {
+                let name = match (alias, expression) {
+                    (Some(alias), _) => alias.clone(),
+                    (None, Expression::Column { name, .. }) => name.clone(),
+                    (None, _) => expression.to_string(),
+                };
+                let inferred = infer(expression, &scope)?;
+                columns.push(ViewColumn {
+                    name,
+                    data_type: inferred.data_type,
+                    nullable: inferred.nullable,
+                    lineage: lineage(expression, &scope)?,
+                });
+            }
+        }
+    }
+    Ok(columns)
+}
+
+fn build_scope(select: &Select, catalog: &Catalog) -> Result<Vec<ScopeSource>, String> {
+    let mut scope: Vec<ScopeSource> = vec![];
+    for source in select.sources() {
+        let columns = if let Some(table) = catalog.find_table(&source.table_name) {
+            table
+                .columns
+                .iter()
+                .map(|column| ScopeColumn {
+                    name: column.name.clone(),
+                    data_type: column.data_type.clone(),
+                    nullable: !column.not_null,
+                })
+                .collect()
+        } else if let Some(view) = catalog.find_view(&source.table_name) {
+            view.columns
+                .iter()
+                .map(|column| ScopeColumn {
+                    name: column.name.clone(),
+                    data_type: column.data_type.clone(),
+                    nullable: column.nullable,
+                })
+                .collect()
+        } else {
+            return Err(format!("Unknown table {}", source.table_name));
+        };
+        scope.push(ScopeSource {
+            table: source.table_name.clone(),
+            source: source.clone(),
+            columns,
+        });
+    }
+
+    // outer joins produce null values for the columns of the optional side
+    for (index, join) in select.joins.iter().enumerate() {
+        let joined = index + 1;
+        let nullable_sources = match join.kind {
+            JoinKind::Inner => 0..0,
+            JoinKind::Left => joined..joined + 1,
+            JoinKind::Right => 0..joined,
+            JoinKind::Full => 0..joined + 1,
+        };
+        for scope_source in &mut scope[nullable_sources] {
+            for column in &mut scope_source.columns {
+                column.nullable = true;
+            }
+        }
+    }
+    Ok(scope)
+}
+
+/// Returns the index of the source and of the column a column reference resolves to.
+fn resolve_column(
+    qualifier: Option<&String>,
+    name: &String,
+    scope: &[ScopeSource],
+) -> Result<(usize, usize), String> {
+    let mut matches = scope
+        .iter()
+        .enumerate()
+        .filter(|(_, scope_source)| match qualifier {
+            Some(qualifier) => scope_source.source.is_referenced_by(qualifier),
+            None => true,
+        })
+        .filter_map(|(source_index, scope_source)| {
+            scope_source
+                .columns
+                .iter()
+                .position(|column| &column.name == name)
+                .map(|column_index| (source_index, column_index))
+        });
+
+    let column_name = match qualifier {
+        Some(qualifier) => format!("{}.{}", qualifier, name),
+        None => name.clone(),
+    };
+    let found = matches
+        .next()
+        .ok_or_else(|| format!("Unknown column {}", column_name))?;
+    if matches.next().is_some() {
+        return Err(format!("Ambiguous column {}", column_name));
+    }
+    Ok(found)
+}
+
+fn lineage(expression: &Expression, scope: &[ScopeSource]) -> Result<Vec<ColumnLineage>, String> {
+    let mut lineage: Vec<ColumnLineage> = vec![];
+    for (qualifier, name) in expression.columns() {
+        let (source_index, _) = resolve_column(qualifier, name, scope)?;
+        let column_lineage = ColumnLineage {
+            table: scope[source_index].table.clone(),
+            column: name.clone(),
+        };
+        if !lineage.contains(&column_lineage) {
+            lineage.push(column_lineage);
+        }
+    }
+    Ok(lineage)
+}
+
+/// Columns that cannot be null in the result because the filter removes rows with null values,
+/// e.g. `a IS NOT NULL AND b > 3`.
+fn not_null_columns(filter: &Expression) -> Vec<(Option<&String>, &String)> {
+    match filter {
+        Expression::Binary {
+            left,
+            operator: BinaryOperator::And,
+            right,
+        } => {
+            let mut columns = not_null_columns(left);
+            columns.extend(not_null_columns(right));
+            columns
+        }
+        Expression::IsNull {
+            expression,
+            negated: true,
+        } => match expression.as_ref() {
+            Expression::Column { qualifier, name } => vec![(qualifier.as_ref(), name)],
+            _ => vec![],
+        },
+        Expression::Binary {
+            operator: BinaryOperator::Comparison(_),
+            ..
+        } => filter.columns(),
+        _ => vec![],
+    }
+}
+
+struct InferredType {
+    data_type: DataType,
+    nullable: bool,
+}
+
+impl InferredType {
+    fn new(class: DataClass, nullable: bool) -> Self {
+        Self {
+            data_type: unsized_type(class),
+            nullable,
+        }
+    }
+}
+
+fn infer(expression: &Expression, scope: &[ScopeSource]) -> Result<InferredType, String> {
+    let inferred = match expression {
+        Expression::Column { qualifier, name } => {
+            let (source_index, column_index) = resolve_column(qualifier.as_ref(), name, scope)?;
+            let column = &scope[source_index].columns[column_index];
+            InferredType {
+                data_type: column.data_type.clone(),
+                nullable: column.nullable,
+            }
+        }
+        Expression::Literal(Literal::Number(number)) if number.contains('.') => {
+            InferredType::new(DataClass::Double, false)
+        }
+        Expression::Literal(Literal::Number(_)) => InferredType::new(DataClass::Int, false),
+        Expression::Literal(Literal::String(value)) => InferredType {
+            data_type: DataType {
+                class: DataClass::VarChar,
+                size: Some([Some(value.chars().count() as u32), None]),
+            },
+            nullable: false,
+        },
+        Expression::Literal(Literal::Boolean(_)) => InferredType::new(DataClass::Bool, false),
+        Expression::Literal(Literal::Null) => InferredType::new(DataClass::Unknown, true),
+        Expression::Wildcard => InferredType::new(DataClass::Unknown, false),
+        Expression::Binary {
+            left,
+            operator,
+            right,
+        } => {
+            let left = infer(left, scope)?;
+            let right = infer(right, scope)?;
+            let nullable = left.nullable || right.nullable;
+            match operator {
+                operator if operator.is_arithmetic() => {
+                    for operand in [&left.data_type, &right.data_type] {
+                        if operand.class != DataClass::Unknown && !operand.class.is_numeric_like() {
+                            return Err(format!(
+                                "Operator {} in {} requires numeric operands, found {}",
+                                operator, expression, operand.class
+                            ));
+                        }
+                    }
+                    InferredType {
+                        data_type: arithmetic_type(operator, &left.data_type, &right.data_type),
+                        nullable,
+                    }
+                }
+                BinaryOperator::Concat => InferredType::new(DataClass::String, nullable),
+                _ => InferredType::new(DataClass::Bool, nullable),
+            }
+        }
+        Expression::Not(expression) => {
+            InferredType::new(DataClass::Bool, infer(expression, scope)?.nullable)
+        }
+        Expression::IsNull { expression, .. } => {
+            infer(expression, scope)?;
+            InferredType::new(DataClass::Bool, false)
+        }
+        Expression::Negate(expression) => infer(expression, scope)?,
+        Expression::Cast {
+            expression,
+            data_type,
+        } => InferredType {
+            nullable: infer(expression, scope)?.nullable,
+            data_type: parse_type_name(data_type)
+                .ok_or_else(|| format!("Unknown type {}", data_type))?,
+        },
+        Expression::Function { name, arguments } => {
+            let mut argument_types: Vec<InferredType> = vec![];
+            for argument in arguments {
+                argument_types.push(infer(argument, scope)?);
+            }
+            function_type(name, &argument_types)
+        }
+        Expression::Filter {
+            function,
+            condition,
+        } => {
+            infer(condition, scope)?;
+            infer(function, scope)?
+        }
+    };
+    Ok(inferred)
+}
+
+fn unsized_type(class: DataClass) -> DataType {
+    DataType { class, size: None }
+}
+
+/// Parses type names of casts, sizes are optional, e.g. `DOUBLE` or `VARCHAR(10)`.
+fn parse_type_name(type_name: &str) -> Option<DataType> {
+    if let Ok(data_type) = data_class::DataTypeExprParser::new().parse(type_name) {
+        return Some(data_type);
+    }
+    if type_name.eq_ignore_ascii_case("string") {
+        return Some(unsized_type(DataClass::String));
+    }
+    data_class::AllDataClassExprParser::new()
+        .parse(type_name)
+        .ok()
+        .map(unsized_type)
+}
+
+fn arithmetic_type(operator: &BinaryOperator, left: &DataType, right: &DataType) -> DataType {
+    if left.class == DataClass::Unknown || right.class == DataClass::Unknown {
+        return DataType::default();
+    }
+    if *operator == BinaryOperator::Divide
+        || left.class.is_fraction_like()
+        || right.class.is_fraction_like()
+    {
+        return unsized_type(DataClass::Double);
+    }
+    if left.class == right.class {
+        return unsized_type(left.class.clone());
+    }
+    unsized_type(DataClass::BigInt)
+}
+
+/// Result type of the most common Spark SQL functions, unknown functions have an unknown type.
+fn function_type(name: &str, arguments: &[InferredType]) -> InferredType {
+    let any_nullable = arguments.iter().any(|argument| argument.nullable);
+    let first = arguments
+        .first()
+        .map(|argument| argument.data_type.clone())
+        .unwrap_or_default();
+    let typed = |class: DataClass| InferredType::new(class, any_nullable);
+
+    match name.to_lowercase().as_str() {
+        "count" | "approx_count_distinct" => InferredType::new(DataClass::BigInt, false),
+        "sum" if first.class.is_fraction_like() => typed(DataClass::Double),
+        "sum" => typed(DataClass::BigInt),
+        "avg" | "mean" | "stddev" | "variance" => typed(DataClass::Double),
+        "floor" | "ceil" | "length" => typed(DataClass::BigInt),
+        // string functions that do not change the maximum length keep the type of the argument
+        "min" | "max" | "abs" | "round" | "first" | "last" | "upper" | "lower" | "trim"
+        | "ltrim" | "rtrim" => InferredType {
+            data_type: first,
+            nullable: any_nullable,
+        },
+        "concat" | "substring" | "substr" => typed(DataClass::String),
+        "coalesce" | "ifnull" | "nvl" => InferredType {
+            data_type: arguments
+                .iter()
+                .map(|argument| argument.data_type.clone())
+                .find(|data_type| data_type.class != DataClass::Unknown)
+                .unwrap_or_default(),
+            nullable: arguments.iter().all(|argument| argument.nullable),
+        },
+        "current_date" | "to_date" => typed(DataClass::Date),
+        "current_timestamp" | "now" | "to_timestamp" => typed(DataClass::Timestamp),
+        _ => typed(DataClass::Unknown),
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::catalog::Catalog;
+    use crate::model::data_class::DataClass;
+    use crate::model::table_expr::{ColumnDef, DataType, TableDef, TableRef};
+    use crate::model::view_expr::{ColumnLineage, ViewDef};
+    use rstest::rstest;
+
+    fn catalog() -> Catalog {
+        Catalog::new(vec![
+            TableDef {
+                table_ref: TableRef::new("Orders", Some("shop"), None),
+                columns: vec![
+                    ColumnDef::new(
+                        "Id".to_owned(),
+                        DataType::new("Int", Some(10), None),
+                        true,
+                        true,
+                    ),
+                    ColumnDef::new(
+                        "CustomerId".to_owned(),
+                        DataType::new("Int", Some(10), None),
+                        false,
+                        false,
+                    ),
+                    ColumnDef::new(
+                        "Price".to_owned(),
+                        DataType::new("Double", Some(10), Some(2)),
+                        false,
+                        false,
+                    ),
+                    ColumnDef::new(
+                        "Quantity".to_owned(),
+                        DataType::new("Int", Some(3), None),
+                        false,
+                        false,
+                    ),
+                ],
+                table_level_rules: vec![],
+                ..Default::default()
+            },
+            TableDef {
+                table_ref: TableRef::new("Customers", None, None),
+                columns: vec![
+                    ColumnDef::new(
+                        "Id".to_owned(),
+                        DataType::new("Int", Some(10), None),
+                        true,
+                        true,
+                    ),
+                    ColumnDef::new(
+                        "Name".to_owned(),
+                        DataType::new("VarChar", Some(50), None),
+                        false,
+                        false,
+                    ),
+                ],
+                table_level_rules: vec![],
+                ..Default::default()
+            },
+        ])
+    }
+
+    fn view(declared_columns: Option<Vec<ColumnDef>>, select_string: &str) -> ViewDef {
+        ViewDef::new(
+            TableRef::new("OrderTotals", None, None),
+            declared_columns,
+            select_string.to_owned(),
+        )
+    }
+
+    #[test]
+    fn test_resolve_view() {
+        let view = view(
+            None,
+            "SELECT o.Id, c.Name AS Customer, o.Price * o.Quantity AS Total, count(*) AS N \
+            FROM shop.Orders o JOIN Customers c ON o.CustomerId = c.Id WHERE o.Price > 0",
+        )
+        .resolve(&catalog())
+        .unwrap();
+
+        let columns = view
+            .columns
+            .iter()
+            .map(|column| (column.name.as_str(), column.data_type.class.clone()))
+            .collect::<Vec<(&str, DataClass)>>();
+        assert_eq!(
+            columns,
+            vec![
+                ("Id", DataClass::Int),
+                ("Customer", DataClass::VarChar),
+                ("Total", DataClass::Double),
+                ("N", DataClass::BigInt),
+            ]
+        );
+        assert_eq!(
+            view.columns[2].lineage,
+            vec![
+                ColumnLineage {
+                    table: "shop.Orders".to_owned(),
+                    column: "Price".to_owned()
+                },
+                ColumnLineage {
+                    table: "shop.Orders".to_owned(),
+                    column: "Quantity".to_owned()
+                },
+            ]
+        );
+        assert_eq!(view.columns[1].data_type.size, Some([Some(50), None]));
+    }
+
+    #[test]
+    fn test_resolve_view_wildcard() {
+        let view = view(None, "SELECT * FROM Customers")
+            .resolve(&catalog())
+            .unwrap();
+        assert_eq!(view.columns.len(), 2);
+        assert_eq!(view.columns[1].lineage[0].table, "Customers");
+    }
+
+    #[rstest]
+    #[case(None, "SELECT Unknown FROM Customers")]
+    #[case(None, "SELECT Id FROM Missing")]
+    #[case(
+        None,
+        "SELECT Id FROM shop.Orders o JOIN Customers c ON o.CustomerId = c.Id"
+    )]
+    #[case(None, "SELECT c.Id FROM Customers WHERE Other > 3")]
+    #[case(None, "SELECT Name * 2 AS Doubled FROM Customers")]
+    #[case(None, "SELECT x.* FROM Customers")]
+    #[case(Some(vec![ColumnDef::new("Name".to_owned(), DataType::new("Int", Some(10), None), false, false)]), "SELECT Name FROM Customers")]
+    #[case(Some(vec![ColumnDef::new("Id".to_owned(), DataType::new("Int", Some(10), None), false, false)]), "SELECT Id, Name FROM Customers")]
+    #[case(Some(vec![ColumnDef::new("Id".to_owned(), DataType::new("Int", Some(10), None), false, false),
+        ColumnDef::new("Name".to_owned(), DataType::new("VarChar", Some(10), None), false, false)]), "SELECT Id FROM Customers")]
+    fn test_resolve_view_error(
+        #[case] declared_columns: Option<Vec<ColumnDef>>,
+        #[case] select_string: &str,
+    ) {
+        assert!(view(declared_columns, select_string)
+            .resolve(&catalog())
+            .is_err());
+    }
+
+    #[test]
+    fn test_resolve_view_declared_schema() {
+        let declared_columns = vec![
+            ColumnDef::new(
+                "Id".to_owned(),
+                DataType::new("BigInt", Some(20), None),
+                false,
+                false,
+            ),
+            ColumnDef::new(
+                "Name".to_owned(),
+                DataType::new("Text", Some(100), None),
+                false,
+                false,
+            ),
+        ];
+        assert!(
+            view(Some(declared_columns), "SELECT Id, Name FROM Customers")
+                .resolve(&catalog())
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use crate::model::rule_ext_config::RuleExtConfig;
+use crate::model::rule_traits::{ColumnValidationError, ValidTableRule};
+use crate::model::table_expr::TableDef;
+use crate::model::threshold::ThresholdOperator;
+use crate::parser::error_utils::DDLxParseError;
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Alias of the previous load of a table, it is the reference dataset in DQDL.
+pub const PREVIOUS_RUN: &str = "previous_run";
+
+/// Expected number of rows of a table. Counts can be grouped with `_`, e.g. `5_000_000`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum RowCount {
+    Between(u64, u64),
+    Compare(ThresholdOperator, u64),
+    /// compared with the row count of the previous run times a factor, e.g. `>= 0.9 * previous_run`
+    PreviousRun(ThresholdOperator, f64),
+}
+
+impl RowCount {
+    /// Python lambda that checks a row count, the previous row count is `previous_size`.
+    pub fn assertion(&self) -> String {
+        let python_operator = |operator: ThresholdOperator| match operator {
+            ThresholdOperator::Equal => "==".to_owned(),
+            operator => operator.to_string(),
+        };
+        match *self {
+            RowCount::Between(min, max) => format!("lambda x: {} <= x <= {}", min, max),
+            RowCount::Compare(operator, count) => {
+                format!("lambda x: x {} {}", python_operator(operator), count)
+            }
+            RowCount::PreviousRun(operator, factor) => format!(
+                "lambda x: previous_size is None or x {} {} * previous_size",
+                python_operator(operator),
+                factor
+            ),
+        }
+    }
+}
+
+impl Display for RowCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RowCount::Between(min, max) => write!(f, "between {} and {}", min, max),
+            RowCount::Compare(operator, count) => write!(f, "{} {}", operator, count),
+            RowCount::PreviousRun(operator, factor) => {
+                write!(f, "{} {} * {}", operator, factor, PREVIOUS_RUN)
+            }
+        }
+    }
+}
+
+/// Parses a row count whose digits can be grouped with `_`, only counts above `u64::MAX` fail.
+pub fn parse_count(count: &str) -> Result<u64, DDLxParseError> {
+    count.replace('_', "").parse::<u64>().map_err(|_| {
+        DDLxParseError::TableValidationError(format!(
+            "Row count {} has to be at most {}",
+            count,
+            u64::MAX
+        ))
+    })
+}
+
+/// `-ROWS <assertion>` rule on the number of rows of a table, e.g. to detect empty loads.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Volume {
+    pub name: String,
+    pub row_count: RowCount,
+    pub rule_ext_config: RuleExtConfig,
+}
+
+impl Volume {
+    pub fn new(
+        name: Option<String>,
+        row_count: RowCount,
+        rule_ext_config: Option<RuleExtConfig>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            row_count,
+            rule_ext_config: rule_ext_config.unwrap_or_default(),
+        }
+    }
+
+    /// Rules that compare the row count with the previous run, which has to be stored in a
+    /// metrics repository.
+    pub fn is_run_over_run(&self) -> bool {
+        matches!(self.row_count, RowCount::PreviousRun(..))
+    }
+}
+
+impl ValidTableRule for Volume {
+    fn validate_table(&self, table: &TableDef) -> Result<String, ColumnValidationError> {
+        let invalid = |message: &str| {
+            Err(ColumnValidationError::InvalidThreshold(format!(
+                "Row count {} of table {}: {}",
+                self.row_count, table.table_ref, message
+            )))
+        };
+        match self.row_count {
+            RowCount::Between(min, max) if min > max => invalid("the range is empty"),
+            RowCount::PreviousRun(_, factor) if factor <= 0.0 => {
+                invalid("the factor has to be positive")
+            }
+            _ => Ok(String::from("valid")),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::model::rule_traits::ValidTableRule;
+    use crate::model::table_expr::{TableDef, TableRef};
+    use crate::model::table_rule::TableRule;
+    use crate::model::threshold::ThresholdOperator;
+    use crate::model::volume::{RowCount, Volume};
+    use crate::parser::table::TableConstraintExprParser;
+    use lalrpop_util::ParseError;
+    use rstest::rstest;
+
+    fn parse(rule: &str) -> Result<RowCount, String> {
+        let rule = TableConstraintExprParser::new()
+            .parse(rule)
+            .map_err(|err| match err {
+                ParseError::User { error } => error.to_string(),
+                err => err.to_string(),
+            })?;
+        match rule {
+            TableRule::Volume(volume) => Ok(volume.row_count),
+            rule => Err(format!("Expected a row count, found {:?}", rule)),
+        }
+    }
+
+    #[rstest]
+    #[case("-ROWS between 1000 and 5_000_000", RowCount::Between(1000, 5_000_000))]
+    #[case(
+        "-rows >= 1_000",
+        RowCount::Compare(ThresholdOperator::GreaterEqual, 1000)
+    )]
+    #[case("-ROWS = 0", RowCount::Compare(ThresholdOperator::Equal, 0))]
+    #[case(
+        "-ROWS >=0.9 * previous_run",
+        RowCount::PreviousRun(ThresholdOperator::GreaterEqual, 0.9)
+    )]
+    #[case(
+        "-ROWS < 2 * PREVIOUS_RUN",
+        RowCount::PreviousRun(ThresholdOperator::Less, 2.0)
+    )]
+    fn test_parse(#[case] rule: &str, #[case] expected: RowCount) {
+        assert_eq!(parse(rule), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("-ROWS 1000")]
+    #[case("-ROWS >= 0.9 * last_run")]
+    #[case("-ROWS >= 1.5")]
+    #[case("-ROWS between 1 and")]
+    fn test_parse_error(#[case] rule: &str) {
+        assert!(parse(rule).is_err());
+    }
+
+    #[rstest]
+    #[case(
+        "-ROWS >= 18_446_744_073_709_551_615",
+        Ok(RowCount::Compare(ThresholdOperator::GreaterEqual, u64::MAX))
+    )]
+    #[case("-ROWS >= 18446744073709551616", Err("Row count 18446744073709551616 has to be at most 18446744073709551615".to_owned()))]
+    #[case("-ROWS >= 0.9 * last_run", Err("Unrecognized token `last_run` found at 15:23\nExpected one of PREVIOUS_RUN".to_owned()))]
+    fn test_parse_overflow(#[case] rule: &str, #[case] expected: Result<RowCount, String>) {
+        assert_eq!(parse(rule), expected);
+    }
+
+    #[rstest]
+    #[case(RowCount::Between(1000, 5_000_000), true)]
+    #[case(RowCount::Between(5_000_000, 1000), false)]
+    #[case(RowCount::PreviousRun(ThresholdOperator::GreaterEqual, 0.0), false)]
+    fn test_validate_table(#[case] row_count: RowCount, #[case] is_valid: bool) {
+        let table = TableDef {
+            table_ref: TableRef::new("Orders", None, None),
+            ..Default::default()
+        };
+        let rule = Volume::new(None, row_count, None);
+        assert_eq!(rule.validate_table(&table).is_ok(), is_valid);
+    }
+}
@@ -1,49 +1,224 @@
+use crate::model::catalog::{Catalog, SymbolKind};
+use crate::model::column_rule::{ColumnRule, ColumnSequence};
+use crate::model::domain::expand_column;
+use crate::model::lineage::LineageGraph;
+use crate::model::pragma::{ImplicitRules, Pragma};
 use crate::model::rule_filter::combine_itentical_filters;
 use crate::model::rule_filter::filter::ColumnRuleFilter;
-use crate::model::rule_traits::ValidColumnRule;
+use crate::model::rule_traits::{ValidColumnRule, ValidTableRule};
+use crate::model::select_expr::select;
+use crate::model::statement::Statement;
 use crate::model::table_expr::{ColumnDef, TableDef};
+use crate::model::table_rule::{validate_columns_exist, TableRule};
+use crate::model::transform::Insert;
 use crate::parser::error_utils::{
     gen_syntax_error_message, gen_unknown_token_error_message, DDLxParseError,
 };
 use crate::parser::lines::Line;
+use crate::parser::project::Project;
+use lalrpop_util::lexer::Token;
 use lalrpop_util::{lalrpop_mod, ParseError};
+use std::fmt::Debug;
 
 pub mod create_table_tests;
 pub mod data_class_tests;
 pub mod error_utils;
 pub mod lines;
+pub mod project;
 pub mod rule_filter_tests;
 
-lalrpop_mod!(pub table, "/parser/create_table.rs");
+lalrpop_mod!(#[allow(clippy::all)] pub table, "/parser/create_table.rs");
 
+/// Parses an input that defines a single table, all statements of the input are applied.
+#[cfg(test)]
 pub fn parse(input_string: &str) -> Result<Box<TableDef>, DDLxParseError> {
-    let table_def = table::CreateTableExprParser::new().parse(input_string);
-    if table_def.is_err() {
-        let lines = Line::from_string(input_string.to_owned());
+    single_table(parse_catalog(input_string)?)
+}
 
-        let err = table_def.unwrap_err();
-        match err {
-            ParseError::InvalidToken { location } => {
-                let message = gen_unknown_token_error_message(location, lines);
+/// Returns the only table of a catalog, e.g. for targets that compile a single table.
+pub fn single_table(mut catalog: Catalog) -> Result<Box<TableDef>, DDLxParseError> {
+    if catalog.tables.len() != 1 {
+        return Err(DDLxParseError::TableValidationError(format!(
+            "Expected exactly one table, found {}",
+            catalog.tables.len()
+        )));
+    }
 
-                return Err(DDLxParseError::UnknownToken(message));
-            }
-            ParseError::UnrecognizedToken { token, expected } => {
-                let message = gen_syntax_error_message(token, lines, expected);
+    Ok(Box::new(catalog.tables.remove(0)))
+}
+
+/// Parses all statements of the input into a single catalog. `ALTER TABLE` statements are
+/// applied in order to the tables created before and the altered table is validated again.
+/// Views are resolved against the tables and views defined before them. Declared column lineage
+/// is checked against the complete catalog, its warnings are kept in the catalog.
+pub fn parse_catalog(input_string: &str) -> Result<Catalog, DDLxParseError> {
+    let statements = parse_statements(input_string)?;
+    if let Some(Statement::Import(path)) = statements
+        .iter()
+        .find(|statement| matches!(statement, Statement::Import(_)))
+    {
+        return Err(DDLxParseError::ImportError(format!(
+            "Cannot import {}, imports are only supported for input files",
+            path
+        )));
+    }
+
+    let mut catalog = Catalog::default();
+    apply_statements(&mut catalog, statements, None)?;
 
-                return Err(DDLxParseError::SyntaxError(message));
+    catalog.lineage_warnings = LineageGraph::from_catalog(&catalog).validate(&catalog)?;
+    Ok(catalog)
+}
+
+/// Builds one catalog from all files of a project. Files are applied in the order of their
+/// imports, so tables can reference tables that are defined in other files.
+pub fn parse_project(project: &Project) -> Result<Catalog, DDLxParseError> {
+    let mut catalog = Catalog::default();
+    for file in &project.files {
+        let file_name = file.path.display().to_string();
+        apply_statements(&mut catalog, file.statements.clone(), Some(&file_name))
+            .map_err(|err| err.in_file(&file_name))?;
+    }
+
+    catalog.lineage_warnings = LineageGraph::from_catalog(&catalog).validate(&catalog)?;
+    Ok(catalog)
+}
+
+fn parse_statements(input_string: &str) -> Result<Vec<Statement>, DDLxParseError> {
+    table::CatalogExprParser::new()
+        .parse(input_string)
+        .map_err(|err| match err {
+            ParseError::User { error } => error,
+            err => convert_parse_error(err, input_string),
+        })
+}
+
+fn apply_statements(
+    catalog: &mut Catalog,
+    statements: Vec<Statement>,
+    file: Option<&str>,
+) -> Result<(), DDLxParseError> {
+    let mut pragmas: Vec<Pragma> = vec![];
+    for statement in statements {
+        match statement {
+            Statement::CreateTable(table) => {
+                catalog.define(table.table_ref.to_string(), SymbolKind::Table, file)?;
+                let mut table = expand_table(&table, catalog)?;
+                // pragmas of the table override the pragmas of the file
+                table.pragmas.splice(0..0, pragmas.iter().cloned());
+                catalog.tables.push(validate_table(&apply_pragmas(table)?)?)
+            }
+            Statement::AlterTable(alter_table) => {
+                let table_name = alter_table.table_ref.to_string();
+                let mut table = catalog.find_table(&table_name).cloned().ok_or_else(|| {
+                    DDLxParseError::SchemaEvolutionError(format!(
+                        "Table {} is altered before it is created",
+                        table_name
+                    ))
+                })?;
+                alter_table.apply(&mut table)?;
+                let table = validate_table(&apply_pragmas(expand_table(&table, catalog)?)?)?;
+                if let Some(catalog_table) = catalog.find_table_mut(&table_name) {
+                    *catalog_table = table;
+                }
+            }
+            Statement::CreateView(mut view) => {
+                catalog.define(view.view_ref.to_string(), SymbolKind::View, file)?;
+                view.declared_columns = view
+                    .declared_columns
+                    .iter()
+                    .map(|column| expand_column(column, catalog))
+                    .collect::<Result<Vec<ColumnDef>, DDLxParseError>>()?;
+                let resolved_view = view.resolve(catalog)?;
+                catalog.views.push(resolved_view);
+            }
+            Statement::CreateDomain(domain) => {
+                catalog.define(domain.name.clone(), SymbolKind::Domain, file)?;
+                let expanded_domain = domain.expand(catalog)?;
+                catalog.domains.push(expanded_domain);
+            }
+            Statement::CreateRuleSet(ruleset) => {
+                catalog.define(ruleset.name.clone(), SymbolKind::RuleSet, file)?;
+                let expanded_ruleset = ruleset.expand(catalog)?;
+                catalog.rulesets.push(expanded_ruleset);
+            }
+            Statement::CreateFunction(function) => {
+                catalog.define(function.qualified_name(), SymbolKind::Function, file)?;
+                function.validate()?;
+                catalog.functions.push(function);
+            }
+            Statement::Pragma(pragma) => {
+                ImplicitRules::from_pragmas(std::slice::from_ref(&pragma))?;
+                pragmas.push(pragma);
             }
-            // ParseError::ExtraToken { token } => {
-            //     panic!("Extra token {}", token);
-            // }
-            // ParseError::UnrecognizedEof { location, expected } => {
-            //     panic!("Unrecognized EOF at {} expected {}", location, expected);
-            // }
-            _ => panic!("{:?}", err),
+            // imports are resolved when the project files are loaded
+            Statement::Import(_) => {}
         }
     }
-    let table = table_def.unwrap();
+    Ok(())
+}
+
+/// Parses the `INSERT .. SELECT` statements of a transformation script.
+pub fn parse_transform(input_string: &str) -> Result<Vec<Insert>, DDLxParseError> {
+    select::TransformExprParser::new()
+        .parse(input_string)
+        .map_err(|err| convert_parse_error(err, input_string))
+}
+
+fn convert_parse_error<E: Debug>(
+    err: ParseError<usize, Token<'_>, E>,
+    input_string: &str,
+) -> DDLxParseError {
+    let lines = Line::from_string(input_string.to_owned());
+
+    match err {
+        ParseError::InvalidToken { location } => {
+            let message = gen_unknown_token_error_message(location, lines);
+
+            DDLxParseError::UnknownToken(message)
+        }
+        ParseError::UnrecognizedToken { token, expected } => {
+            let message = gen_syntax_error_message(token, lines, expected);
 
+            DDLxParseError::SyntaxError(message)
+        }
+        ParseError::UnrecognizedEof { expected, .. } => DDLxParseError::SyntaxError(format!(
+            "Unexpected end of input, expected one of {}",
+            expected.join(", ")
+        )),
+        // ParseError::ExtraToken { token } => {
+        //     panic!("Extra token {}", token);
+        // }
+        _ => panic!("{:?}", err),
+    }
+}
+
+/// Expands the domains and rulesets used by the columns of a table.
+fn expand_table(table: &TableDef, catalog: &Catalog) -> Result<TableDef, DDLxParseError> {
+    let columns = table
+        .columns
+        .iter()
+        .map(|column| expand_column(column, catalog))
+        .collect::<Result<Vec<ColumnDef>, DDLxParseError>>()?;
+
+    Ok(TableDef {
+        columns,
+        ..table.clone()
+    })
+}
+
+/// Removes or samples the implicit rules of the columns as defined by the pragmas of the table.
+fn apply_pragmas(mut table: TableDef) -> Result<TableDef, DDLxParseError> {
+    let implicit_rules = ImplicitRules::from_pragmas(&table.pragmas)?;
+    for column in table.columns.iter_mut() {
+        implicit_rules.apply(column);
+    }
+    Ok(table)
+}
+
+/// Parses the rule filters of all columns and validates the rules against the column types
+/// and the table level rules against the columns of the table.
+fn validate_table(table: &TableDef) -> Result<TableDef, DDLxParseError> {
     let mut columns: Vec<ColumnDef> = vec![];
 
     for column in &table.columns {
@@ -57,7 +232,23 @@ pub fn parse(input_string: &str) -> Result<Box<TableDef>, DDLxParseError> {
             for col_rule in filter_result.rules {
                 let result = col_rule.validate_col_type(column);
                 if let Err(err) = result {
-                    return Err(DDLxParseError::ColumnValidationError(format!("{:?}", err)));
+                    return Err(DDLxParseError::ColumnValidationError(err.to_string()));
+                }
+
+                // custom SQL can reference every column of the table
+                if let ColumnRule::CustomSql(rule) = &col_rule {
+                    rule.validate_table(table)
+                        .map_err(|err| DDLxParseError::ColumnValidationError(err.to_string()))?;
+                }
+
+                // a sequence can only be ordered by a column of the same table
+                if let ColumnRule::ColumnSequence(ColumnSequence {
+                    order_by: Some(order_by),
+                    ..
+                }) = &col_rule
+                {
+                    validate_columns_exist(std::slice::from_ref(order_by), table)
+                        .map_err(|err| DDLxParseError::ColumnValidationError(err.to_string()))?;
                 }
             }
         }
@@ -65,23 +256,47 @@ pub fn parse(input_string: &str) -> Result<Box<TableDef>, DDLxParseError> {
         parsed_filters = combine_itentical_filters(parsed_filters);
 
         columns.push(ColumnDef {
-            name: column.name.clone(),
-            data_type: column.data_type.clone(),
-            primary_key: column.primary_key,
-            not_null: column.not_null,
             rules: parsed_filters,
+            ..column.clone()
         })
     }
 
-    Ok(Box::new(TableDef {
+    let table_def = TableDef {
         table_ref: table.table_ref.to_owned(),
         columns,
-    }))
+        table_level_rules: table.table_level_rules.clone(),
+        severity: table.severity,
+        pragmas: table.pragmas.clone(),
+    };
+
+    let primary_keys = table_def
+        .table_level_rules
+        .iter()
+        .filter(|rule| matches!(rule, TableRule::PrimaryKey(_)))
+        .count();
+    if primary_keys > 1 {
+        return Err(DDLxParseError::TableValidationError(format!(
+            "Table {} has {} primary keys, only one is allowed",
+            table_def.table_ref, primary_keys
+        )));
+    }
+
+    for rule in &table_def.table_level_rules {
+        if let Err(err) = rule.validate_table(&table_def) {
+            return Err(DDLxParseError::TableValidationError(err.to_string()));
+        }
+    }
+
+    Ok(table_def)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::parse;
+    use crate::model::column_rule::ColumnRule;
+    use crate::model::data_class::DataClass;
+    use crate::model::table_rule::{PrimaryKey, TableRule};
+    use crate::parser::error_utils::DDLxParseError;
+    use crate::parser::{parse, parse_catalog};
     use rstest::rstest;
 
     #[test]
@@ -92,7 +307,366 @@ mod tests {
 
     #[rstest]
     #[case("CREATE TABLE test {id INT(3)., name VARCHAR(255)};")]
+    #[case("CREATE TABLE test {id INT(3) NULL NOT NULL};")]
+    #[case("CREATE TABLE test {id FOO(3)};")]
+    #[case("CREATE DOMAIN Code AS FOO;\nCREATE TABLE test {id Code};")]
+    #[case("CREATE TABLE test {id INT(3) PRIMARY KEY NULL};")]
+    #[case("CREATE TABLE test {price FLOAT(3,8)};")]
+    #[case("CREATE TABLE test {price DECIMAL(2, 3)};")]
     fn test_parse_error(#[case] input_string: &str) {
-        parse(input_string).is_err();
+        assert!(parse(input_string).is_err());
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE test {id INT(3), line INT(3), PRIMARY KEY (id, line)};")]
+    #[case(
+        "CREATE TABLE test {id INT(3), line INT(3), CONSTRAINT pk_test PRIMARY KEY (id, line)};"
+    )]
+    #[case(
+        "CREATE TABLE test {id INT(3), Constraint INT(3), CONSTRAINT uq UNIQUE (id, Constraint)};"
+    )]
+    #[case("CREATE TABLE test {Type INT(3), As INT(3)};\nALTER TABLE test ALTER COLUMN Type TYPE BIGINT(10), ADD UNIQUE (Type, As);")]
+    #[case("CREATE TABLE test {True BOOL {-DISTRIBUTION {true: 0.5..1}}, False INT(3) {-DISTRIBUTION {0: 0..1}}};")]
+    #[case(
+        "CREATE TABLE test {id INT(3) UNIQUE, line INT(3), CONSTRAINT uq_line UNIQUE (id, line)};"
+    )]
+    #[case("CREATE TABLE test {id INT(3), -ROWS BETWEEN 1000 AND 5_000_000, -ROWS >= 0.9 * previous_run};")]
+    #[case("CREATE TABLE test {id INT(3), PRIMARY KEY (id) @severity(error), -ROWS > 0 @severity(info)};")]
+    #[case("CREATE TABLE test {id INT(3)};\nALTER TABLE test ADD UNIQUE (id) @severity(error);")]
+    #[case("CREATE TABLE test {id INT(3) {-ANOMALY mean RATE_OF_CHANGE 0.2}, -ANOMALY row_count RATE_OF_CHANGE 0.1};")]
+    #[case("CREATE TABLE test {price INT(3), quantity INT(3), -CORRELATION(price, quantity) >= 0.7, -CORRELATION(price, quantity) BETWEEN 0 AND 0.9};")]
+    #[case(
+        "CREATE TABLE test {price INT(3), quantity INT(3), -CORRELATION(price, quantity) <= -0.5};"
+    )]
+    fn test_parse_table_constraints(#[case] input_string: &str) {
+        assert!(parse(input_string).is_ok());
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE test {id INT(3), PRIMARY KEY (id, line)};")]
+    #[case("CREATE TABLE test {id INT(3) PRIMARY KEY, line INT(3), PRIMARY KEY (id, line)};")]
+    #[case("CREATE TABLE test {id INT(3) PRIMARY KEY, line INT(3) PRIMARY KEY};")]
+    #[case("CREATE TABLE test {id INT(3), CONSTRAINT pk PRIMARY KEY (id, line)};")]
+    #[case("CREATE TABLE test {id INT(3), UNIQUE (name)};")]
+    #[case("CREATE TABLE test {id INT(3), -ROWS BETWEEN 10 AND 1};")]
+    #[case("CREATE TABLE test {id INT(3), -ROWS >= 0.9 * last_run};")]
+    #[case("CREATE TABLE test {id INT(3), -ROWS >= 99999999999999999999999};")]
+    #[case("CREATE TABLE test {id INT(3), -ROWS BETWEEN 1 AND 99_999_999_999_999_999_999};")]
+    #[case("CREATE TABLE test {id INT(3), -ANOMALY completeness RATE_OF_CHANGE 0.1};")]
+    #[case("CREATE TABLE test {price INT(3), name VARCHAR(3), -CORRELATION(price, name) >= 0.7};")]
+    #[case("CREATE TABLE test {price INT(3), -CORRELATION(price, quantity) >= 0.7};")]
+    #[case(
+        "CREATE TABLE test {price INT(3), quantity INT(3), -CORRELATION(price, quantity) >= 1.5};"
+    )]
+    #[case("CREATE TABLE test {name VARCHAR(3) {-ANOMALY mean RATE_OF_CHANGE 0.1}};")]
+    #[case("CREATE TABLE test {id INT(3) {-ANOMALY median RATE_OF_CHANGE 0.1}};")]
+    fn test_parse_table_constraints_error(#[case] input_string: &str) {
+        assert!(parse(input_string).is_err());
+    }
+
+    #[test]
+    fn test_parse_row_count_overflow_error() {
+        let err = parse_catalog("CREATE TABLE test {id INT(3), -ROWS >= 99999999999999999999999};")
+            .unwrap_err();
+        assert!(
+            matches!(&err, DDLxParseError::TableValidationError(message) if message == "Row count 99999999999999999999999 has to be at most 18446744073709551615"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_error_expected_keyword() {
+        let err = parse_catalog(
+            "CREATE TABLE test {id INT(3)};\nALTER TABLE test ALTER COLUMN id INT(3);",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("\"TYPE\""), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_column_primary_key() {
+        let table = parse("CREATE TABLE test {id INT(3) PRIMARY KEY, name VARCHAR(255)};").unwrap();
+        assert_eq!(
+            table.table_level_rules,
+            vec![TableRule::PrimaryKey(PrimaryKey::new(
+                None,
+                vec!["id".to_owned()],
+                None
+            ))]
+        );
+        assert!(!table.columns[0]
+            .rules
+            .iter()
+            .flat_map(|rule_filter| &rule_filter.rules)
+            .any(|rule| matches!(rule, ColumnRule::Uniqueness(_))));
+    }
+
+    #[test]
+    fn test_parse_catalog() {
+        let catalog = parse_catalog(
+            "CREATE TABLE test {id INT(3)};\nCREATE TABLE other.test {name VARCHAR(255) {-not_empty}};",
+        )
+        .unwrap();
+        assert_eq!(catalog.tables.len(), 2);
+        assert_eq!(catalog.tables[1].table_ref.to_string(), "other.test");
+    }
+
+    #[test]
+    fn test_parse_catalog_alter_table() {
+        let catalog = parse_catalog(
+            "CREATE TABLE test {id INT(3), name VARCHAR(255), price DOUBLE(10,2)};
+            ALTER TABLE test ADD COLUMN line INT(3) NOT NULL, DROP COLUMN price;
+            ALTER TABLE test ALTER COLUMN id TYPE BIGINT(20), ADD PRIMARY KEY (id, line);
+            ALTER TABLE test ADD RULE name {-NOT_EMPTY};",
+        )
+        .unwrap();
+        let table = &catalog.tables[0];
+        let columns = table
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(columns, vec!["id", "name", "line"]);
+        assert_eq!(table.columns[0].data_type.class, DataClass::BigInt);
+        assert!(table.columns[0].not_null);
+        assert_eq!(table.table_level_rules.len(), 1);
+        assert_eq!(table.columns[1].rules.len(), 1);
+        assert_eq!(table.columns[1].rules[0].rules.len(), 3);
+    }
+
+    #[rstest]
+    #[case("ALTER TABLE test ADD COLUMN id INT(3);")]
+    #[case("CREATE TABLE test {id INT(3)};\nALTER TABLE test ADD COLUMN id INT(3);")]
+    #[case("CREATE TABLE test {id INT(3)};\nALTER TABLE test DROP COLUMN name;")]
+    #[case("CREATE TABLE test {id INT(3), PRIMARY KEY (id)};\nALTER TABLE test DROP COLUMN id;")]
+    #[case("CREATE TABLE test {name VARCHAR(3) {-NOT_EMPTY}};\nALTER TABLE test ALTER COLUMN name TYPE INT(3);")]
+    #[case("CREATE TABLE test {id INT(3)};\nALTER TABLE test ADD RULE id {-NOT_EMPTY};")]
+    fn test_parse_catalog_alter_table_error(#[case] input_string: &str) {
+        assert!(parse_catalog(input_string).is_err());
+    }
+
+    #[test]
+    fn test_parse_catalog_alter_table_unknown_type() {
+        let err =
+            parse_catalog("CREATE TABLE t {a INT(3)};\nALTER TABLE t ALTER COLUMN a TYPE FOO;")
+                .unwrap_err();
+        assert!(
+            matches!(&err, DDLxParseError::SchemaEvolutionError(message) if message == "Cannot alter column a: Unknown data type FOO"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_catalog_pragmas() {
+        let catalog = parse_catalog(
+            "#pragma implicit_type_checks = off;
+            CREATE TABLE test {#pragma implicit_uniqueness = sampled, id INT(3) NOT NULL UNIQUE};
+            #pragma implicit_not_null = off
+            CREATE TABLE other {name VARCHAR(3) NOT NULL {-NOT_EMPTY}};",
+        )
+        .unwrap();
+
+        let rules = &catalog.tables[0].columns[0].rules[0].rules;
+        assert_eq!(rules.len(), 2);
+        assert!(matches!(rules[0], ColumnRule::NonNull(_)));
+        assert!(rules[1].is_sampled());
+
+        // explicit rules are kept when the implicit rules are turned off
+        let rules = &catalog.tables[1].columns[0].rules[0].rules;
+        assert_eq!(rules.len(), 1);
+        assert!(matches!(rules[0], ColumnRule::NotEmpty(_)));
+    }
+
+    #[rstest]
+    #[case("#pragma implicit_checks = off\nCREATE TABLE test {id INT(3)};")]
+    #[case("CREATE TABLE test {#pragma implicit_type_checks = sampled, id INT(3)};")]
+    fn test_parse_catalog_pragma_error(#[case] input_string: &str) {
+        assert!(parse_catalog(input_string).is_err());
+    }
+
+    #[rstest]
+    #[case(
+        "CREATE TABLE test {a INT(3), b INT(3) {-SQL \"count(*) filter (where a > b) = 0\"}};",
+        true
+    )]
+    #[case(
+        "CREATE TABLE test {a INT(3), b INT(3), -SQL \"count(*) filter (where a > b) = 0\"};",
+        true
+    )]
+    #[case(
+        "CREATE TABLE test {a INT(3), b INT(3) {-SQL \"count(*) filter (where a > c) = 0\"}};",
+        false
+    )]
+    #[case("CREATE TABLE test {a INT(3), b INT(3), -SQL \"max(a)\"};", false)]
+    #[case(
+        "CREATE TABLE test {a VARCHAR(5), -SQL \"count(*) filter (where a = 'open') = 0\"};",
+        true
+    )]
+    #[case(
+        "CREATE TABLE test {a VARCHAR(5) {-SQL 'count(*) filter (where a = \"open\") = 0'}};",
+        false
+    )]
+    #[case("CREATE TABLE test {a VARCHAR(5) {-LIKE \"it's\"}};", false)]
+    fn test_parse_catalog_custom_sql(#[case] input_string: &str, #[case] is_valid: bool) {
+        assert_eq!(parse_catalog(input_string).is_ok(), is_valid);
+    }
+
+    #[rstest]
+    #[case(
+        "CREATE FUNCTION checks.luhn(VARCHAR, INT) RETURNS BOOL;
+        CREATE TABLE test {iban VARCHAR(34) {-UDF checks.luhn(10) 0.9}};",
+        true
+    )]
+    #[case(
+        "CREATE FUNCTION checks.luhn(VARCHAR, INT) RETURNS BOOL;
+        RULESET iban {-UDF checks.luhn(10)};
+        CREATE TABLE test {iban VARCHAR(34) {-USE iban}};",
+        true
+    )]
+    #[case("CREATE TABLE test {iban VARCHAR(34) {-UDF checks.luhn(10)}};", false)]
+    #[case(
+        "CREATE FUNCTION checks.luhn(VARCHAR, INT) RETURNS BOOL;
+        CREATE TABLE test {iban INT(10) {-UDF checks.luhn(10)}};",
+        false
+    )]
+    #[case(
+        "CREATE FUNCTION checks.luhn(VARCHAR, INT) RETURNS BOOL;
+        CREATE TABLE test {iban VARCHAR(34) {-UDF checks.luhn(\"10\")}};",
+        false
+    )]
+    #[case(
+        "CREATE FUNCTION checks.luhn(VARCHAR) RETURNS INT;
+        CREATE TABLE test {iban VARCHAR(34) {-UDF checks.luhn()}};",
+        false
+    )]
+    #[case("CREATE FUNCTION checks.luhn(MONEY) RETURNS BOOL;", false)]
+    fn test_parse_catalog_user_function(#[case] input_string: &str, #[case] is_valid: bool) {
+        assert_eq!(parse_catalog(input_string).is_ok(), is_valid);
+    }
+
+    #[rstest]
+    #[case(
+        "CREATE TABLE test {channel VARCHAR(10) {-DISTRIBUTION { 'web': 0.4..0.6, 'app': 30%..50% }, -NOT_EMPTY}};",
+        true
+    )]
+    #[case("CREATE TABLE test {status INT(3) {-DISTRIBUTION MAX 80%}};", true)]
+    #[case(
+        "CREATE TABLE test {active BOOL {-DISTRIBUTION {true: 0.5..1}}};",
+        true
+    )]
+    #[case(
+        "CREATE TABLE test {channel VARCHAR(10) {-DISTRIBUTION {'web': 0.6..1, 'app': 0.5..1}}};",
+        false
+    )]
+    #[case(
+        "CREATE TABLE test {channel VARCHAR(10) {-DISTRIBUTION {1: 0.1..0.2}}};",
+        false
+    )]
+    #[case(
+        "CREATE TABLE test {price DECIMAL(10, 2) {-DISTRIBUTION MAX 0.8}};",
+        false
+    )]
+    #[case(
+        "CREATE TABLE test {channel VARCHAR(10) {-DISTRIBUTION {'web': 0.4}}};",
+        false
+    )]
+    fn test_parse_catalog_distribution(#[case] input_string: &str, #[case] is_valid: bool) {
+        assert_eq!(parse_catalog(input_string).is_ok(), is_valid);
+    }
+
+    #[rstest]
+    #[case(
+        "CREATE TABLE test {a VARCHAR(5) {-MIN_LENGTH 2, -MAX_LENGTH 4}};",
+        true
+    )]
+    #[case("CREATE TABLE test {a VARCHAR(5) {-MIN_LENGTH 5}};", true)]
+    #[case("CREATE TABLE test {a VARCHAR(3) {-MIN_LENGTH 5}};", false)]
+    #[case(
+        "CREATE TABLE test {a VARCHAR(10) {-MIN_LENGTH 5, -MAX_LENGTH 3}};",
+        false
+    )]
+    #[case(
+        "CREATE TABLE test {a VARCHAR(10) {-MAX_LENGTH 3} {-MIN_LENGTH 5 | a > 1}};",
+        false
+    )]
+    #[case(
+        "CREATE TABLE test {a VARCHAR(10) {-MIN_LENGTH 5} {-MAX_LENGTH 3 | a > 1}};",
+        false
+    )]
+    #[case("CREATE TABLE test {a VARCHAR(10) {-MIN_LENGTH 99999999999}};", false)]
+    #[case("CREATE TABLE test {a VARCHAR(10) {-MAX_LENGTH 99999999999}};", false)]
+    #[case("CREATE TABLE test {a VARCHAR(99999999999)};", false)]
+    fn test_parse_catalog_length(#[case] input_string: &str, #[case] is_valid: bool) {
+        assert_eq!(parse_catalog(input_string).is_ok(), is_valid);
+    }
+
+    #[test]
+    fn test_parse_catalog_length_overflow() {
+        let error = parse_catalog("CREATE TABLE test {a VARCHAR(10) {-MIN_LENGTH 99999999999}};")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Number 99999999999 has to be at most 4294967295"
+        );
+    }
+
+    #[test]
+    fn test_parse_catalog_view() {
+        let catalog = parse_catalog(
+            "CREATE TABLE shop.orders {id INT(3), price DOUBLE(10,2), quantity INT(3)};
+            CREATE VIEW order_totals {id INT(3), total DOUBLE(10,2)} AS
+                SELECT id, price * quantity AS total FROM shop.orders WHERE quantity > 0;
+            CREATE VIEW big_orders AS SELECT * FROM order_totals WHERE total > 100;",
+        )
+        .unwrap();
+
+        assert_eq!(catalog.views.len(), 2);
+        assert_eq!(catalog.views[1].columns.len(), 2);
+        assert_eq!(catalog.views[0].columns[1].lineage.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_catalog_lineage_warnings() {
+        let catalog = parse_catalog(
+            "CREATE TABLE s {id INT(3) NOT NULL};\nCREATE TABLE t {id INT(3) LINEAGE FROM s.id};",
+        )
+        .unwrap();
+        assert_eq!(catalog.lineage_warnings.len(), 1);
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE t {id INT(3) LINEAGE FROM crm.customers.id};")]
+    #[case("CREATE TABLE s {id INT(3)};\nCREATE TABLE t {id INT(3) LINEAGE FROM s.name};")]
+    #[case("CREATE TABLE s {id INT(3)};\nCREATE TABLE t {id VARCHAR(3) LINEAGE FROM s.id};")]
+    fn test_parse_catalog_lineage_error(#[case] input_string: &str) {
+        assert!(parse_catalog(input_string).is_err());
+    }
+
+    #[rstest]
+    #[case("CREATE VIEW v AS SELECT id FROM missing;")]
+    #[case("CREATE TABLE t {id INT(3)};\nCREATE VIEW v AS SELECT name FROM t;")]
+    #[case("CREATE TABLE t {id INT(3)};\nCREATE VIEW v {id VARCHAR(3)} AS SELECT id FROM t;")]
+    #[case("CREATE TABLE t {id INT(3)};\nCREATE VIEW v AS SELECT id FROM t WHERE;")]
+    fn test_parse_catalog_view_error(#[case] input_string: &str) {
+        assert!(parse_catalog(input_string).is_err());
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE t {id INT(3)};\nCREATE TABLE t {id INT(3)};")]
+    #[case("CREATE TABLE t {id INT(3)};\nCREATE VIEW t AS SELECT id FROM t;")]
+    #[case("IMPORT \"other.ddlx\";\nCREATE TABLE t {id INT(3)};")]
+    fn test_parse_catalog_symbol_error(#[case] input_string: &str) {
+        assert!(parse_catalog(input_string).is_err());
+    }
+
+    #[test]
+    fn test_parse_catalog_validation_error() {
+        let catalog = parse_catalog(
+            "CREATE TABLE test {id INT(3)};\nCREATE TABLE other {id INT(3) {-not_empty}};",
+        );
+        assert!(catalog.is_err());
     }
 }
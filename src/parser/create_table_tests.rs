@@ -4,67 +4,74 @@ use lalrpop_util::lalrpop_mod;
 use rstest::rstest;
 
 use crate::model::column_rule::{
-    ColumnRule, ContainsValue, IsType, LikePattern, NonNull, NotEmpty, RegexPattern, Uniqueness,
+    ColumnRule, ColumnSequence, ColumnStatistic, ContainsValue, IntegerRange, IsType, LikePattern,
+    MaxLength, MinLength, NonNull, NotEmpty, RegexPattern, SemanticValidator, Uniqueness,
 };
 use crate::model::data_class::DataClass;
-use crate::model::rule_ext_config::RuleExtConfig;
+use crate::model::rule_ext_config::{RuleExtConfig, Severity};
 use crate::model::rule_filter::filter::ColumnRuleFilter;
+use crate::model::semantic_format::SemanticFormat;
+use crate::model::sequence::Sequence;
+use crate::model::statistic::Statistic;
 use crate::model::table_expr::DataType;
 use crate::model::table_expr::{ColumnDef, TableRef};
+use crate::model::threshold::{Threshold, ThresholdOperator};
 
-lalrpop_mod!(pub table, "/parser/create_table.rs");
+lalrpop_mod!(#[allow(clippy::all)] pub table, "/parser/create_table.rs");
 
 #[rstest]
 #[case("CREATE TABLE IF NOT EXISTS Inventory {Id INT(10),Title VARCHAR(3),  };",
 TableRef::new("Inventory", None, None),
 vec![
     ColumnDef {name: String::from("Id"), data_type: DataType::new("INT", Some(10), None), rules:
-    vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::Int, size: Some([Some(10), None]) }, ..Default::default()})])],
+    vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+    class: DataClass::Int, size: Some([Some(10), None]) }, ..Default::default()}),
+    ColumnRule::IntegerRange(IntegerRange::new(None, i32::MIN.into(), i32::MAX.into(), None))])],
     ..Default::default()},
     ColumnDef {name: String::from("Title"), data_type: DataType::new("VARCHAR", Some(3), None), rules:
-    vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(3), None]) }, ..Default::default()})])],
+    vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+    class: DataClass::VarChar, size: Some([Some(3), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 3, None))])],
     ..Default::default()},
 ])]
 #[case("CREATE TABLE IF NOT EXISTS\n Test {Id FLOAT(10)};", "Test",
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(10), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(10), None]) }, ..Default::default()})])],
 ..Default::default()},
 ])]
 #[case(" CREATE TABLE IF NOT EXISTS\n Test \n{\nId FLOAT(2)\n,}\n;\n", "Test",
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(2), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(2), None]) }, ..Default::default()})])], ..Default::default()},
 ])]
 #[case(" create table if not exists\n Test \n{\nId FLOAT(3)\n,}\n;\n", "Test",
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(3), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(3), None]) }, ..Default::default()})])], ..Default::default()},
 ])]
 #[case(" create table if not exists\n Schema.Test \n{\nId FLOAT(1)\n,}\n;\n",
 TableRef::new("Test", Some("Schema"), None),
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(1), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(1), None]) }, ..Default::default()})])], ..Default::default()},
 ])]
 #[case(" create table if not exists\n Schema.Test \'jlk asdf19(**\' \n{\nId FLOAT(3)\n,}\n;\n",
 TableRef::new("Test", Some("Schema"), Some("jlk asdf19(**")),
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(3), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(3), None]) }, ..Default::default()})])],..Default::default()},
 ])]
 #[case(" create table if not exists\n Schema.Test \"jlk asdf19(**\" \n{\nId FLOAT(9)\n,}\n;\n",
 TableRef::new("Test", Some("Schema"), Some("jlk asdf19(**")),
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(9), None), rules: 
-vec![ColumnRuleFilter::new(None, vec![
+vec![ColumnRuleFilter::implicit(vec![
 ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(9), None]) }, ..Default::default()})
 ])], ..Default::default()},
@@ -73,37 +80,37 @@ class: DataClass::Float, size: Some([Some(9), None]) }, ..Default::default()})
 TableRef::new("Test", None, None),
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(2), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(2), None]) }, ..Default::default()})])], ..Default::default()},
 ])]
 #[case(" CREATE TABLE IF NOT EXISTS\n Test33 \n{\nId FLOAT(2),\nPrice FLOAT(3),\nNotes TEXT(10)\n}\n;\n",
 TableRef::new("Test33", None, None),
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(2), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(2), None]) }, ..Default::default()})])],..Default::default()},
 ColumnDef {name: String::from("Price"), data_type: DataType::new("FLOAT", Some(3), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(3), None]) }, ..Default::default()})])], ..Default::default()},
 ColumnDef {name: String::from("Notes"), data_type: DataType::new("TEXT", Some(10), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Text, size: Some([Some(10), None]) }, ..Default::default()})])], ..Default::default()},
 ])]
 #[case(" create table\n Test33 \n{\nId FLOAT(1) PRIMARY KEY,\nPrice FLOAT(2),\nNotes TEXT(10) not null\n}\n;\n",
 TableRef::new("Test33", None, None),
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(1), None), primary_key: true, not_null: true, rules: 
-vec![ColumnRuleFilter::new(None, vec![
-ColumnRule::NonNull(NonNull::new(None, None, None)), ColumnRule::Uniqueness(Uniqueness::new(None, None)),
+vec![ColumnRuleFilter::implicit(vec![
+ColumnRule::NonNull(NonNull::new(None, None, None)),
 ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(1), None]) }, ..Default::default()})])], ..Default::default()},
 
 ColumnDef {name: String::from("Price"), data_type: DataType::new("FLOAT", Some(2), None), rules:
-vec![ColumnRuleFilter::new(None, vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+vec![ColumnRuleFilter::implicit(vec![ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(2), None]) }, ..Default::default()})])], ..Default::default()},
 
 ColumnDef {name: String::from("Notes"), data_type: DataType::new("TEXT", Some(10), None), not_null: true, rules: 
-vec![ColumnRuleFilter::new(None, vec![
+vec![ColumnRuleFilter::implicit(vec![
 ColumnRule::NonNull(NonNull::new(None, None, None)),
 ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Text, size: Some([Some(10), None]) }, ..Default::default()})])],..Default::default()},
@@ -112,7 +119,7 @@ class: DataClass::Text, size: Some([Some(10), None]) }, ..Default::default()})])
 TableRef::new("Test", None, None),
 vec![
 ColumnDef {name: String::from("Id"), data_type: DataType::new("FLOAT", Some(100), None), rules: 
-vec![ColumnRuleFilter::new(None, vec![
+vec![ColumnRuleFilter::implicit(vec![
 ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
 class: DataClass::Float, size: Some([Some(100), None]) }, ..Default::default()})]),
 ColumnRuleFilter::new(None, vec![
@@ -196,9 +203,9 @@ fn test_create_table_failure(#[case] input_value: &str) {
     false
 )]
 #[case(
-    " Id33 DOUBLE(1,2) not null",
+    " Id33 DOUBLE(2,1) not null",
     "Id33",
-    DataType::new("DOUBLE", Some(1), Some(2)),
+    DataType::new("DOUBLE", Some(2), Some(1)),
     true,
     false
 )]
@@ -243,6 +250,42 @@ fn test_column_def_success(
     assert_eq!(column_def.primary_key, primary_key);
 }
 
+#[rstest]
+#[case("Id VARCHAR(20) NULL", None, false, None)]
+#[case("Quantity INT(3) NOT NULL DEFAULT 0", Some("0"), false, None)]
+#[case("Price DOUBLE(10,2) DEFAULT -1.5", Some("-1.5"), false, None)]
+#[case("Status VARCHAR(10) DEFAULT 'open'", Some("'open'"), false, None)]
+#[case(
+    "CreatedBy VARCHAR(30) default CURRENT_USER",
+    Some("CURRENT_USER"),
+    false,
+    None
+)]
+#[case("Code INT(3) DEFAULT NULL", Some("NULL"), false, None)]
+#[case("Id BIGINT(20) PRIMARY KEY AUTO_INCREMENT", None, true, None)]
+#[case(
+    "Total DOUBLE(10,2) GENERATED ALWAYS AS (round(Price * Quantity, 2)) STORED",
+    None,
+    false,
+    Some("round(Price * Quantity, 2)")
+)]
+fn test_column_def_options(
+    #[case] input_value: &str,
+    #[case] default: Option<&str>,
+    #[case] auto_increment: bool,
+    #[case] generated: Option<&str>,
+) {
+    let parsed_result = table::ColumnDefExprParser::new().parse(input_value);
+
+    assert!(parsed_result.is_ok(), "{:?}", parsed_result.err());
+
+    let column_def = parsed_result.unwrap();
+
+    assert_eq!(column_def.default.as_deref(), default);
+    assert_eq!(column_def.auto_increment, auto_increment);
+    assert_eq!(column_def.generated.as_deref(), generated);
+}
+
 #[rstest]
 #[case("'22")]
 #[case("'22''")]
@@ -250,7 +293,8 @@ fn test_column_def_success(
 #[case("Id, INT")]
 #[case("Id INT PRIMARY")]
 #[case("Id INT NOT")]
-#[case("Id TEXT NULL")]
+#[case("Id INT(3) DEFAULT")]
+#[case("Id INT(3) GENERATED ALWAYS AS Id + 1")]
 #[case("ISBN VARCHAR(20) { -LIKE \"%test%\" ")]
 #[case("ISBN VARCHAR(20) { LIKE \"%test%\" }")]
 fn test_column_def_failure(#[case] input_value: &str) {
@@ -265,93 +309,115 @@ fn test_column_def_failure(#[case] input_value: &str) {
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: false,
     primary_key: false,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()})]),
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
     ColumnRuleFilter::new(None, vec![
     ColumnRule::RegexPattern(RegexPattern {name: String::new(), rule_ext_config: RuleExtConfig::new_empty(),
-    pattern: "^(?=(?:\\D*\\d){10}(?:(?:\\D*\\d){3})?$)[\\d-]+$".to_owned(), ..Default::default()})])]
+    pattern: "^(?=(?:\\D*\\d){10}(?:(?:\\D*\\d){3})?$)[\\d-]+$".to_owned(), ..Default::default()})])], ..Default::default()
 })]
 #[case("ISBN VARCHAR(20) { -LIKE \"%test%\" }", ColumnDef {
     name: String::from("ISBN"),
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: false,
     primary_key: false,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()})]),
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
     ColumnRuleFilter::new(None, vec![
     ColumnRule::LikePattern(LikePattern  {name: String::new(), rule_ext_config: RuleExtConfig::new_empty(),
-    pattern: "%test%".to_owned(), ..Default::default()})])]
+    pattern: "%test%".to_owned(), ..Default::default()})])], ..Default::default()
 })]
 #[case("ISBN VARCHAR(20) { -CONTAINS \"test\" }", ColumnDef {
     name: String::from("ISBN"),
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: false,
     primary_key: false,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()})]),
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
     ColumnRuleFilter::new(None, vec![
     ColumnRule::ContainsValue(ContainsValue  {name: String::new(), rule_ext_config: RuleExtConfig::new_empty(),
-    value: "test".to_owned(), ..Default::default()})])]
+    value: "test".to_owned(), ..Default::default()})])], ..Default::default()
 })]
 #[case("ISBN VARCHAR(20) { -CONTAINS \"test\" 0.01 }", ColumnDef {
     name: String::from("ISBN"),
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: false,
     primary_key: false,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()})]),
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
     ColumnRuleFilter::new(None, vec![
     ColumnRule::ContainsValue(ContainsValue  {name: String::new(), rule_ext_config: RuleExtConfig::new_empty(),
-    threshold: 0.01, value: "test".to_owned(), ..Default::default()})])]
+    threshold: 0.01.into(), value: "test".to_owned()})])], ..Default::default()
 })]
 #[case("ISBN VARCHAR(20) { -CONTAINS \"test\" 1. }", ColumnDef {
     name: String::from("ISBN"),
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: false,
     primary_key: false,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()})]),
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
     ColumnRuleFilter::new(None, vec![
     ColumnRule::ContainsValue(ContainsValue  {name: String::new(), rule_ext_config: RuleExtConfig::new_empty(),
-    value: "test".to_owned(), ..Default::default()})])]
+    value: "test".to_owned(), ..Default::default()})])], ..Default::default()
 })]
 #[case("ISBN VARCHAR(20) PRIMARY KEY ", ColumnDef {
     name: String::from("ISBN"),
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: true,
     primary_key: true,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::NonNull(NonNull::new(None, None, None)),
     ColumnRule::Uniqueness(Uniqueness::new(None, None)),
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),])]
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None)),])], ..Default::default()
 })]
 #[case("ISBN VARCHAR(20) { -unique} ", ColumnDef {
     name: String::from("ISBN"),
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: false,
     primary_key: false,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()})]),
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
     ColumnRuleFilter::new(None, vec![
-    ColumnRule::Uniqueness(Uniqueness::new(None, None)),])]
+    ColumnRule::Uniqueness(Uniqueness::new(None, None)),])], ..Default::default()
 })]
 #[case("ISBN VARCHAR(20) { -not_empty} ", ColumnDef {
     name: String::from("ISBN"),
     data_type: DataType::new("VARCHAR", Some(20), None),
     not_null: false,
     primary_key: false,
-    rules: vec![ColumnRuleFilter::new(None, vec![
+    rules: vec![ColumnRuleFilter::implicit(vec![
+    ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
+    ColumnRuleFilter::new(None, vec![
+    ColumnRule::NotEmpty(NotEmpty::new(None, None, None)),])], ..Default::default()
+})]
+#[case("Contact VARCHAR(20) { -EMAIL 0.9, -iso_country | Contact != 'x',} ", ColumnDef {
+    name: String::from("Contact"),
+    data_type: DataType::new("VARCHAR", Some(20), None),
+    not_null: false,
+    primary_key: false,
+    rules: vec![ColumnRuleFilter::implicit(vec![
     ColumnRule::IsType(IsType {  name: "".to_owned(), data_type: DataType {
-    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()})]),
+    class: DataClass::VarChar, size: Some([Some(20), None]) }, ..Default::default()}),
+    ColumnRule::MaxLength(MaxLength::new(None, 20, None))]),
     ColumnRuleFilter::new(None, vec![
-    ColumnRule::NotEmpty(NotEmpty::new(None, None, None)),])]
+    ColumnRule::SemanticValidator(SemanticValidator::new(None, SemanticFormat::Email, None, Some(0.9.into()))),]),
+    ColumnRuleFilter::new(Some(" Contact != 'x'".to_owned()), vec![
+    ColumnRule::SemanticValidator(SemanticValidator::new(None, SemanticFormat::IsoCountry, None, None)),])], ..Default::default()
 })]
 fn test_column_with_rule_expr_success(
     #[case] input_value: &str,
@@ -369,3 +435,230 @@ fn test_column_with_rule_expr_success(
     assert_eq!(column_def.not_null, desired_column.not_null);
     assert_eq!(column_def.primary_key, desired_column.primary_key);
 }
+
+#[rstest]
+#[case("Code VARCHAR(20) { -NOT_EMPTY 0.9 }", Threshold::from(0.9))]
+#[case("Code VARCHAR(20) { -NOT_EMPTY >= 95% }", Threshold::from(0.95))]
+#[case(
+    "Code VARCHAR(20) { -NOT_EMPTY < 0.01 }",
+    Threshold::Compare(ThresholdOperator::Less, 0.01)
+)]
+#[case(
+    "Code VARCHAR(20) { -NOT_EMPTY = 50% }",
+    Threshold::Compare(ThresholdOperator::Equal, 0.5)
+)]
+#[case(
+    "Code VARCHAR(20) { -NOT_EMPTY between 0.9 and 99% }",
+    Threshold::Between(0.9, 0.99)
+)]
+#[case(
+    "Code VARCHAR(20) { -LIKE \"a%\" BETWEEN 90% AND 1.0 | Code != 'x',}",
+    Threshold::Between(0.9, 1.0)
+)]
+#[case(
+    "Code VARCHAR(20) { -NOT_EMPTY BETWEEN 0 AND 1. }",
+    Threshold::Between(0.0, 1.0)
+)]
+fn test_column_rule_threshold(#[case] input_value: &str, #[case] expected: Threshold) {
+    let column_def = table::ColumnWithRulesExprParser::new()
+        .parse(input_value)
+        .unwrap();
+    let threshold = match &column_def.rules[1].rules[0] {
+        ColumnRule::NotEmpty(rule) => rule.threshold,
+        ColumnRule::LikePattern(rule) => rule.threshold,
+        rule => panic!("unexpected rule {:?}", rule),
+    };
+    assert_eq!(threshold, expected);
+}
+
+#[rstest]
+#[case("CREATE TABLE t {code VARCHAR(20) {-NOT_EMPTY >= 150%}};")]
+#[case("CREATE TABLE t {code VARCHAR(20) {-NOT_EMPTY between 0.99 and 0.9}};")]
+#[case("CREATE TABLE t {code VARCHAR(20) {-NOT_EMPTY 2.5}};")]
+#[case("CREATE TABLE t {code VARCHAR(20) {-NULL_RATIO <= 5}};")]
+fn test_column_rule_threshold_out_of_range(#[case] input_value: &str) {
+    assert!(crate::parser::parse_catalog(input_value).is_err());
+}
+
+#[test]
+fn test_keywords_as_column_names() {
+    let catalog = crate::parser::parse_catalog(
+        "CREATE TABLE t {between INT(3), And VARCHAR(20), error INT(3), Info VARCHAR(20)};",
+    )
+    .unwrap();
+    let columns = &catalog.tables[0].columns;
+    assert_eq!(columns[0].name, "between");
+    assert_eq!(columns[1].name, "And");
+    assert_eq!(columns[2].name, "error");
+    assert_eq!(columns[3].name, "Info");
+}
+
+#[rstest]
+#[case("Code VARCHAR(20) { -NOT_EMPTY }", None)]
+#[case(
+    "Code VARCHAR(20) { -NOT_EMPTY 0.9 @severity(error) }",
+    Some(Severity::Error)
+)]
+#[case(
+    "Code VARCHAR(20) { -UNIQUE @SEVERITY(Info) | Code != 'x',}",
+    Some(Severity::Info)
+)]
+#[case(
+    "Code VARCHAR(20) { -UNIQUE @severity ( WARNING ) }",
+    Some(Severity::Warning)
+)]
+fn test_column_rule_severity(#[case] input_value: &str, #[case] expected: Option<Severity>) {
+    let column_def = table::ColumnWithRulesExprParser::new()
+        .parse(input_value)
+        .unwrap();
+    let rule = &column_def.rules[1].rules[0];
+    assert_eq!(rule.rule_ext_config().severity(), expected);
+}
+
+#[rstest]
+#[case("CREATE TABLE t {Id INT(10)};", Severity::Warning)]
+#[case("CREATE TABLE t @severity(error) {Id INT(10)};", Severity::Error)]
+#[case(
+    "CREATE TABLE s.t 'alias' @severity(info) {Id INT(10)};",
+    Severity::Info
+)]
+fn test_table_severity(#[case] input_value: &str, #[case] expected: Severity) {
+    let table_def = table::CreateTableExprParser::new()
+        .parse(input_value)
+        .unwrap();
+    assert_eq!(table_def.severity, expected);
+}
+
+#[rstest]
+#[case("CREATE TABLE t @severity(fatal) {Id INT(10)};")]
+#[case("CREATE TABLE t @severity() {Id INT(10)};")]
+#[case("CREATE TABLE t {Id INT(10) {-UNIQUE @severity error}};")]
+fn test_severity_error(#[case] input_value: &str) {
+    assert!(crate::parser::parse_catalog(input_value).is_err());
+}
+
+#[rstest]
+#[case(
+    "Code VARCHAR(20) { -NULL_RATIO <= 0.05 }",
+    Statistic::NullRatio,
+    Threshold::Compare(ThresholdOperator::LessEqual, 0.05)
+)]
+#[case(
+    "Code VARCHAR(20) { -distinctness >= 80% }",
+    Statistic::Distinctness,
+    Threshold::from(0.8)
+)]
+#[case(
+    "Code VARCHAR(20) { -NULL_RATIO = 0 }",
+    Statistic::NullRatio,
+    Threshold::Compare(ThresholdOperator::Equal, 0.0)
+)]
+#[case(
+    "Code VARCHAR(20) { -ENTROPY > 2.5 }",
+    Statistic::Entropy,
+    Threshold::Compare(ThresholdOperator::Greater, 2.5)
+)]
+#[case(
+    "Code VARCHAR(20) { -APPROX_DISTINCT between 10 and 20 | Code != 'x',}",
+    Statistic::ApproxDistinct,
+    Threshold::Between(10.0, 20.0)
+)]
+#[case(
+    "Code VARCHAR(20) { -APPROX_DISTINCT >= 5000000000 }",
+    Statistic::ApproxDistinct,
+    Threshold::from(5_000_000_000.0)
+)]
+#[case(
+    "Code VARCHAR(20) { -APPROX_DISTINCT = 16777217 }",
+    Statistic::ApproxDistinct,
+    Threshold::Compare(ThresholdOperator::Equal, 16_777_217.0)
+)]
+fn test_column_statistic(
+    #[case] input_value: &str,
+    #[case] statistic: Statistic,
+    #[case] threshold: Threshold,
+) {
+    let column_def = table::ColumnWithRulesExprParser::new()
+        .parse(input_value)
+        .unwrap();
+    assert_eq!(
+        column_def.rules[1].rules[0],
+        ColumnRule::ColumnStatistic(ColumnStatistic::new(None, statistic, None, threshold))
+    );
+}
+
+#[test]
+fn test_column_statistic_overflow() {
+    let input = format!(
+        "CREATE TABLE t {{Code VARCHAR(20) {{ -APPROX_DISTINCT >= {} }}}};",
+        "9".repeat(400)
+    );
+    let error = crate::parser::parse_catalog(&input).unwrap_err();
+    assert!(
+        error.to_string().contains("cannot be represented"),
+        "{}",
+        error
+    );
+}
+
+#[rstest]
+#[case(
+    "Code VARCHAR(20) { -MIN_LENGTH 3 }",
+    ColumnRule::MinLength(MinLength::new(None, 3, None))
+)]
+#[case(
+    "Code VARCHAR(20) { -max_length 10 }",
+    ColumnRule::MaxLength(MaxLength::new(None, 10, None))
+)]
+fn test_column_length(#[case] input_value: &str, #[case] rule: ColumnRule) {
+    let column_def = table::ColumnWithRulesExprParser::new()
+        .parse(input_value)
+        .unwrap();
+    assert_eq!(
+        column_def.rules[0].rules[1],
+        ColumnRule::MaxLength(MaxLength::new(None, 20, None)).implicit()
+    );
+    assert_eq!(column_def.rules[1].rules[0], rule);
+}
+
+#[rstest]
+#[case("CREATE TABLE test {Code INT(3) { -MIN_LENGTH 3 }};")]
+#[case("CREATE TABLE test {Code DATE { -MAX_LENGTH 10 }};")]
+fn test_column_length_invalid_type(#[case] input_value: &str) {
+    assert!(crate::parser::parse_catalog(input_value).is_err());
+}
+
+#[rstest]
+#[case("Id BIGINT(20) { -INCREASING }", Sequence::Increasing, None)]
+#[case(
+    "Id BIGINT(20) { -strictly_increasing ORDER BY CreatedAt }",
+    Sequence::StrictlyIncreasing,
+    Some("CreatedAt")
+)]
+#[case("Day DATE { -NO_GAPS order by Id }", Sequence::NoGaps, Some("Id"))]
+fn test_column_sequence(
+    #[case] input_value: &str,
+    #[case] sequence: Sequence,
+    #[case] order_by: Option<&str>,
+) {
+    let column_def = table::ColumnWithRulesExprParser::new()
+        .parse(input_value)
+        .unwrap();
+    assert_eq!(
+        column_def.rules.last().unwrap().rules[0],
+        ColumnRule::ColumnSequence(ColumnSequence::new(
+            None,
+            sequence,
+            order_by.map(str::to_owned),
+            None
+        ))
+    );
+}
+
+#[rstest]
+#[case("CREATE TABLE test {Code VARCHAR(3) { -INCREASING }};")]
+#[case("CREATE TABLE test {Price DOUBLE(10,2) { -NO_GAPS }};")]
+#[case("CREATE TABLE test {Id INT(3) { -INCREASING ORDER BY CreatedAt }};")]
+fn test_column_sequence_invalid(#[case] input_value: &str) {
+    assert!(crate::parser::parse_catalog(input_value).is_err());
+}
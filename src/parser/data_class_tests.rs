@@ -5,7 +5,7 @@ use crate::model::table_expr::DataType;
 use lalrpop_util::lalrpop_mod;
 use rstest::rstest;
 
-lalrpop_mod!(pub data_class, "/parser/data_class_parsing.rs");
+lalrpop_mod!(#[allow(clippy::all)] pub data_class, "/parser/data_class_parsing.rs");
 
 #[rstest]
 #[case("Tinyblob", DataClass::TinyBlob)]
@@ -20,24 +20,15 @@ fn test_no_size_type(#[case] input_str: &str, #[case] expected: DataClass) {
         .unwrap();
     assert_eq!(actual, expected);
 
-    assert_eq!(
-        data_class::OneSizeDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::TwoSizesDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::BothSizesDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
+    assert!(data_class::OneSizeDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::TwoSizesDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::BothSizesDataClassParser::new()
+        .parse(input_str)
+        .is_err());
 }
 
 #[rstest]
@@ -56,24 +47,15 @@ fn test_one_size_type(#[case] input_str: &str, #[case] expected: DataClass) {
         .unwrap();
     assert_eq!(actual, expected);
 
-    assert_eq!(
-        data_class::NoSizeDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::TwoSizesDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::BothSizesDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
+    assert!(data_class::NoSizeDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::TwoSizesDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::BothSizesDataClassParser::new()
+        .parse(input_str)
+        .is_err());
 }
 
 #[rstest]
@@ -89,24 +71,15 @@ fn test_two_size_type(#[case] input_str: &str, #[case] expected: DataClass) {
         .unwrap();
     assert_eq!(actual, expected);
 
-    assert_eq!(
-        data_class::NoSizeDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::OneSizeDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::BothSizesDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
+    assert!(data_class::NoSizeDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::OneSizeDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::BothSizesDataClassParser::new()
+        .parse(input_str)
+        .is_err());
 }
 
 #[rstest]
@@ -121,24 +94,15 @@ fn test_both_size_type(#[case] input_str: &str, #[case] expected: DataClass) {
         .unwrap();
     assert_eq!(actual, expected);
 
-    assert_eq!(
-        data_class::NoSizeDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::OneSizeDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
-    assert_eq!(
-        data_class::TwoSizesDataClassParser::new()
-            .parse(input_str)
-            .is_err(),
-        true
-    );
+    assert!(data_class::NoSizeDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::OneSizeDataClassParser::new()
+        .parse(input_str)
+        .is_err());
+    assert!(data_class::TwoSizesDataClassParser::new()
+        .parse(input_str)
+        .is_err());
 }
 
 #[rstest]
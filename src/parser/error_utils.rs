@@ -1,12 +1,55 @@
 use crate::parser::lines::Line;
 use lalrpop_util::lexer::Token;
+use std::fmt::Display;
 
 #[derive(Debug)]
 pub enum DDLxParseError {
     UnknownToken(String),
     SyntaxError(String),
     ColumnValidationError(String),
+    TableValidationError(String),
     InvalidFilterCondition(String),
+    SchemaEvolutionError(String),
+    InvalidSelect(String),
+    ViewValidationError(String),
+    TransformValidationError(String),
+    LineageValidationError(String),
+    ImportError(String),
+    InvalidPragma(String),
+    InFile {
+        file: String,
+        error: Box<DDLxParseError>,
+    },
+}
+
+impl DDLxParseError {
+    /// Adds the input file the error occurred in.
+    pub fn in_file(self, file: &str) -> Self {
+        DDLxParseError::InFile {
+            file: file.to_owned(),
+            error: Box::new(self),
+        }
+    }
+}
+
+impl Display for DDLxParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DDLxParseError::UnknownToken(message)
+            | DDLxParseError::SyntaxError(message)
+            | DDLxParseError::ColumnValidationError(message)
+            | DDLxParseError::TableValidationError(message)
+            | DDLxParseError::InvalidFilterCondition(message)
+            | DDLxParseError::SchemaEvolutionError(message)
+            | DDLxParseError::InvalidSelect(message)
+            | DDLxParseError::ViewValidationError(message)
+            | DDLxParseError::TransformValidationError(message)
+            | DDLxParseError::LineageValidationError(message)
+            | DDLxParseError::ImportError(message)
+            | DDLxParseError::InvalidPragma(message) => write!(f, "{}", message),
+            DDLxParseError::InFile { file, error } => write!(f, "{}: {}", file, error),
+        }
+    }
 }
 
 pub fn gen_unknown_token_error_message(location: usize, lines: Vec<Line>) -> String {
@@ -36,7 +79,13 @@ pub fn gen_syntax_error_message(
     let current_line = lines.get(line_number).unwrap();
     let mapped_expected = expected
         .iter()
-        .map(|x| x[1..x.len() - 1].to_owned())
+        // quoted terminals are unquoted, named terminals like keywords are not quoted
+        .map(|x| {
+            x.strip_prefix('"')
+                .and_then(|x| x.strip_suffix('"'))
+                .unwrap_or(x)
+                .to_owned()
+        })
         .collect::<Vec<String>>();
 
     let pre_line = " ".repeat(token.0 - (current_line.start as usize));
@@ -71,7 +71,7 @@ mod tests {
             Line {
                 start: 76,
                 end: 109,
-                content: "    Price FLOAT(3,8) PRIMARY KEY".to_string(),
+                content: "    Price FLOAT(8,3) PRIMARY KEY".to_string(),
             },
             Line {
                 start: 110,
@@ -127,7 +127,7 @@ mod tests {
             Line {
                 start: 70,
                 end: 101,
-                content: "    Price FLOAT(3,8) PRIMARY KEY".to_string(),
+                content: "    Price FLOAT(8,3) PRIMARY KEY".to_string(),
             },
             Line {
                 start: 102,
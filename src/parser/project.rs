@@ -0,0 +1,132 @@
+use crate::model::statement::Statement;
+use crate::parser::error_utils::DDLxParseError;
+use crate::parser::parse_statements;
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+/// File extensions that are read in project mode.
+const EXTENSIONS: [&str; 2] = ["ddlx", "sql"];
+
+/// Parsed input file of a project.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub statements: Vec<Statement>,
+}
+
+/// All input files of a compilation. Imported files are placed before the files that import them
+/// and every file is loaded only once.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Project {
+    pub files: Vec<SourceFile>,
+}
+
+impl Project {
+    /// Loads the files in order together with their imports.
+    pub fn from_files(paths: &[PathBuf]) -> Result<Self, DDLxParseError> {
+        let mut project = Project::default();
+        for path in paths {
+            project.load(path, &mut vec![])?;
+        }
+        Ok(project)
+    }
+
+    /// Loads all `.ddlx` and `.sql` files of a directory and its subdirectories in alphabetical
+    /// order, imports are loaded before the files importing them.
+    pub fn from_dir(dir: &Path) -> Result<Self, DDLxParseError> {
+        let mut paths: Vec<PathBuf> = vec![];
+        collect_files(dir, &mut paths)?;
+        paths.sort();
+        Self::from_files(&paths)
+    }
+
+    /// Loads a file after its imports. `stack` contains the files that are currently imported
+    /// to detect import cycles.
+    fn load(&mut self, path: &Path, stack: &mut Vec<PathBuf>) -> Result<(), DDLxParseError> {
+        let path = path
+            .canonicalize()
+            .map_err(|err| import_error(path, err.to_string()))?;
+
+        if let Some(position) = stack.iter().position(|file| *file == path) {
+            let cycle = stack[position..]
+                .iter()
+                .chain([&path])
+                .map(|file| file.display().to_string())
+                .collect::<Vec<String>>();
+            return Err(DDLxParseError::ImportError(format!(
+                "Import cycle {}",
+                cycle.join(" -> ")
+            )));
+        }
+        if self.files.iter().any(|file| file.path == path) {
+            return Ok(());
+        }
+
+        let content = read_to_string(&path).map_err(|err| import_error(&path, err.to_string()))?;
+        let statements =
+            parse_statements(&content).map_err(|err| err.in_file(&path.display().to_string()))?;
+
+        let dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        stack.push(path.clone());
+        for statement in &statements {
+            if let Statement::Import(import) = statement {
+                self.load(&dir.join(import), stack)?;
+            }
+        }
+        stack.pop();
+
+        self.files.push(SourceFile { path, statements });
+        Ok(())
+    }
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<(), DDLxParseError> {
+    let entries = read_dir(dir).map_err(|err| import_error(dir, err.to_string()))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|err| import_error(dir, err.to_string()))?
+            .path();
+        if path.is_dir() {
+            collect_files(&path, paths)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| EXTENSIONS.iter().any(|e| extension == *e))
+        {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn import_error(path: &Path, message: String) -> DDLxParseError {
+    DDLxParseError::ImportError(format!("Cannot read {}: {}", path.display(), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_project;
+    use crate::parser::project::Project;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_project_from_dir() {
+        let project = Project::from_dir(Path::new("./test_data/project")).unwrap();
+
+        assert_eq!(project.files.len(), 2);
+        assert!(project.files[0].path.ends_with("common/customers.ddlx"));
+
+        let catalog = parse_project(&project).unwrap();
+        assert_eq!(catalog.tables.len(), 2);
+        assert!(catalog
+            .find_symbol("crm.Customers")
+            .and_then(|symbol| symbol.file.as_ref())
+            .is_some_and(|file| file.ends_with("customers.ddlx")));
+    }
+
+    #[test]
+    fn test_project_import_cycle() {
+        let error =
+            Project::from_files(&[PathBuf::from("./test_data/import_cycle/a.ddlx")]).unwrap_err();
+        assert!(error.to_string().starts_with("Import cycle"));
+    }
+}
@@ -5,7 +5,7 @@ use rstest::rstest;
 
 use crate::model::rule_filter::{filter::FilterCondition, operator::ComparisonOperator};
 
-lalrpop_mod!(pub rule, "/parser/rule_filter_expr.rs");
+lalrpop_mod!(#[allow(clippy::all)] pub rule, "/parser/rule_filter_expr.rs");
 
 #[rstest]
 #[case(">", ComparisonOperator::GreaterThan)]
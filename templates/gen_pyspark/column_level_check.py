@@ -1,26 +1,45 @@
 from pyspark.sql import SparkSession, DataFrame
 from pydeequ import deequ_maven_coord, f2j_maven_coord
-from pyspark.sql.functions import lit
+from pyspark.sql.functions import expr, lit
 from pydeequ.checks import Check, CheckLevel, ConstrainableDataTypes
 from pydeequ.verification import VerificationSuite, VerificationResult
+{% if udfs %}from pyspark.sql.types import BooleanType
+{% for udf in udfs %}from {{udf["module"]}} import {{udf["function"]}} as {{udf["sql_name"]}}
+{% endfor %}{% endif %}{% if metrics_repository %}from pydeequ.analyzers import Size
+{% if anomaly_checks %}from pydeequ.analyzers import ApproxCountDistinct, Completeness, Entropy, Mean, StandardDeviation, Sum, Uniqueness
+from pydeequ.anomaly_detection import AbsoluteChangeStrategy, RelativeRateOfChangeStrategy
+from pydeequ.verification import AnomalyCheckConfig
+{% endif %}from pydeequ.repository import FileSystemMetricsRepository, ResultKey
 
+METRICS_REPOSITORY = "{{metrics_repository}}"
+
+
+def load_previous_size(spark_session: SparkSession, table: str) -> float | None:
+    repository = FileSystemMetricsRepository(spark_session, METRICS_REPOSITORY)
+    metrics = (repository.load().withTagValues({"table": table}).forAnalyzers([Size()])
+               .getSuccessMetricsAsJson())
+    sizes = [metric for metric in metrics if metric["name"] == "Size"]
+    if not sizes:
+        return None
+    return max(sizes, key=lambda metric: metric["dataset_date"])["value"]
+{% endif %}
 {% for column_level_check in column_level_checks %}
 def column_level_checks_{{column_level_check["column_name"]|lower}}(data_frame: DataFrame, spark_session: SparkSession) -> tuple[str, DataFrame | None]:
     try:
         data_frames = []
-        {% for filter in column_level_check["filter_checks"] -%} {%if filter["has_filter"]%}
+        {% for filter in column_level_check["filter_checks"] -%} {% for severity_check in filter["severity_checks"] -%} {%if filter["has_filter"]%}
         data_frame_filtered = data_frame.filter("{{filter["filter"]}}")
-        check = Check(spark_session, CheckLevel.Warning,
+        check = Check(spark_session, CheckLevel.{{severity_check["level"]}},
                       "{{filter["description"]}}")
-        check_result = VerificationSuite(spark_session).onData(data_frame_filtered).addCheck(
-            check{% for check in filter["checks"] %}
+        check_result = VerificationSuite(spark_session).onData(data_frame_filtered{% for window_column in filter["window_columns"] %}.withColumn("{{window_column["name"]}}", expr("{{window_column["expression"]}}")){% endfor %}{% if severity_check["sample_fraction"] %}.sample(fraction={{severity_check["sample_fraction"]}}, seed=42){% endif %}).addCheck(
+            check{% for check in severity_check["checks"] %}
             {{check}}
             {%- endfor %}
         ).run(){%else%}
-        check = Check(spark_session, CheckLevel.Warning,
+        check = Check(spark_session, CheckLevel.{{severity_check["level"]}},
                       "{{filter["description"]}}")
-        check_result = VerificationSuite(spark_session).onData(data_frame).addCheck(
-            check{% for check in filter["checks"] %}
+        check_result = VerificationSuite(spark_session).onData(data_frame{% for window_column in filter["window_columns"] %}.withColumn("{{window_column["name"]}}", expr("{{window_column["expression"]}}")){% endfor %}{% if severity_check["sample_fraction"] %}.sample(fraction={{severity_check["sample_fraction"]}}, seed=42){% endif %}).addCheck(
+            check{% for check in severity_check["checks"] %}
             {{check}}
             {%- endfor %}
         ).run(){%endif%}
@@ -28,9 +47,10 @@ def column_level_checks_{{column_level_check["column_name"]|lower}}(data_frame:
         result_df = VerificationResult.checkResultsAsDataFrame(spark_session, check_result)
         result_df = (result_df.withColumn("check_category", lit("column level"))
                      .withColumn("columns", lit("{{column_level_check["ext_column_name"]|lower}}"))
-                     .withColumn("filter", lit("{{filter["filter"]}}")))
+                     .withColumn("filter", lit("{{filter["filter"]}}"))
+                     .withColumn("severity", lit("{{severity_check["severity"]}}")))
         data_frames.append(result_df)
-        {% endfor %}
+        {% endfor %}{% endfor %}
         final_df = None
         for result_df in data_frames:
             if final_df is None:
@@ -44,6 +64,9 @@ def column_level_checks_{{column_level_check["column_name"]|lower}}(data_frame:
 
 {% endfor %}
 def check_column_level(data_frame: DataFrame, spark_session: SparkSession) -> tuple[DataFrame | None, list[tuple[str, str]]]:
+    {% for udf in udfs -%}
+    spark_session.udf.register("{{udf["sql_name"]}}", {{udf["sql_name"]}}, BooleanType())
+    {% endfor -%}
     {% for column_level_check in column_level_checks -%}
     column_level_checks_{{column_level_check["column_name"]|lower}}_df = column_level_checks_{{column_level_check["column_name"]|lower}}(data_frame, spark_session)
     {% endfor -%}
@@ -69,8 +92,124 @@ def check_column_level(data_frame: DataFrame, spark_session: SparkSession) -> tu
     return combined_result_df, failed_checks
 
 
+{% if custom_sql_checks -%}
+CUSTOM_SQL_RESULT_COLUMNS = ["check", "check_level", "check_status", "constraint", "constraint_status",
+                             "constraint_message", "check_category", "columns", "filter", "severity"]
+
+
+def custom_sql_result(check: str, level: str, constraint: str, value, assertion, check_category: str,
+                      columns: str, filter: str, severity: str) -> tuple:
+    success = value is not None and assertion(value)
+    return (check, level, "Success" if success else level, f"CustomSqlConstraint({constraint})",
+            "Success" if success else "Failure",
+            "" if success else f"Value: {value} does not meet the constraint requirement!",
+            check_category, columns, filter, severity)
+
+
+def check_custom_sql(data_frame: DataFrame, spark_session: SparkSession) -> tuple[str, DataFrame | None]:
+    try:
+        rows = []
+        {% for custom_sql_check in custom_sql_checks -%}
+        value = data_frame{% if custom_sql_check["filter"] %}.filter("{{custom_sql_check["filter"]}}"){% endif %}.selectExpr("{{custom_sql_check["aggregate"]}} AS value").first()["value"]
+        rows.append(custom_sql_result("{{custom_sql_check["description"]}}", "{{custom_sql_check["level"]}}",
+                                      "{{custom_sql_check["constraint_name"]}}", value, {{custom_sql_check["assertion"]}},
+                                      "{{custom_sql_check["check_category"]}}", "{{custom_sql_check["columns"]|lower}}",
+                                      "{{custom_sql_check["filter"]}}", "{{custom_sql_check["severity"]}}"))
+        {% endfor -%}
+        return 'success', spark_session.createDataFrame(rows, CUSTOM_SQL_RESULT_COLUMNS)
+
+    except Exception as e:
+        return f'failure: {e}', None
+
+
+{% endif -%}
+{% if table_level_check -%}
+def check_table_level(data_frame: DataFrame, spark_session: SparkSession) -> tuple[str, DataFrame | None]:
+    try:
+        {%- if metrics_repository %}
+        {%- if table_level_check["run_over_run"] %}
+        previous_size = load_previous_size(spark_session, "{{table_level_check["ext_table_name"]}}")
+        {%- endif %}
+        repository = FileSystemMetricsRepository(spark_session, METRICS_REPOSITORY)
+        result_key = ResultKey(spark_session, ResultKey.current_milli_time(),
+                               {"table": "{{table_level_check["ext_table_name"]}}"})
+        {%- endif %}
+        check_result = VerificationSuite(spark_session).onData(data_frame){% if metrics_repository %}.useRepository(repository).saveOrAppendResult(result_key){% endif %}
+        {%- for severity_check in table_level_check["severity_checks"] %}.addCheck(
+            Check(spark_session, CheckLevel.{{severity_check["level"]}},
+                  "{{severity_check["description"]}}"){% for check in severity_check["checks"] %}
+            {{check}}
+            {%- endfor %}
+        ){% endfor %}
+        {%- for anomaly_check in table_level_check["anomaly_checks"] %}.addAnomalyCheck(
+            {{anomaly_check["strategy"]}}, {{anomaly_check["analyzer"]}},
+            AnomalyCheckConfig(CheckLevel.{{anomaly_check["level"]}}, "{{anomaly_check["description"]}}",
+                               {"table": "{{table_level_check["ext_table_name"]}}"})
+        ){% endfor %}.run()
+
+        result_df = VerificationResult.checkResultsAsDataFrame(spark_session, check_result)
+        data_frames = []
+        {% for severity_check in table_level_check["severity_checks"] -%}
+        data_frames.append(result_df.filter(result_df.check == "{{severity_check["description"]}}")
+                           .withColumn("check_category", lit("table level"))
+                           .withColumn("columns", lit("{{table_level_check["ext_table_name"]|lower}}"))
+                           .withColumn("filter", lit(""))
+                           .withColumn("severity", lit("{{severity_check["severity"]}}")))
+        {% endfor -%}
+        {% for anomaly_check in table_level_check["anomaly_checks"] -%}
+        data_frames.append(result_df.filter(result_df.check == "{{anomaly_check["description"]}}")
+                           .withColumn("check_category", lit("{{anomaly_check["check_category"]}}"))
+                           .withColumn("columns", lit("{{anomaly_check["columns"]|lower}}"))
+                           .withColumn("filter", lit("{{anomaly_check["filter"]}}"))
+                           .withColumn("severity", lit("{{anomaly_check["severity"]}}")))
+        {% endfor -%}
+        final_df = data_frames[0]
+        for result_df in data_frames[1:]:
+            final_df = final_df.union(result_df)
+        return 'success', final_df
+
+    except Exception as e:
+        return f'failure: {e}', None
+
+
+def check_table(data_frame: DataFrame, spark_session: SparkSession) -> tuple[DataFrame | None, list[tuple[str, str]]]:
+    combined_result_df, failed_checks = check_column_level(data_frame, spark_session)
+    is_success, result_df = check_table_level(data_frame, spark_session)
+    if is_success == 'success':
+        if combined_result_df is None:
+            combined_result_df = result_df
+        else:
+            combined_result_df = combined_result_df.union(result_df)
+    else:
+        failed_checks.append(('check_table_level', is_success))
+{%- if custom_sql_checks %}
+    is_success, result_df = check_custom_sql(data_frame, spark_session)
+    if is_success == 'success':
+        if combined_result_df is None:
+            combined_result_df = result_df
+        else:
+            combined_result_df = combined_result_df.union(result_df)
+    else:
+        failed_checks.append(('check_custom_sql', is_success))
+{%- endif %}
+    return combined_result_df, failed_checks
+{%- else -%}
 def check_table(data_frame: DataFrame, spark_session: SparkSession) -> tuple[DataFrame | None, list[tuple[str, str]]]:
+{%- if custom_sql_checks %}
+    combined_result_df, failed_checks = check_column_level(data_frame, spark_session)
+    is_success, result_df = check_custom_sql(data_frame, spark_session)
+    if is_success == 'success':
+        if combined_result_df is None:
+            combined_result_df = result_df
+        else:
+            combined_result_df = combined_result_df.union(result_df)
+    else:
+        failed_checks.append(('check_custom_sql', is_success))
+    return combined_result_df, failed_checks
+{%- else %}
     return check_column_level(data_frame, spark_session)
+{%- endif %}
+{%- endif %}
 
 
 if __name__ == '__main__':
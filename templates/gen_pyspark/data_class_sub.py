@@ -10,6 +10,9 @@ from pyspark.sql import DataFrame
 from pyspark.sql.context import SQLContext
 from pyspark.sql.session import SparkSession
 from pyspark.sql.column import Column
+{%- if has_defaults %}
+from pyspark.sql.functions import coalesce, col, expr
+{%- endif %}
 from pyspark.sql.types import StructField, StructType, IntegerType, FloatType, StringType, DoubleType
 from pyspark.sql.types import _parse_datatype_string
 
@@ -31,6 +34,22 @@ class {{table_name}}(DataFrame):
             {% endfor -%}
         ], key=lambda x: x.name))
         self._columns = sorted([{%for column in columns%}"{{column["name"]}}",{%endfor%}])
+        {%- if has_defaults %}
+        self._defaults = {
+            {%- for column in columns %}{% if column["default"] %}
+            "{{column["name"]}}": "{{column["default"]}}",
+            {%- endif %}{% endfor %}
+        }
+
+    def fill_defaults(self) -> "{{table_name}}":
+        """
+        Replace null values with the DEFAULT expressions of the table definition
+        """
+        data_frame = self
+        for column_name, default in self._defaults.items():
+            data_frame = data_frame.withColumn(column_name, coalesce(col(column_name), expr(default)))
+        return {{table_name}}(data_frame._jdf, data_frame.sql_ctx)
+        {%- endif %}
 
     def __getitem__(self, item: Union[int, str, Column, List, Tuple]) -> Union[Column, "DataFrame"]:
         """Returns the column as a :class:`Column`.
@@ -1,8 +1,21 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
 use syn;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, LitFloat, Token};
 
-#[proc_macro_derive(ValidColumnRule)]
+/// Derives `ValidColumnRule` for a rule struct.
+///
+/// * `#[valid_for(string_like, numeric_like)]`: the column type has to belong to one of the
+///   `DataClass` categories, e.g. `string_like` checks `DataClass::is_string_like`
+/// * `#[threshold_in(0.0, 1.0)]`: the values of the `threshold` assertion of the rule have to be
+///   within the bounds
+/// * `#[validate_with(method)]`: additional validation with a method of the rule that has the
+///   signature of `validate_col_type`
+///
+/// Rules without attributes are valid for every column.
+#[proc_macro_derive(ValidColumnRule, attributes(valid_for, threshold_in, validate_with))]
 pub fn valid_column_rule_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
@@ -14,12 +27,86 @@ pub fn valid_column_rule_derive(input: TokenStream) -> TokenStream {
 
 fn impl_valid_column_macro(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
+    let rule_name = rule_name(&name.to_string());
+
+    let mut checks: Vec<TokenStream2> = vec![];
+    for attr in &ast.attrs {
+        if attr.path.is_ident("valid_for") {
+            checks.push(type_check(attr, &rule_name));
+        } else if attr.path.is_ident("threshold_in") {
+            checks.push(threshold_check(attr, &rule_name));
+        } else if attr.path.is_ident("validate_with") {
+            let method: Ident = attr.parse_args().expect("validate_with expects a method name");
+            checks.push(quote! {
+                self.#method(column)?;
+            });
+        }
+    }
+
     let gen = quote! {
         impl ValidColumnRule for #name {
             fn validate_col_type(&self, column: &ColumnDef) -> Result<String, ColumnValidationError> {
-                return Ok(String::from("valid"));
+                #(#checks)*
+                Ok(String::from("valid"))
             }
         }
     };
     gen.into()
-}
\ No newline at end of file
+}
+
+/// Checks that the column type belongs to one of the categories of the attribute.
+fn type_check(attr: &Attribute, rule_name: &str) -> TokenStream2 {
+    let categories = attr
+        .parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+        .expect("valid_for expects a list of type categories like string_like");
+    let methods = categories
+        .iter()
+        .map(|category| format_ident!("is_{}", category))
+        .collect::<Vec<Ident>>();
+    let expected = categories
+        .iter()
+        .map(|category| category.to_string().replace('_', "-"))
+        .collect::<Vec<String>>()
+        .join(" or ");
+
+    quote! {
+        if !(#(column.data_type.class.#methods())||*) {
+            return Err(ColumnValidationError::InvalidType(format!(
+                "Type of column {} is not {} for {} rule",
+                column.name, #expected, #rule_name
+            )));
+        }
+    }
+}
+
+/// Checks that the threshold of the rule is within the bounds of the attribute.
+fn threshold_check(attr: &Attribute, rule_name: &str) -> TokenStream2 {
+    let bounds = attr
+        .parse_args_with(Punctuated::<LitFloat, Token![,]>::parse_terminated)
+        .expect("threshold_in expects a lower and upper bound");
+    let (min, max) = match bounds.iter().collect::<Vec<&LitFloat>>()[..] {
+        [min, max] => (min.clone(), max.clone()),
+        _ => panic!("threshold_in expects a lower and upper bound"),
+    };
+
+    quote! {
+        if let Err(message) = self.threshold.check_bounds(#min, #max) {
+            return Err(ColumnValidationError::InvalidThreshold(format!(
+                "Threshold {} of {} rule for column {}: {}",
+                self.threshold, #rule_name, column.name, message
+            )));
+        }
+    }
+}
+
+/// Name of the rule in messages, e.g. `like pattern` for `LikePattern`.
+fn rule_name(struct_name: &str) -> String {
+    let mut name = String::new();
+    for (i, c) in struct_name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            name.push(' ');
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    name
+}